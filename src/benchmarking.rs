@@ -9,11 +9,11 @@ use crate::Pallet as CollatorStaking;
 use codec::Decode;
 use frame_benchmarking::{account, v2::*, whitelisted_caller, BenchmarkError};
 use frame_support::traits::fungible::{Inspect, Mutate};
-use frame_support::traits::{EnsureOrigin, Get};
+use frame_support::traits::{fungibles::Inspect as _, EnsureOrigin, Get};
 use frame_system::{pallet_prelude::BlockNumberFor, EventRecord, RawOrigin};
 use pallet_authorship::EventHandler;
 use pallet_session::SessionManager;
-use sp_runtime::Percent;
+use sp_runtime::{FixedU128, Perbill, Percent};
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
@@ -87,7 +87,12 @@ fn register_candidates<T: Config>(count: u32) {
 
 	for who in candidates {
 		T::Currency::mint_into(&who, CandidacyBond::<T>::get() * 3u32.into()).unwrap();
-		CollatorStaking::<T>::register_as_candidate(RawOrigin::Signed(who).into()).unwrap();
+		CollatorStaking::<T>::register_as_candidate(
+			RawOrigin::Signed(who).into(),
+			MaxCommission::<T>::get(),
+			0u32.into(),
+		)
+		.unwrap();
 	}
 }
 
@@ -99,17 +104,43 @@ fn min_candidates<T: Config>() -> u32 {
 
 fn min_invulnerables<T: Config>() -> u32 {
 	let min_collators = T::MinEligibleCollators::get();
-	let candidates_length = CandidateList::<T>::decode_len()
-		.unwrap_or_default()
-		.try_into()
-		.unwrap_or_default();
-	min_collators.saturating_sub(candidates_length)
+	min_collators.saturating_sub(Candidates::<T>::count())
+}
+
+fn lock_tiers<T: Config>(
+	count: u32,
+) -> frame_support::BoundedVec<LockTier<BlockNumberFor<T>>, T::MaxLockTiers> {
+	let tiers = (0..count)
+		.map(|t| LockTier {
+			duration: (10u32 * (t + 1)).into(),
+			multiplier: FixedU128::from_u32(t + 2),
+		})
+		.collect::<Vec<_>>();
+	frame_support::BoundedVec::truncate_from(tiers)
+}
+
+/// Amount minted per block when benchmarking [`FixedMint`].
+pub struct BenchmarkMintPerBlock<T>(PhantomData<T>);
+impl<T: Config> Get<BalanceOf<T>> for BenchmarkMintPerBlock<T> {
+	fn get() -> BalanceOf<T> {
+		T::Currency::minimum_balance()
+	}
+}
+
+frame_support::parameter_types! {
+	/// Inflation curve used when benchmarking [`IdealStakingRateInflation`].
+	pub BenchmarkInflationCurve: InflationCurve = InflationCurve {
+		min_inflation: Perbill::from_percent(2),
+		max_inflation: Perbill::from_percent(10),
+		ideal_staking_rate: Perbill::from_percent(50),
+		blocks_per_year: 2_629_800,
+	};
 }
 
 #[benchmarks(where T: pallet_authorship::Config + pallet_session::Config)]
 mod benchmarks {
 	use super::*;
-	use frame_support::traits::fungible::{Inspect, Mutate, MutateHold};
+	use frame_support::traits::fungible::{Inspect, Mutate};
 	use sp_runtime::Perbill;
 
 	#[benchmark]
@@ -161,18 +192,18 @@ mod benchmarks {
 		for (who, _) in candidates.iter() {
 			let deposit = CandidacyBond::<T>::get();
 			T::Currency::mint_into(who, deposit * 1000_u32.into()).unwrap();
-			CandidateList::<T>::try_mutate(|list| {
-				list.try_push(CandidateInfo {
+			Candidates::<T>::insert(
+				who,
+				CandidateInfo {
 					who: who.clone(),
 					stake: 0u32.into(),
 					deposit,
 					stakers: 1,
-				})
-				.unwrap();
-				Ok::<(), BenchmarkError>(())
-			})
-			.unwrap();
-			T::Currency::hold(&HoldReason::Staking.into(), who, deposit)?;
+					commission: Percent::from_parts(0),
+				},
+			);
+			CollatorStaking::<T>::insert_into_candidate_list(who, 0u32.into(), None);
+			CollatorStaking::<T>::hold_stake(who, deposit)?;
 			LastAuthoredBlock::<T>::insert(
 				who.clone(),
 				frame_system::Pallet::<T>::block_number() + T::KickThreshold::get(),
@@ -228,13 +259,13 @@ mod benchmarks {
 
 	#[benchmark]
 	fn set_candidacy_bond() -> Result<(), BenchmarkError> {
-		let initial_bond_amount: BalanceOf<T> = T::Currency::minimum_balance() * 2u32.into();
-		CandidacyBond::<T>::put(initial_bond_amount);
+		CandidacyBond::<T>::put(T::Currency::minimum_balance());
 		MinStake::<T>::put(T::Currency::minimum_balance());
 
 		let origin =
 			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
-		let bond_amount = MinStake::<T>::get();
+		// worst case is increasing the bond, which starts a grace period.
+		let bond_amount: BalanceOf<T> = T::Currency::minimum_balance() * 2u32.into();
 
 		#[extrinsic_call]
 		_(origin as T::RuntimeOrigin, bond_amount);
@@ -243,6 +274,39 @@ mod benchmarks {
 		Ok(())
 	}
 
+	// worst case is decreasing the deposit, which creates an unstaking request.
+	#[benchmark]
+	fn update_candidacy_bond() {
+		let bond = T::Currency::minimum_balance();
+		CandidacyBond::<T>::put(bond);
+		MinStake::<T>::put(bond);
+
+		register_validators::<T>(1);
+		register_candidates::<T>(1);
+		let candidate = CandidateListHead::<T>::get().unwrap();
+		whitelist_account!(candidate);
+		CollatorStaking::<T>::update_candidacy_bond(
+			RawOrigin::Signed(candidate.clone()).into(),
+			bond * 2u32.into(),
+		)
+		.unwrap();
+		let requests = (0..T::MaxStakedCandidates::get() - 1)
+			.map(|_| UnstakeRequest {
+				candidate: candidate.clone(),
+				block: 0u32.into(),
+				amount: bond,
+			})
+			.collect::<Vec<_>>();
+		UnstakingRequests::<T>::set(&candidate, requests.try_into().unwrap());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(candidate.clone()), bond);
+
+		assert_last_event::<T>(
+			Event::CandidateBondUpdated { account_id: candidate, deposit: bond }.into(),
+		);
+	}
+
 	// worse case is when we have all the max-candidate slots filled except one, and we fill that
 	// one.
 	#[benchmark]
@@ -253,10 +317,12 @@ mod benchmarks {
 
 		register_validators::<T>(c);
 		register_candidates::<T>(c);
+		// worst case is the candidate staking on itself.
+		MinSelfStake::<T>::put(T::Currency::minimum_balance());
 
 		let caller: T::AccountId = whitelisted_caller();
 		let bond: BalanceOf<T> = T::Currency::minimum_balance() * 2u32.into();
-		T::Currency::mint_into(&caller, bond).unwrap();
+		T::Currency::mint_into(&caller, bond * 2u32.into()).unwrap();
 
 		pallet_session::Pallet::<T>::set_keys(
 			RawOrigin::Signed(caller.clone()).into(),
@@ -266,11 +332,17 @@ mod benchmarks {
 		.unwrap();
 
 		#[extrinsic_call]
-		_(RawOrigin::Signed(caller.clone()));
+		_(
+			RawOrigin::Signed(caller.clone()),
+			MaxCommission::<T>::get(),
+			T::Currency::minimum_balance(),
+		);
 
-		assert_last_event::<T>(
-			Event::CandidateAdded { account_id: caller, deposit: bond / 2u32.into() }.into(),
+		assert_has_event::<T>(
+			Event::CandidateAdded { account_id: caller.clone(), deposit: bond / 2u32.into() }
+				.into(),
 		);
+		assert_eq!(Stake::<T>::get(&caller, &caller).stake, T::Currency::minimum_balance());
 	}
 
 	#[benchmark]
@@ -294,10 +366,15 @@ mod benchmarks {
 		)
 		.unwrap();
 
-		let target = CandidateList::<T>::get().iter().last().unwrap().who.clone();
+		let target = CandidateListTail::<T>::get().unwrap();
 
 		#[extrinsic_call]
-		_(RawOrigin::Signed(caller.clone()), balance / 2u32.into(), target.clone());
+		_(
+			RawOrigin::Signed(caller.clone()),
+			balance / 2u32.into(),
+			target.clone(),
+			MaxCommission::<T>::get(),
+		);
 
 		assert_last_event::<T>(
 			Event::CandidateReplaced {
@@ -320,7 +397,7 @@ mod benchmarks {
 		register_validators::<T>(c);
 		register_candidates::<T>(c);
 
-		let leaving = CandidateList::<T>::get().iter().last().unwrap().who.clone();
+		let leaving = CandidateListTail::<T>::get().unwrap();
 		v2::whitelist!(leaving);
 
 		#[extrinsic_call]
@@ -361,7 +438,7 @@ mod benchmarks {
 
 		let new_block: BlockNumberFor<T> = T::KickThreshold::get();
 		let zero_block: BlockNumberFor<T> = 0u32.into();
-		let candidates: Vec<T::AccountId> = CandidateList::<T>::get()
+		let candidates: Vec<T::AccountId> = CollatorStaking::<T>::candidate_list()
 			.iter()
 			.map(|candidate_info| candidate_info.who.clone())
 			.collect();
@@ -383,53 +460,79 @@ mod benchmarks {
 		}
 
 		let min_candidates = min_candidates::<T>();
-		let pre_length = CandidateList::<T>::decode_len().unwrap_or_default();
+		let pre_length = Candidates::<T>::count();
 
 		frame_system::Pallet::<T>::set_block_number(new_block);
 
-		let current_length: u32 = CandidateList::<T>::decode_len()
-			.unwrap_or_default()
-			.try_into()
-			.unwrap_or_default();
-		assert!(c == current_length);
+		assert!(c == pre_length);
 		#[block]
 		{
 			<CollatorStaking<T> as SessionManager<_>>::new_session(0);
 		}
 
+		// Stale candidates are only flagged, and removed in the following blocks.
+		let remaining = Candidates::<T>::count() - StaleCandidates::<T>::count();
 		if c > r && non_removals >= min_candidates {
 			// candidates > removals and remaining candidates > min candidates
 			// => remaining candidates should be shorter than before removal, i.e. some were
-			//    actually removed.
-			assert!(CandidateList::<T>::decode_len().unwrap_or_default() < pre_length);
+			//    actually flagged.
+			assert!(remaining < pre_length);
 		} else if c > r && non_removals < min_candidates {
 			// candidates > removals and remaining candidates would be less than min candidates
-			// => remaining candidates should equal min candidates, i.e. some were removed up to
+			// => remaining candidates should equal min candidates, i.e. some were flagged up to
 			//    the minimum, but then anymore were "forced" to stay in candidates.
-			let current_length: u32 = CandidateList::<T>::decode_len()
-				.unwrap_or_default()
-				.try_into()
-				.unwrap_or_default();
-			assert_eq!(min_candidates, current_length);
+			assert_eq!(min_candidates, remaining);
 		} else {
 			// removals >= candidates, non removals must == 0
 			// can't remove more than exist
-			assert_eq!(CandidateList::<T>::decode_len().unwrap_or_default(), pre_length);
+			assert_eq!(remaining, pre_length);
 		}
 	}
 
-	// worst case is promoting from first position to last one.
+	// Worst case is kicking out a candidate with stake on itself, in the middle of the list.
 	#[benchmark]
-	fn stake(c: Linear<1, { T::MaxCandidates::get() }>) {
+	fn kick_stale_candidate() {
 		let amount = T::Currency::minimum_balance();
 		CandidacyBond::<T>::put(amount);
 		MinStake::<T>::put(amount);
 		frame_system::Pallet::<T>::set_block_number(0u32.into());
 
-		register_validators::<T>(c);
-		register_candidates::<T>(c);
+		register_validators::<T>(3);
+		register_candidates::<T>(3);
+
+		let candidate = CollatorStaking::<T>::candidate_list()[1].who.clone();
+		CollatorStaking::<T>::stake(
+			RawOrigin::Signed(candidate.clone()).into(),
+			candidate.clone(),
+			amount,
+		)
+		.unwrap();
+		StaleCandidates::<T>::insert(&candidate, KickReason::Lazy);
+		let max_weight =
+			T::WeightInfo::kick_stale_candidate().saturating_add(T::DbWeight::get().reads(1));
+
+		#[block]
+		{
+			CollatorStaking::<T>::kick_stale_candidates(max_weight);
+		}
+
+		assert!(!Candidates::<T>::contains_key(&candidate));
+		assert_eq!(StaleCandidates::<T>::count(), 0);
+	}
+
+	// `c` is the number of candidates overtaken. Worst case is promoting from first position to
+	// last one.
+	#[benchmark]
+	fn stake(c: Linear<0, { T::MaxCandidates::get() - 1 }>) {
+		let amount = T::Currency::minimum_balance();
+		CandidacyBond::<T>::put(amount);
+		MinStake::<T>::put(amount);
+		frame_system::Pallet::<T>::set_block_number(0u32.into());
+
+		register_validators::<T>(c + 1);
+		register_candidates::<T>(c + 1);
 
-		let candidate = CandidateList::<T>::get()[0].who.clone();
+		let candidate = CandidateListHead::<T>::get().unwrap();
 		whitelist_account!(candidate);
 		let stake_before = Stake::<T>::get(&candidate, &candidate).stake;
 
@@ -437,13 +540,75 @@ mod benchmarks {
 		_(RawOrigin::Signed(candidate.clone()), candidate.clone(), amount);
 
 		assert_eq!(Stake::<T>::get(&candidate, &candidate).stake, stake_before + amount);
-		assert_eq!(&CandidateList::<T>::get()[(c - 1) as usize].who, &candidate);
+		assert_eq!(CandidateListTail::<T>::get(), Some(candidate));
+	}
+
+	// `c` is the number of candidates overtaken. Worst case is promoting from first position to
+	// last one while extending an existing lock.
+	#[benchmark]
+	fn stake_with_lock(c: Linear<0, { T::MaxCandidates::get() - 1 }>) {
+		let amount = T::Currency::minimum_balance();
+		CandidacyBond::<T>::put(amount);
+		MinStake::<T>::put(amount);
+		LockTiers::<T>::put(lock_tiers::<T>(T::MaxLockTiers::get().max(1)));
+		frame_system::Pallet::<T>::set_block_number(0u32.into());
+
+		register_validators::<T>(c + 1);
+		register_candidates::<T>(c + 1);
+
+		let candidate = CandidateListHead::<T>::get().unwrap();
+		whitelist_account!(candidate);
+		StakeLocks::<T>::insert(
+			&candidate,
+			&candidate,
+			StakeLock {
+				until: 1u32.into(),
+				multiplier: FixedU128::from_u32(2),
+				bonus: 0u32.into(),
+			},
+		);
+		let stake_before = Stake::<T>::get(&candidate, &candidate).stake;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(candidate.clone()), candidate.clone(), amount, 0);
+
+		assert_eq!(Stake::<T>::get(&candidate, &candidate).stake, stake_before + amount);
+		assert_eq!(CandidateListTail::<T>::get(), Some(candidate.clone()));
+		assert!(StakeLocks::<T>::contains_key(&candidate, &candidate));
+	}
+
+	#[benchmark]
+	fn unlock_stake() {
+		let amount = T::Currency::minimum_balance();
+		CandidacyBond::<T>::put(amount);
+		MinStake::<T>::put(amount);
+		LockTiers::<T>::put(lock_tiers::<T>(1));
+		frame_system::Pallet::<T>::set_block_number(0u32.into());
+
+		let candidate = register_validators::<T>(1)[0].clone();
+		register_candidates::<T>(1);
+		CollatorStaking::<T>::stake_with_lock(
+			RawOrigin::Signed(candidate.clone()).into(),
+			candidate.clone(),
+			amount,
+			0,
+		)
+		.unwrap();
+		frame_system::Pallet::<T>::set_block_number(10u32.into());
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), candidate.clone(), candidate.clone());
+
+		assert!(!StakeLocks::<T>::contains_key(&candidate, &candidate));
 	}
 
-	// worst case is promoting from last position to first one
+	// `c` is the number of candidates overtaken. Worst case is demoting from last position to
+	// first one.
 	#[benchmark]
 	fn unstake_from(
-		c: Linear<1, { T::MaxCandidates::get() }>,
+		c: Linear<0, { T::MaxCandidates::get() - 1 }>,
 		u: Linear<0, { T::MaxStakedCandidates::get() - 1 }>,
 	) {
 		let amount = T::Currency::minimum_balance();
@@ -451,10 +616,10 @@ mod benchmarks {
 		MinStake::<T>::put(amount);
 		frame_system::Pallet::<T>::set_block_number(0u32.into());
 
-		register_validators::<T>(c);
-		register_candidates::<T>(c);
+		register_validators::<T>(c + 1);
+		register_candidates::<T>(c + 1);
 
-		CandidateList::<T>::get().iter().for_each(|cand| {
+		CollatorStaking::<T>::candidate_list().iter().for_each(|cand| {
 			CollatorStaking::<T>::stake(
 				RawOrigin::Signed(cand.who.clone()).into(),
 				cand.who.clone(),
@@ -463,20 +628,60 @@ mod benchmarks {
 			.unwrap();
 		});
 
+		let candidate = CandidateListTail::<T>::get().unwrap();
+		whitelist_account!(candidate);
 		let requests = (0..u)
 			// worst case is inserting at the beginning
-			.map(|_| UnstakeRequest { block: 1000u32.into(), amount })
+			.map(|_| UnstakeRequest { candidate: candidate.clone(), block: 1000u32.into(), amount })
 			.collect::<Vec<_>>();
-
-		let candidate = CandidateList::<T>::get()[(c - 1) as usize].who.clone();
-		whitelist_account!(candidate);
 		UnstakingRequests::<T>::set(&candidate, requests.try_into().unwrap());
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(candidate.clone()), candidate.clone());
 
 		assert_eq!(Stake::<T>::get(&candidate, &candidate).stake, 0u32.into());
-		assert_eq!(&CandidateList::<T>::get()[0].who, &candidate);
+		assert_eq!(CandidateListHead::<T>::get(), Some(candidate));
+	}
+
+	// `c` is the number of candidates overtaken and `u` the number of unstaking requests the
+	// caller already has. Worst case is a partial unstake that keeps the caller at `MinStake` and
+	// moves its candidate across the other `c` candidates, from the last position of the list to
+	// the first one, while inserting the new request before all the existing ones.
+	#[benchmark]
+	fn unstake_amount(
+		c: Linear<0, { T::MaxCandidates::get() - 1 }>,
+		u: Linear<0, { T::MaxStakedCandidates::get() - 1 }>,
+	) {
+		let amount = T::Currency::minimum_balance();
+		CandidacyBond::<T>::put(amount);
+		MinStake::<T>::put(amount);
+		frame_system::Pallet::<T>::set_block_number(0u32.into());
+
+		register_validators::<T>(c + 1);
+		register_candidates::<T>(c + 1);
+
+		CollatorStaking::<T>::candidate_list().iter().for_each(|cand| {
+			CollatorStaking::<T>::stake(
+				RawOrigin::Signed(cand.who.clone()).into(),
+				cand.who.clone(),
+				amount * 2u32.into(),
+			)
+			.unwrap();
+		});
+
+		let candidate = CandidateListTail::<T>::get().unwrap();
+		whitelist_account!(candidate);
+		let requests = (0..u)
+			// worst case is inserting at the beginning
+			.map(|_| UnstakeRequest { candidate: candidate.clone(), block: 1000u32.into(), amount })
+			.collect::<Vec<_>>();
+		UnstakingRequests::<T>::set(&candidate, requests.try_into().unwrap());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(candidate.clone()), candidate.clone(), amount);
+
+		assert_eq!(Stake::<T>::get(&candidate, &candidate).stake, amount);
+		assert_eq!(CandidateListHead::<T>::get(), Some(candidate));
 	}
 
 	// worst case is having stake in as many collators as possible
@@ -495,7 +700,7 @@ mod benchmarks {
 
 		let caller = whitelisted_caller();
 		T::Currency::mint_into(&caller, amount * 2u32.into() * c.into()).unwrap();
-		CandidateList::<T>::get().iter().take(s as usize).for_each(|cand| {
+		CollatorStaking::<T>::candidate_list().iter().take(s as usize).for_each(|cand| {
 			assert_eq!(cand.deposit, amount);
 			assert_eq!(cand.stake, 0u32.into());
 			CollatorStaking::<T>::stake(
@@ -511,7 +716,7 @@ mod benchmarks {
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()));
 
-		CandidateList::<T>::get().iter().for_each(|cand| {
+		CollatorStaking::<T>::candidate_list().iter().for_each(|cand| {
 			assert_eq!(Stake::<T>::get(&cand.who, &cand.who).stake, 0u32.into());
 			assert_eq!(Stake::<T>::get(&cand.who, &caller).stake, 0u32.into());
 			assert_eq!(cand.stake, 0u32.into());
@@ -530,7 +735,7 @@ mod benchmarks {
 
 		let caller = whitelisted_caller();
 		T::Currency::mint_into(&caller, amount * 2u32.into() * (c + 1).into()).unwrap();
-		CandidateList::<T>::get().iter().for_each(|cand| {
+		CollatorStaking::<T>::candidate_list().iter().for_each(|cand| {
 			CollatorStaking::<T>::stake(
 				RawOrigin::Signed(caller.clone()).into(),
 				cand.who.clone(),
@@ -549,6 +754,140 @@ mod benchmarks {
 		assert_eq!(0, UnstakingRequests::<T>::get(&caller).len());
 	}
 
+	// `c` is the number of candidates overtaken. Worst case is consuming all unstaking requests
+	// and promoting the candidate from first position to last one.
+	#[benchmark]
+	fn rebond(
+		c: Linear<0, { T::MaxCandidates::get() - 1 }>,
+		u: Linear<1, { T::MaxStakedCandidates::get() }>,
+	) {
+		let amount = T::Currency::minimum_balance();
+		CandidacyBond::<T>::put(amount);
+		MinStake::<T>::put(amount);
+		frame_system::Pallet::<T>::set_block_number(0u32.into());
+
+		register_validators::<T>(c + 1);
+		register_candidates::<T>(c + 1);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::mint_into(&caller, amount * 2u32.into() * u.into()).unwrap();
+		CollatorStaking::<T>::hold_stake(&caller, amount * u.into()).unwrap();
+		let candidate = CandidateListHead::<T>::get().unwrap();
+		let requests = (0..u)
+			.map(|_| UnstakeRequest { candidate: candidate.clone(), block: 1000u32.into(), amount })
+			.collect::<Vec<_>>();
+		UnstakingRequests::<T>::set(&caller, requests.try_into().unwrap());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), candidate.clone(), amount * u.into());
+
+		assert_eq!(Stake::<T>::get(&candidate, &caller).stake, amount * u.into());
+		assert_eq!(UnstakingRequests::<T>::get(&caller).len(), 0);
+		assert_eq!(CandidateListTail::<T>::get(), Some(candidate));
+	}
+
+	// `c` is the number of candidates overtaken by each of the candidates involved. Worst case is
+	// demoting the origin candidate from last position to first one, and promoting the destination
+	// candidate from first position to last one.
+	#[benchmark]
+	fn move_stake(c: Linear<1, { T::MaxCandidates::get() - 1 }>) {
+		let amount = T::Currency::minimum_balance();
+		CandidacyBond::<T>::put(amount);
+		MinStake::<T>::put(amount);
+		frame_system::Pallet::<T>::set_block_number(0u32.into());
+
+		register_validators::<T>(c + 1);
+		register_candidates::<T>(c + 1);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::mint_into(&caller, amount * 2u32.into()).unwrap();
+		let from = CandidateListHead::<T>::get().unwrap();
+		CollatorStaking::<T>::stake(RawOrigin::Signed(caller.clone()).into(), from.clone(), amount)
+			.unwrap();
+		let to = CandidateListHead::<T>::get().unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), from.clone(), to.clone(), amount);
+
+		assert_eq!(Stake::<T>::get(&from, &caller).stake, 0u32.into());
+		assert_eq!(Stake::<T>::get(&to, &caller).stake, amount);
+		assert_eq!(CandidateListTail::<T>::get(), Some(to));
+	}
+
+	// `c` is the number of candidates overtaken. Worst case is promoting from first position to
+	// last one.
+	#[benchmark]
+	fn stake_for(c: Linear<0, { T::MaxCandidates::get() - 1 }>) {
+		let amount = T::Currency::minimum_balance();
+		CandidacyBond::<T>::put(amount);
+		MinStake::<T>::put(amount);
+		frame_system::Pallet::<T>::set_block_number(0u32.into());
+
+		register_validators::<T>(c + 1);
+		register_candidates::<T>(c + 1);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::mint_into(&caller, amount * 2u32.into()).unwrap();
+		let beneficiary = create_funded_user::<T>("beneficiary", 0, 1);
+		let candidate = CandidateListHead::<T>::get().unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), beneficiary.clone(), candidate.clone(), amount);
+
+		assert_eq!(Stake::<T>::get(&candidate, &beneficiary).stake, amount);
+		assert_eq!(StakeFunders::<T>::get(&candidate, &beneficiary), Some(caller));
+		assert_eq!(CandidateListTail::<T>::get(), Some(candidate));
+	}
+
+	// `c` is the number of candidates overtaken. Worst case is demoting from last position to
+	// first one.
+	#[benchmark]
+	fn unstake_for(
+		c: Linear<0, { T::MaxCandidates::get() - 1 }>,
+		u: Linear<0, { T::MaxStakedCandidates::get() - 1 }>,
+	) {
+		let amount = T::Currency::minimum_balance();
+		CandidacyBond::<T>::put(amount);
+		MinStake::<T>::put(amount);
+		frame_system::Pallet::<T>::set_block_number(0u32.into());
+
+		register_validators::<T>(c + 1);
+		register_candidates::<T>(c + 1);
+
+		CollatorStaking::<T>::candidate_list().iter().for_each(|cand| {
+			CollatorStaking::<T>::stake(
+				RawOrigin::Signed(cand.who.clone()).into(),
+				cand.who.clone(),
+				amount,
+			)
+			.unwrap();
+		});
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::mint_into(&caller, amount * 2u32.into()).unwrap();
+		let beneficiary = create_funded_user::<T>("beneficiary", 0, 1);
+		let candidate = CandidateListTail::<T>::get().unwrap();
+		CollatorStaking::<T>::stake_for(
+			RawOrigin::Signed(caller.clone()).into(),
+			beneficiary.clone(),
+			candidate.clone(),
+			amount,
+		)
+		.unwrap();
+		let requests = (0..u)
+			// worst case is inserting at the beginning
+			.map(|_| UnstakeRequest { candidate: candidate.clone(), block: 1000u32.into(), amount })
+			.collect::<Vec<_>>();
+		UnstakingRequests::<T>::set(&caller, requests.try_into().unwrap());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), beneficiary.clone(), candidate.clone());
+
+		assert_eq!(Stake::<T>::get(&candidate, &beneficiary).stake, 0u32.into());
+		assert_eq!(UnstakingRequests::<T>::get(&caller).len() as u32, u + 1);
+		assert_eq!(CandidateListHead::<T>::get(), Some(candidate));
+	}
+
 	#[benchmark]
 	fn set_autocompound_percentage() {
 		let caller: T::AccountId = whitelisted_caller();
@@ -561,7 +900,18 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn set_collator_reward_percentage() -> Result<(), BenchmarkError> {
+	fn set_reward_destination() {
+		let caller: T::AccountId = whitelisted_caller();
+		let destination = RewardDestination::Account(account("payee", 0, SEED));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), destination.clone());
+
+		assert_eq!(RewardDestinations::<T>::get(&caller), destination);
+	}
+
+	#[benchmark]
+	fn set_max_commission() -> Result<(), BenchmarkError> {
 		let origin =
 			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 		let percent = Percent::from_parts(70);
@@ -569,10 +919,33 @@ mod benchmarks {
 		#[extrinsic_call]
 		_(origin as T::RuntimeOrigin, percent);
 
-		assert_eq!(CollatorRewardPercentage::<T>::get(), percent);
+		assert_eq!(MaxCommission::<T>::get(), percent);
 		Ok(())
 	}
 
+	// worst case is requesting a commission increase for the last candidate.
+	#[benchmark]
+	fn set_commission(c: Linear<1, { T::MaxCandidates::get() }>) {
+		CandidacyBond::<T>::put(T::Currency::minimum_balance());
+		MinStake::<T>::put(T::Currency::minimum_balance());
+		MaxCommission::<T>::put(Percent::from_parts(10));
+
+		register_validators::<T>(c);
+		register_candidates::<T>(c);
+		MaxCommission::<T>::put(Percent::from_parts(20));
+
+		let candidate = CandidateListTail::<T>::get().unwrap();
+		whitelist_account!(candidate);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(candidate.clone()), Percent::from_parts(20));
+
+		assert_eq!(
+			CommissionChangeRequests::<T>::get(&candidate).map(|r| r.commission),
+			Some(Percent::from_parts(20))
+		);
+	}
+
 	#[benchmark]
 	fn set_extra_reward() -> Result<(), BenchmarkError> {
 		let origin =
@@ -599,6 +972,52 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_max_candidate_stake(
+		c: Linear<0, { T::MaxCandidates::get() }>,
+	) -> Result<(), BenchmarkError> {
+		CandidacyBond::<T>::put(T::Currency::minimum_balance());
+		MinStake::<T>::put(T::Currency::minimum_balance());
+		register_validators::<T>(c);
+		register_candidates::<T>(c);
+
+		let origin =
+			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let max_candidate_stake = T::Currency::minimum_balance() * 100u32.into();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, max_candidate_stake);
+
+		assert_eq!(MaxCandidateStake::<T>::get(), max_candidate_stake);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_lock_tiers(t: Linear<0, { T::MaxLockTiers::get() }>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let tiers = lock_tiers::<T>(t);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, tiers.clone());
+
+		assert_eq!(LockTiers::<T>::get(), tiers);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_minimum_self_stake() -> Result<(), BenchmarkError> {
+		let origin =
+			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let min_self_stake = MinStake::<T>::get() + 3u32.into();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, min_self_stake);
+
+		assert_eq!(MinSelfStake::<T>::get(), min_self_stake);
+		Ok(())
+	}
+
 	#[benchmark]
 	fn stop_extra_reward() -> Result<(), BenchmarkError> {
 		let origin =
@@ -632,47 +1051,82 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn reward_one_collator(
-		c: Linear<1, { T::MaxStakedCandidates::get() }>,
-		s: Linear<0, { T::MaxStakers::get() }>,
-		a: Linear<0, 100>,
-	) {
+	fn provide_rewards_extra_pot() {
 		let amount = T::Currency::minimum_balance();
-		CandidacyBond::<T>::put(amount);
-		MinStake::<T>::put(amount);
-		frame_system::Pallet::<T>::set_block_number(0u32.into());
-		CollatorRewardPercentage::<T>::put(Percent::from_parts(20));
+		ExtraReward::<T>::put(amount);
+		T::Currency::mint_into(
+			&CollatorStaking::<T>::extra_reward_account_id(),
+			amount * 100u32.into(),
+		)
+		.unwrap();
+		let pot = CollatorStaking::<T>::account_id();
+		let balance_before = T::Currency::balance(&pot);
 
-		let collator = register_validators::<T>(c)[0].clone();
+		#[block]
+		{
+			ExtraRewardPot::<T>::provide_rewards(&pot, 10);
+		}
+
+		assert_eq!(T::Currency::balance(&pot), balance_before + amount * 10u32.into());
+	}
+
+	#[benchmark]
+	fn provide_rewards_fixed_mint() {
+		let pot = CollatorStaking::<T>::account_id();
+		let balance_before = T::Currency::balance(&pot);
+
+		#[block]
+		{
+			FixedMint::<T, BenchmarkMintPerBlock<T>>::provide_rewards(&pot, 10);
+		}
+
+		assert_eq!(
+			T::Currency::balance(&pot),
+			balance_before + T::Currency::minimum_balance() * 10u32.into()
+		);
+	}
+
+	#[benchmark]
+	fn provide_rewards_inflation() {
+		let amount = T::Currency::minimum_balance();
+		CandidacyBond::<T>::put(amount);
+		MinStake::<T>::put(amount);
+		register_validators::<T>(1);
+		register_candidates::<T>(1);
+		let pot = CollatorStaking::<T>::account_id();
+		let balance_before = T::Currency::balance(&pot);
+
+		#[block]
+		{
+			IdealStakingRateInflation::<T, BenchmarkInflationCurve>::provide_rewards(
+				&pot,
+				BenchmarkInflationCurve::get().blocks_per_year,
+			);
+		}
+
+		assert!(T::Currency::balance(&pot) > balance_before);
+	}
+
+	#[benchmark]
+	fn reward_one_collator(c: Linear<1, { T::MaxStakedCandidates::get() }>) {
+		let amount = T::Currency::minimum_balance();
+		CandidacyBond::<T>::put(amount);
+		MinStake::<T>::put(amount);
+		frame_system::Pallet::<T>::set_block_number(0u32.into());
+		MaxCommission::<T>::put(Percent::from_parts(20));
+
+		let collator = register_validators::<T>(c)[0].clone();
 		register_candidates::<T>(c);
 
-		let autocompound = Percent::from_parts(a as u8) * s;
-		let mut accounts = vec![];
-		let mut autocompound_accounts = vec![];
-		for n in 0..s {
-			let acc = create_funded_user::<T>("staker", n, 1000);
-			CollatorStaking::<T>::stake(
-				RawOrigin::Signed(acc.clone()).into(),
-				collator.clone(),
-				amount,
-			)
+		let staker = create_funded_user::<T>("staker", 0, 1000);
+		CollatorStaking::<T>::stake(RawOrigin::Signed(staker).into(), collator.clone(), amount)
 			.unwrap();
-			if n <= autocompound {
-				CollatorStaking::<T>::set_autocompound_percentage(
-					RawOrigin::Signed(acc.clone()).into(),
-					Percent::from_parts(50),
-				)
-				.unwrap();
-				autocompound_accounts.push(acc.clone());
-			}
-			accounts.push(acc);
-		}
 		<CollatorStaking<T> as SessionManager<_>>::start_session(1);
 		for _ in 0..10 {
 			<CollatorStaking<T> as EventHandler<_, _>>::note_author(collator.clone())
 		}
 		frame_system::Pallet::<T>::set_block_number(20u32.into());
-		let total_rewards = amount * s.into();
+		let total_rewards = amount * 10u32.into();
 		T::Currency::mint_into(
 			&CollatorStaking::<T>::account_id(),
 			total_rewards + T::Currency::minimum_balance(),
@@ -689,7 +1143,7 @@ mod benchmarks {
 			CollatorStaking::<T>::reward_one_collator(1);
 		}
 
-		let collator_reward = CollatorRewardPercentage::<T>::get().mul_floor(total_rewards);
+		let collator_reward = MaxCommission::<T>::get().mul_floor(total_rewards);
 		assert_has_event::<T>(
 			Event::<T>::StakingRewardReceived {
 				staker: collator.clone(),
@@ -698,11 +1152,63 @@ mod benchmarks {
 			}
 			.into(),
 		);
+	}
+
+	#[benchmark]
+	fn reward_stakers_page(s: Linear<0, { T::RewardPageSize::get() }>, a: Linear<0, 100>) {
+		let amount = T::Currency::minimum_balance();
+		CandidacyBond::<T>::put(amount);
+		MinStake::<T>::put(amount);
+		frame_system::Pallet::<T>::set_block_number(0u32.into());
+
+		let collator = register_validators::<T>(1)[0].clone();
+		register_candidates::<T>(1);
+
+		let autocompound = Percent::from_parts(a as u8) * s;
+		let mut accounts = vec![];
+		let mut autocompound_accounts = vec![];
+		for n in 0..s {
+			let acc = create_funded_user::<T>("staker", n, 1000);
+			CollatorStaking::<T>::stake(
+				RawOrigin::Signed(acc.clone()).into(),
+				collator.clone(),
+				amount,
+			)
+			.unwrap();
+			if n <= autocompound {
+				CollatorStaking::<T>::set_autocompound_percentage(
+					RawOrigin::Signed(acc.clone()).into(),
+					Percent::from_parts(50),
+				)
+				.unwrap();
+				autocompound_accounts.push(acc.clone());
+			}
+			accounts.push(acc);
+		}
+		CurrentSession::<T>::put(2);
+		let stakers_rewards = amount * s.into();
+		T::Currency::mint_into(
+			&CollatorStaking::<T>::account_id(),
+			stakers_rewards + T::Currency::minimum_balance(),
+		)
+		.unwrap();
+		let cursor = RewardCursor {
+			session: 1,
+			collator: collator.clone(),
+			last_staker: None,
+			total_stake: amount * s.into(),
+			stakers_rewards,
+			remaining_rewards: stakers_rewards,
+		};
+
+		#[block]
+		{
+			CollatorStaking::<T>::reward_stakers_page(cursor);
+		}
 
 		if s > 0 {
-			let stakers_reward = total_rewards - collator_reward;
 			let expected_reward =
-				Perbill::from_rational(amount, amount * s.into()).mul_floor(stakers_reward);
+				Perbill::from_rational(amount, amount * s.into()).mul_floor(stakers_rewards);
 			for acc in accounts {
 				assert_has_event::<T>(
 					Event::<T>::StakingRewardReceived {
@@ -725,6 +1231,57 @@ mod benchmarks {
 				);
 			}
 		}
+		assert_eq!(CurrentRewardCursor::<T>::get(), None);
+	}
+
+	#[benchmark]
+	fn claim_rewards(s: Linear<1, { T::RewardsHistoryDepth::get() }>) {
+		let amount = T::Currency::minimum_balance();
+		CandidacyBond::<T>::put(amount);
+		MinStake::<T>::put(amount);
+		frame_system::Pallet::<T>::set_block_number(0u32.into());
+
+		let collator = register_validators::<T>(1)[0].clone();
+		register_candidates::<T>(1);
+		let staker = create_funded_user::<T>("staker", 0, 1000);
+		CollatorStaking::<T>::stake(
+			RawOrigin::Signed(staker.clone()).into(),
+			collator.clone(),
+			amount,
+		)
+		.unwrap();
+		CollatorStaking::<T>::set_autocompound_percentage(
+			RawOrigin::Signed(staker.clone()).into(),
+			Percent::from_parts(50),
+		)
+		.unwrap();
+
+		// The staker obtained as many rewards as its stake in every session.
+		for session in 1..=s {
+			RewardPerStake::<T>::insert(session, &collator, FixedU128::from_u32(1));
+			UnclaimedRewards::<T>::insert(session, amount);
+		}
+		CurrentSession::<T>::put(s + 1);
+		T::Currency::mint_into(
+			&CollatorStaking::<T>::account_id(),
+			amount * s.into() + T::Currency::minimum_balance(),
+		)
+		.unwrap();
+
+		#[block]
+		{
+			CollatorStaking::<T>::do_claim_rewards(&staker, &collator).unwrap();
+		}
+
+		assert_has_event::<T>(
+			Event::<T>::RewardsClaimed {
+				staker: staker.clone(),
+				candidate: collator.clone(),
+				amount: amount * s.into(),
+			}
+			.into(),
+		);
+		assert_eq!(LastClaimedSession::<T>::get(&collator, &staker), Some(s));
 	}
 
 	#[benchmark]
@@ -733,7 +1290,7 @@ mod benchmarks {
 		CandidacyBond::<T>::put(amount);
 		MinStake::<T>::put(amount);
 		frame_system::Pallet::<T>::set_block_number(0u32.into());
-		CollatorRewardPercentage::<T>::put(Percent::from_parts(20));
+		MaxCommission::<T>::put(Percent::from_parts(20));
 
 		let collator = register_validators::<T>(1)[0].clone();
 		register_candidates::<T>(1);
@@ -768,5 +1325,277 @@ mod benchmarks {
 		}
 	}
 
-	impl_benchmark_test_suite!(CollatorStaking, crate::mock::new_test_ext(), crate::mock::Test,);
+	// worst case is reporting the last candidate while other slashes are deferred to the same
+	// session.
+	#[benchmark]
+	fn slash_candidate(c: Linear<1, { T::MaxCandidates::get() }>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		CandidacyBond::<T>::put(T::Currency::minimum_balance());
+		MinStake::<T>::put(T::Currency::minimum_balance());
+
+		register_validators::<T>(c);
+		register_candidates::<T>(c);
+
+		let candidates = CollatorStaking::<T>::candidate_list();
+		let candidate = candidates[(c - 1) as usize].who.clone();
+		let session = CurrentSession::<T>::get().saturating_add(T::SlashDeferDuration::get());
+		let slashes = candidates
+			.iter()
+			.take((c - 1) as usize)
+			.map(|info| UnappliedSlash {
+				candidate: info.who.clone(),
+				fraction: Perbill::from_percent(10),
+			})
+			.collect::<Vec<_>>();
+		UnappliedSlashes::<T>::insert(
+			session,
+			frame_support::BoundedVec::try_from(slashes).unwrap(),
+		);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, candidate.clone(), Perbill::from_percent(10));
+
+		if T::SlashDeferDuration::get() > 0 {
+			assert!(UnappliedSlashes::<T>::get(session)
+				.iter()
+				.any(|slash| slash.candidate == candidate));
+		}
+		Ok(())
+	}
+
+	// worst case is cancelling the last slash deferred to a given session.
+	#[benchmark]
+	fn cancel_deferred_slash(
+		s: Linear<1, { T::MaxCandidates::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let origin =
+			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let slashes = (0..s)
+			.map(|n| UnappliedSlash {
+				candidate: account("candidate", n, SEED),
+				fraction: Perbill::from_percent(10),
+			})
+			.collect::<Vec<_>>();
+		let candidate = slashes[(s - 1) as usize].candidate.clone();
+		UnappliedSlashes::<T>::insert(1, frame_support::BoundedVec::try_from(slashes).unwrap());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 1, candidate);
+
+		assert_eq!(UnappliedSlashes::<T>::get(1).len(), (s - 1) as usize);
+		Ok(())
+	}
+
+	// `s` is the number of stakers and `u` the number of accounts with pending unstaking requests
+	// towards the candidate. Worst case is slashing a candidate whose stakers also have pending
+	// unstaking requests.
+	#[benchmark]
+	fn apply_slash(
+		s: Linear<0, { T::MaxStakers::get() }>,
+		u: Linear<0, { T::MaxStakers::get() * T::MaxStakedCandidates::get() }>,
+	) {
+		let amount = T::Currency::minimum_balance();
+		CandidacyBond::<T>::put(amount);
+		MinStake::<T>::put(amount);
+		frame_system::Pallet::<T>::set_block_number(0u32.into());
+
+		let collator = register_validators::<T>(1)[0].clone();
+		register_candidates::<T>(1);
+
+		let stakers = (0..s)
+			.map(|n| {
+				let acc = create_funded_user::<T>("staker", n, 1000);
+				CollatorStaking::<T>::stake(
+					RawOrigin::Signed(acc.clone()).into(),
+					collator.clone(),
+					amount * 2u32.into(),
+				)
+				.unwrap();
+				CollatorStaking::<T>::unstake_amount(
+					RawOrigin::Signed(acc.clone()).into(),
+					collator.clone(),
+					amount,
+				)
+				.unwrap();
+				acc
+			})
+			.collect::<Vec<_>>();
+		let unstakers = (0..u)
+			.map(|n| {
+				let acc = create_funded_user::<T>("unstaker", n, 1000);
+				CollatorStaking::<T>::stake(
+					RawOrigin::Signed(acc.clone()).into(),
+					collator.clone(),
+					amount * 2u32.into(),
+				)
+				.unwrap();
+				CollatorStaking::<T>::unstake_from(
+					RawOrigin::Signed(acc.clone()).into(),
+					collator.clone(),
+				)
+				.unwrap();
+				acc
+			})
+			.collect::<Vec<_>>();
+
+		#[block]
+		{
+			CollatorStaking::<T>::do_slash(&collator, Perbill::from_percent(50));
+		}
+
+		for staker in stakers {
+			assert!(Stake::<T>::get(&collator, &staker).stake < amount);
+		}
+		for unstaker in unstakers {
+			assert_eq!(UnstakingRequests::<T>::get(&unstaker)[0].amount, amount);
+		}
+	}
+
+	// `c` is the number of candidates the pool stakes on.
+	#[benchmark]
+	fn create_pool(c: Linear<1, { T::MaxStakedCandidates::get() }>) {
+		let amount = T::Currency::minimum_balance();
+		CandidacyBond::<T>::put(amount);
+		MinStake::<T>::put(amount);
+		frame_system::Pallet::<T>::set_block_number(0u32.into());
+
+		let candidates = register_validators::<T>(c);
+		register_candidates::<T>(c);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::mint_into(&caller, amount * (c + 2).into()).unwrap();
+		let stake = amount * c.into();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			frame_support::BoundedVec::truncate_from(candidates),
+			stake,
+		);
+
+		assert_eq!(PoolMembers::<T>::get(0, &caller), stake);
+		let pool_account = CollatorStaking::<T>::pool_account_id(0);
+		assert_eq!(StakedCandidates::<T>::get(&pool_account).len() as u32, c);
+	}
+
+	// `c` is the number of candidates the pool stakes on.
+	#[benchmark]
+	fn join_pool(c: Linear<1, { T::MaxStakedCandidates::get() }>) {
+		let amount = T::Currency::minimum_balance();
+		CandidacyBond::<T>::put(amount);
+		MinStake::<T>::put(amount);
+		frame_system::Pallet::<T>::set_block_number(0u32.into());
+
+		let candidates = register_validators::<T>(c);
+		register_candidates::<T>(c);
+		let creator = create_funded_user::<T>("creator", 0, c + 2);
+		CollatorStaking::<T>::create_pool(
+			RawOrigin::Signed(creator).into(),
+			frame_support::BoundedVec::truncate_from(candidates),
+			amount * c.into(),
+		)
+		.unwrap();
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::mint_into(&caller, amount * (c + 1).into()).unwrap();
+		let stake = amount * c.into();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), 0, stake);
+
+		assert_eq!(PoolMembers::<T>::get(0, &caller), stake);
+	}
+
+	// `c` is the number of candidates the pool stakes on. Worst case is the last member leaving,
+	// which unstakes from all of them and dissolves the pool.
+	#[benchmark]
+	fn leave_pool(c: Linear<1, { T::MaxStakedCandidates::get() }>) {
+		let amount = T::Currency::minimum_balance();
+		CandidacyBond::<T>::put(amount);
+		MinStake::<T>::put(amount);
+		frame_system::Pallet::<T>::set_block_number(0u32.into());
+
+		let candidates = register_validators::<T>(c);
+		register_candidates::<T>(c);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::mint_into(&caller, amount * (c + 2).into()).unwrap();
+		CollatorStaking::<T>::create_pool(
+			RawOrigin::Signed(caller.clone()).into(),
+			frame_support::BoundedVec::truncate_from(candidates),
+			amount * c.into(),
+		)
+		.unwrap();
+		let points = PoolMembers::<T>::get(0, &caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), 0, points);
+
+		assert_eq!(PoolMembers::<T>::get(0, &caller), 0u32.into());
+		assert!(Pools::<T>::get(0).is_none());
+		assert_eq!(UnstakingRequests::<T>::get(&caller).len() as u32, c);
+	}
+
+	// `c` is the number of candidates overtaken. Worst case is promoting from first position to
+	// last one while minting the first derivative.
+	#[benchmark]
+	fn liquid_stake(c: Linear<0, { T::MaxCandidates::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let asset_id = T::LiquidStakingAssetId::get().ok_or(BenchmarkError::Weightless)?;
+		let amount = T::Currency::minimum_balance();
+		CandidacyBond::<T>::put(amount);
+		MinStake::<T>::put(amount);
+		frame_system::Pallet::<T>::set_block_number(0u32.into());
+
+		register_validators::<T>(c + 1);
+		register_candidates::<T>(c + 1);
+
+		let candidate = CandidateListHead::<T>::get().unwrap();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::mint_into(&caller, amount * 3u32.into()).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), candidate.clone(), amount);
+
+		assert_eq!(T::Assets::balance(asset_id, &caller), amount);
+		assert_eq!(CandidateListTail::<T>::get(), Some(candidate));
+		Ok(())
+	}
+
+	// `c` is the number of candidates the liquid staking account stakes on. Worst case is
+	// unstaking from all of them.
+	#[benchmark]
+	fn liquid_unstake(
+		c: Linear<1, { T::MaxStakedCandidates::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let asset_id = T::LiquidStakingAssetId::get().ok_or(BenchmarkError::Weightless)?;
+		let amount = T::Currency::minimum_balance();
+		CandidacyBond::<T>::put(amount);
+		MinStake::<T>::put(amount);
+		frame_system::Pallet::<T>::set_block_number(0u32.into());
+
+		let candidates = register_validators::<T>(c);
+		register_candidates::<T>(c);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::mint_into(&caller, amount * (c + 2).into()).unwrap();
+		for candidate in candidates {
+			CollatorStaking::<T>::liquid_stake(
+				RawOrigin::Signed(caller.clone()).into(),
+				candidate,
+				amount,
+			)
+			.unwrap();
+		}
+		let minted = T::Assets::balance(asset_id.clone(), &caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), minted);
+
+		assert_eq!(T::Assets::balance(asset_id, &caller), 0u32.into());
+		assert_eq!(UnstakingRequests::<T>::get(&caller).len() as u32, c);
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(CollatorStaking, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -6,15 +6,26 @@
 //!
 //! The Collator Staking pallet provides DPoS functionality to manage collators of a parachain.
 //! It allows stakers to stake their tokens to back collators, and receive rewards proportionately.
-//! There is no slashing in place. If a collator does not produce blocks as expected,
-//! they are removed from the collator set.
+//! If a collator does not produce blocks as expected, they are removed from the collator set.
+//! Offending collators can be slashed through the [`SlashHandler`] trait, which also affects the
+//! stake their stakers deposited on them.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use core::marker::PhantomData;
 
 use codec::Codec;
-use frame_support::traits::TypedGet;
+use frame_support::{
+	traits::{
+		fungibles,
+		tokens::{
+			self, DepositConsequence, Fortitude, Preservation, Provenance, WithdrawConsequence,
+		},
+		TypedGet,
+	},
+	weights::Weight,
+};
+use sp_runtime::{traits::Zero, DispatchError, DispatchResult, Perbill, RuntimeDebug, TokenError};
 
 pub use pallet::*;
 
@@ -26,6 +37,7 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
 pub mod weights;
 
 const LOG_TARGET: &str = "runtime::collator-staking";
@@ -35,31 +47,45 @@ pub mod pallet {
 	use frame_support::{
 		dispatch::{DispatchClass, DispatchResultWithPostInfo},
 		pallet_prelude::*,
+		storage::with_storage_layer,
 		traits::{
-			fungible::{Inspect, Mutate, MutateHold},
+			fungible::{Balanced, BalancedHold, Credit, Inspect, InspectHold, Mutate, MutateHold},
+			fungibles::{self, Inspect as _, Mutate as _},
+			tokens::Fortitude::Polite,
 			tokens::Precision::Exact,
 			tokens::Preservation::{Expendable, Preserve},
-			EnsureOrigin, ValidatorRegistration,
+			tokens::Restriction,
+			EnsureOrigin, OnUnbalanced, ValidatorRegistration,
 		},
 		BoundedVec, DefaultNoBound, PalletId,
 	};
 	use frame_system::pallet_prelude::*;
 	use pallet_session::SessionManager;
 	use sp_runtime::{
-		traits::{AccountIdConversion, Convert, Saturating, Zero},
-		RuntimeDebug,
+		traits::{AccountIdConversion, Bounded, Convert, SaturatedConversion, Saturating, Zero},
+		FixedPointNumber, FixedU128, RuntimeDebug,
 	};
 	use sp_runtime::{Perbill, Percent};
-	use sp_staking::SessionIndex;
-	use sp_std::collections::btree_map::BTreeMap;
+	use sp_staking::{
+		currency_to_vote::SaturatingCurrencyToVote, EraIndex, SessionIndex, StakerStatus,
+		StakingInterface,
+	};
 	use sp_std::vec::Vec;
 
 	pub use crate::weights::WeightInfo;
 
-	use super::LOG_TARGET;
+	use super::{OnStakeChange, RewardSource, SlashHandler, StakeChangeReason, LOG_TARGET};
 
 	/// The in-code storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
+
+	/// Identifier of an asset in [`Config::Assets`].
+	pub type AssetIdOf<T> = <<T as Config>::Assets as fungibles::Inspect<
+		<T as frame_system::Config>::AccountId,
+	>>::AssetId;
+
+	/// Identifier of a nomination pool.
+	pub type PoolId = u32;
 
 	pub type BalanceOf<T> =
 		<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
@@ -90,7 +116,8 @@ pub mod pallet {
 		/// The currency mechanism.
 		type Currency: Inspect<Self::AccountId>
 			+ Mutate<Self::AccountId>
-			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+			+ BalancedHold<Self::AccountId>;
 
 		/// Overarching hold reason.
 		type RuntimeHoldReason: From<HoldReason>;
@@ -136,6 +163,12 @@ pub mod pallet {
 		#[pallet::constant]
 		type KickThreshold: Get<BlockNumberFor<Self>>;
 
+		/// Maximum weight spent per block removing the candidates flagged to be kicked out when
+		/// the last session ended. It must allow at least one removal, as given by
+		/// `WeightInfo::kick_stale_candidate`, on top of one read.
+		#[pallet::constant]
+		type MaxKickWeight: Get<Weight>;
+
 		/// A stable ID for a collator.
 		type CollatorId: Member + Parameter;
 
@@ -163,6 +196,68 @@ pub mod pallet {
 		#[pallet::constant]
 		type UserUnstakingDelay: Get<BlockNumberFor<Self>>;
 
+		/// Expected number of blocks per session. Used to express the unstaking delay in
+		/// sessions, as reported by [`StakingInterface::bonding_duration`].
+		#[pallet::constant]
+		type SessionLength: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks to wait before a commission increase requested by a candidate
+		/// takes effect.
+		#[pallet::constant]
+		type CommissionChangeDelay: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks a staker has to wait to move stake between candidates again after
+		/// moving it. Zero disables the cooldown.
+		#[pallet::constant]
+		type StakeMoveCooldown: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks candidates have to top up their deposit after [`CandidacyBond`] is
+		/// increased, before being removed from the candidate list.
+		#[pallet::constant]
+		type CandidacyBondGracePeriod: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of lock tiers stakers can choose from when locking their stake.
+		#[pallet::constant]
+		type MaxLockTiers: Get<u32>;
+
+		/// Handler for the funds slashed from candidates and their stakers.
+		type OnSlash: OnUnbalanced<Credit<Self::AccountId, Self::Currency>>;
+
+		/// Maximum number of stakers rewarded per block.
+		#[pallet::constant]
+		type RewardPageSize: Get<u32>;
+
+		/// Number of sessions reported slashes are deferred by. During this time they can be
+		/// cancelled by the [`Config::UpdateOrigin`].
+		#[pallet::constant]
+		type SlashDeferDuration: Get<SessionIndex>;
+
+		/// Whether rewards are pushed to the stakers or have to be claimed by them.
+		#[pallet::constant]
+		type RewardsMode: Get<RewardsMode>;
+
+		/// Number of sessions stakers can claim their rewards for when rewards are claimed by
+		/// them. Rewards not claimed in time are distributed again in the following sessions.
+		#[pallet::constant]
+		type RewardsHistoryDepth: Get<SessionIndex>;
+
+		/// Source of the rewards added to the pot when a session ends, on top of the funds already
+		/// sent to it, such as transaction fees.
+		type RewardSource: RewardSource<Self::AccountId, BalanceOf<Self>>;
+
+		/// Handler notified when the stake deposited by a staker in a candidate changes. Use `()`
+		/// if no other pallet needs to be notified.
+		type OnStakeChange: OnStakeChange<Self::AccountId, BalanceOf<Self>>;
+
+		/// Assets in which the liquid staking derivative is minted. Use [`crate::NoAssets`] if the
+		/// runtime does not offer liquid staking.
+		type Assets: fungibles::Inspect<Self::AccountId, Balance = BalanceOf<Self>>
+			+ fungibles::Mutate<Self::AccountId>;
+
+		/// Asset minted as the liquid staking derivative, which must already exist. Use `None`,
+		/// or `()` along with [`crate::NoAssets`], to disable liquid staking.
+		type LiquidStakingAssetId: Get<Option<AssetIdOf<Self>>>;
+
 		/// The weight information of this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -174,6 +269,50 @@ pub mod pallet {
 		Staking,
 	}
 
+	/// How rewards are delivered to stakers.
+	#[derive(
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		Encode,
+		Decode,
+		RuntimeDebug,
+		scale_info::TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub enum RewardsMode {
+		/// Rewards are transferred to every staker once the session ends.
+		Push,
+		/// Rewards are recorded once the session ends, and stakers claim them afterwards.
+		Pull,
+	}
+
+	/// Where the staking rewards of an account are delivered to.
+	#[derive(
+		Clone,
+		PartialEq,
+		Eq,
+		Encode,
+		Decode,
+		Default,
+		RuntimeDebug,
+		scale_info::TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub enum RewardDestination<AccountId> {
+		/// Rewards are transferred to the account, and part of them is staked again according to
+		/// its [`AutoCompound`] percentage.
+		#[default]
+		Free,
+		/// Rewards are staked again in full, regardless of the [`AutoCompound`] percentage.
+		Staked,
+		/// Rewards are transferred to a different account.
+		Account(AccountId),
+		/// Rewards are burned.
+		Burn,
+	}
+
 	/// Basic information about a collator candidate.
 	#[derive(
 		PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
@@ -187,19 +326,124 @@ pub mod pallet {
 		pub deposit: Balance,
 		/// Amount of stakers.
 		pub stakers: u32,
+		/// Percentage of rewards the candidate keeps for producing blocks.
+		pub commission: Percent,
+	}
+
+	/// Position of a candidate in the sorted list of candidates.
+	#[derive(
+		PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
+	)]
+	pub struct ListNode<AccountId> {
+		/// Candidate with less stake, if any.
+		pub prev: Option<AccountId>,
+		/// Candidate with more stake, if any.
+		pub next: Option<AccountId>,
 	}
 
 	/// Information about the unstaking requests.
 	#[derive(
 		PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
 	)]
-	pub struct UnstakeRequest<BlockNumber, Balance> {
+	pub struct UnstakeRequest<AccountId, BlockNumber, Balance> {
+		/// Candidate the stake was removed from.
+		pub candidate: AccountId,
 		/// Block when stake can be unreserved.
 		pub block: BlockNumber,
 		/// Stake to be unreserved.
 		pub amount: Balance,
 	}
 
+	/// Funds unbonded from the idle stake of an account, waiting for the unstaking delay to pass.
+	#[derive(
+		PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
+	)]
+	pub struct IdleUnbond<BlockNumber, Balance> {
+		/// Block when the funds can be released.
+		pub block: BlockNumber,
+		/// Funds to be released.
+		pub amount: Balance,
+	}
+
+	/// Lock period stakers can choose when staking, along with the reward multiplier it gives.
+	#[derive(
+		PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
+	)]
+	pub struct LockTier<BlockNumber> {
+		/// Number of blocks the stake remains locked for.
+		pub duration: BlockNumber,
+		/// Multiplier applied to the locked stake when calculating its share of the rewards.
+		pub multiplier: FixedU128,
+	}
+
+	/// Lock on the stake deposited by a staker in a candidate.
+	#[derive(
+		PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
+	)]
+	pub struct StakeLock<BlockNumber, Balance> {
+		/// Block until which the stake cannot be removed.
+		pub until: BlockNumber,
+		/// Multiplier applied to the stake when calculating its share of the rewards.
+		pub multiplier: FixedU128,
+		/// Weight added to the stake by the multiplier.
+		pub bonus: Balance,
+	}
+
+	/// Information about a nomination pool.
+	#[derive(
+		PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
+	)]
+	pub struct PoolInfo<AccountId, Balance> {
+		/// Account that created the pool, which receives the funds left when it is dissolved.
+		pub creator: AccountId,
+		/// Total points held by the members of the pool.
+		pub points: Balance,
+		/// Number of members of the pool.
+		pub members: u32,
+	}
+
+	/// Information about a commission increase requested by a candidate.
+	#[derive(
+		PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
+	)]
+	pub struct CommissionChangeRequest<BlockNumber> {
+		/// Block when the new commission takes effect.
+		pub block: BlockNumber,
+		/// New commission.
+		pub commission: Percent,
+	}
+
+	/// Progress of the distribution of rewards among the stakers of a collator.
+	#[derive(
+		PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
+	)]
+	pub struct RewardCursor<AccountId, Balance> {
+		/// Session the rewards are being distributed for.
+		pub session: SessionIndex,
+		/// Collator whose stakers are being rewarded.
+		pub collator: AccountId,
+		/// Last staker rewarded, if any.
+		pub last_staker: Option<AccountId>,
+		/// Stake of the collator when the distribution started, including the bonus given by the
+		/// locks of its stakers.
+		pub total_stake: Balance,
+		/// Rewards to be distributed among all stakers.
+		pub stakers_rewards: Balance,
+		/// Rewards not distributed yet.
+		pub remaining_rewards: Balance,
+	}
+
+	/// A slash that was reported but not applied yet.
+	#[derive(
+		PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
+	)]
+	pub struct UnappliedSlash<AccountId> {
+		/// Candidate to be slashed.
+		pub candidate: AccountId,
+		/// Fraction of the funds at stake to be slashed.
+		pub fraction: Perbill,
+	}
+
 	/// Information about stake.
 	#[derive(
 		Default,
@@ -219,6 +463,91 @@ pub mod pallet {
 		pub stake: Balance,
 	}
 
+	/// Reason why a candidate is kicked out of the candidate list when a new session starts.
+	#[derive(
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		Encode,
+		Decode,
+		RuntimeDebug,
+		scale_info::TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub enum KickReason {
+		/// The candidate is also an invulnerable.
+		Invulnerable,
+		/// The candidate did not produce blocks within [`Config::KickThreshold`].
+		Lazy,
+		/// The deposit of the candidate is lower than [`CandidacyBond`], and the grace period
+		/// after the last increase of the bond is over.
+		InsufficientBond,
+		/// The stake of the candidate on itself is lower than [`MinSelfStake`].
+		InsufficientSelfStake,
+	}
+
+	/// Preview of the collator set for the next session.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct CollatorSetPreview<AccountId, Balance> {
+		/// Invulnerables and candidates that would be selected as collators.
+		pub collators: Vec<AccountId>,
+		/// Candidates that would be kicked out, along with the reason.
+		pub kicked: Vec<(AccountId, KickReason)>,
+		/// Stake of the least staked candidate that would be selected, which has to be exceeded
+		/// to be selected instead of it. Zero if there are not enough candidates to fill
+		/// [`DesiredCandidates`].
+		pub stake_cutoff: Balance,
+	}
+
+	/// Parameters of the inflation curve used by [`IdealStakingRateInflation`].
+	#[derive(PartialEq, Eq, Clone, RuntimeDebug)]
+	pub struct InflationCurve {
+		/// Annual inflation when nothing is staked, or when everything is.
+		pub min_inflation: Perbill,
+		/// Annual inflation when the staking rate matches `ideal_staking_rate`.
+		pub max_inflation: Perbill,
+		/// Share of the total issuance that should ideally be staked.
+		pub ideal_staking_rate: Perbill,
+		/// Expected number of blocks produced per year.
+		pub blocks_per_year: u32,
+	}
+
+	impl InflationCurve {
+		/// Returns the annual inflation for a given staking rate.
+		///
+		/// Inflation grows linearly from `min_inflation` to `max_inflation` while the staking rate
+		/// is lower than `ideal_staking_rate`, and decreases linearly back to `min_inflation` as
+		/// the staking rate approaches 100%.
+		pub fn annual_inflation(&self, staking_rate: Perbill) -> Perbill {
+			let range = self.max_inflation.saturating_sub(self.min_inflation);
+			let (rate, ideal) = (staking_rate.deconstruct(), self.ideal_staking_rate.deconstruct());
+			let adjustment = if rate < ideal {
+				range * Perbill::from_rational(rate, ideal)
+			} else {
+				let excess = rate.saturating_sub(ideal);
+				let max_excess = Perbill::one().deconstruct().saturating_sub(ideal).max(1);
+				range.saturating_sub(range * Perbill::from_rational(excess, max_excess))
+			};
+			self.min_inflation.saturating_add(adjustment)
+		}
+	}
+
+	/// Staking position of an account.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct StakerInfo<AccountId, Balance, BlockNumber> {
+		/// Candidates staked on, along with the stake deposited in each of them.
+		pub stakes: Vec<(AccountId, StakeInfo<Balance>)>,
+		/// Unstaking requests that were not claimed yet.
+		pub unstaking_requests: Vec<UnstakeRequest<AccountId, BlockNumber, Balance>>,
+		/// Amount that can be claimed right now from the unstaking requests.
+		pub claimable: Balance,
+		/// Percentage of rewards to be auto-compounded.
+		pub autocompound: Percent,
+		/// Total amount held for candidacy bonds and staking.
+		pub total_held: Balance,
+	}
+
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
@@ -231,15 +560,32 @@ pub mod pallet {
 	/// The (community, limited) collation candidates. `Candidates` and `Invulnerables` should be
 	/// mutually exclusive.
 	///
-	/// This list is sorted in ascending order by total stake and when the stake amounts are equal, the least
-	/// recently updated is considered greater.
+	/// Candidates are kept sorted by total stake in the list formed by [`CandidateListNodes`].
 	#[pallet::storage]
-	pub type CandidateList<T: Config> = StorageValue<
+	pub type Candidates<T: Config> = CountedStorageMap<
 		_,
-		BoundedVec<CandidateInfo<T::AccountId, BalanceOf<T>>, T::MaxCandidates>,
-		ValueQuery,
+		Blake2_128Concat,
+		T::AccountId,
+		CandidateInfo<T::AccountId, BalanceOf<T>>,
+		OptionQuery,
 	>;
 
+	/// Nodes of the doubly linked list of candidates.
+	///
+	/// This list is sorted in ascending order by total stake and when the stake amounts are equal, the least
+	/// recently updated is considered greater.
+	#[pallet::storage]
+	pub type CandidateListNodes<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, ListNode<T::AccountId>, OptionQuery>;
+
+	/// Candidate with the lowest stake, which is the first one in the list of candidates.
+	#[pallet::storage]
+	pub type CandidateListHead<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+	/// Candidate with the highest stake, which is the last one in the list of candidates.
+	#[pallet::storage]
+	pub type CandidateListTail<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
 	/// Last block authored by a collator.
 	#[pallet::storage]
 	pub type LastAuthoredBlock<T: Config> =
@@ -258,10 +604,28 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type CandidacyBond<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+	/// Block until which candidates whose deposit is lower than [`CandidacyBond`] are not removed,
+	/// set when the bond is increased.
+	#[pallet::storage]
+	pub type CandidacyBondGraceEnd<T> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
 	/// Minimum amount a user can stake.
 	#[pallet::storage]
 	pub type MinStake<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+	/// Minimum amount candidates must stake on themselves, on top of the candidacy bond.
+	///
+	/// Zero means candidates are not required to stake on themselves.
+	#[pallet::storage]
+	pub type MinSelfStake<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// Maximum total stake a candidate can receive. Stake above it, if the limit is lowered,
+	/// does not count towards the position of the candidate in the list of candidates.
+	///
+	/// Zero means there is no limit.
+	#[pallet::storage]
+	pub type MaxCandidateStake<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
 	/// Amount staked by users per candidate.
 	///
 	/// First key is the candidate, and second one is the staker.
@@ -276,11 +640,84 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
-	/// Number of candidates staked on by a user.
+	/// Accounts funding the stake deposited by a staker in a candidate on its behalf.
+	///
+	/// First key is the candidate, and second one is the staker.
+	#[pallet::storage]
+	pub type StakeFunders<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	/// Lock periods stakers can choose from, along with their reward multipliers.
+	#[pallet::storage]
+	pub type LockTiers<T: Config> =
+		StorageValue<_, BoundedVec<LockTier<BlockNumberFor<T>>, T::MaxLockTiers>, ValueQuery>;
+
+	/// Locks on the stake deposited by stakers.
+	///
+	/// First key is the candidate, and second one is the staker.
+	#[pallet::storage]
+	pub type StakeLocks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		StakeLock<BlockNumberFor<T>, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// Weight added by the lock multipliers to the stake of a candidate, on top of its actual
+	/// stake, when distributing rewards.
+	#[pallet::storage]
+	pub type CandidateLockBonus<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// Candidates staked on by a user.
+	///
+	/// Cannot hold more than `MaxStakedCandidates` candidates.
+	#[pallet::storage]
+	pub type StakedCandidates<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<T::AccountId, T::MaxStakedCandidates>,
+		ValueQuery,
+	>;
+
+	/// Funds bonded through [`StakingInterface`] that are not staked on any candidate yet.
+	///
+	/// They are held by the pallet, but do not back any candidate nor receive rewards until
+	/// they are nominated.
+	#[pallet::storage]
+	pub type IdleStake<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// Funds unbonded through [`StakingInterface`] from the [`IdleStake`] of an account.
 	///
-	/// Cannot be higher than `MaxStakedCandidates`.
+	/// As they do not back any candidate, they are kept apart from [`UnstakingRequests`]. Funds
+	/// unbonded again before being released are merged, and released once the delay of the last
+	/// unbonding passes.
+	#[pallet::storage]
+	pub type IdleUnbonding<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		IdleUnbond<BlockNumberFor<T>, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// Total amount held by the pallet for staking. This includes the stake deposited in
+	/// candidates, the candidacy bonds, the [`IdleStake`] and the funds waiting in
+	/// [`IdleUnbonding`] and [`UnstakingRequests`], as all of them remain at stake until released.
 	#[pallet::storage]
-	pub type StakeCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+	pub type TotalStaked<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
 	/// Unstaking requests for an account.
 	///
@@ -290,13 +727,56 @@ pub mod pallet {
 		_,
 		Blake2_128Concat,
 		T::AccountId,
-		BoundedVec<UnstakeRequest<BlockNumberFor<T>, BalanceOf<T>>, T::MaxStakedCandidates>,
+		BoundedVec<
+			UnstakeRequest<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>,
+			T::MaxStakedCandidates,
+		>,
 		ValueQuery,
 	>;
 
-	/// Percentage of rewards that would go for collators.
+	/// Accounts with requests in [`UnstakingRequests`] towards a given candidate, together with
+	/// the number of such requests each of them has.
+	///
+	/// This allows slashing the funds pending to be unstaked from a candidate without going
+	/// through the unstaking requests of every account.
+	#[pallet::storage]
+	pub type CandidateUnstakers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		OptionQuery,
+	>;
+
+	/// Number of requests in [`UnstakingRequests`] towards a given candidate.
+	///
+	/// It cannot exceed [`Pallet::max_candidate_unstake_requests`].
+	#[pallet::storage]
+	pub type CandidateUnstakeRequestCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Block in which a staker last moved stake between candidates.
+	///
+	/// Only tracked when [`Config::StakeMoveCooldown`] is not zero.
+	#[pallet::storage]
+	pub type LastStakeMove<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Maximum commission a candidate can take from the rewards.
+	#[pallet::storage]
+	pub type MaxCommission<T: Config> = StorageValue<_, Percent, ValueQuery>;
+
+	/// Commission increases requested by candidates that did not take effect yet.
 	#[pallet::storage]
-	pub type CollatorRewardPercentage<T: Config> = StorageValue<_, Percent, ValueQuery>;
+	pub type CommissionChangeRequests<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		CommissionChangeRequest<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
 
 	/// Per-block extra reward.
 	#[pallet::storage]
@@ -307,6 +787,14 @@ pub mod pallet {
 	pub type PendingExCandidates<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
 
+	/// Candidates flagged to be kicked out when the last session ended, along with the reason.
+	/// They are no longer selected as collators, and are removed in `on_initialize` within
+	/// [`Config::MaxKickWeight`]. Candidates that cannot be removed lose the flag until the next
+	/// session ends.
+	#[pallet::storage]
+	pub type StaleCandidates<T: Config> =
+		CountedStorageMap<_, Blake2_128Concat, T::AccountId, KickReason, OptionQuery>;
+
 	/// Blocks produced in the current session. First value the total,
 	/// and second is blocks produced by candidates only (not invulnerables).
 	#[pallet::storage]
@@ -334,24 +822,115 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type CurrentSession<T: Config> = StorageValue<_, SessionIndex, ValueQuery>;
 
+	/// Distribution of rewards among stakers that is still in progress.
+	#[pallet::storage]
+	pub type CurrentRewardCursor<T: Config> =
+		StorageValue<_, RewardCursor<T::AccountId, BalanceOf<T>>, OptionQuery>;
+
+	/// Slashes reported but not applied yet, keyed by the session they will be applied in.
+	#[pallet::storage]
+	pub type UnappliedSlashes<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		SessionIndex,
+		BoundedVec<UnappliedSlash<T::AccountId>, T::MaxCandidates>,
+		ValueQuery,
+	>;
+
+	/// Number of slashes in [`UnappliedSlashes`] reported against a given candidate.
+	///
+	/// Unstaking requests coming from a candidate with pending slashes cannot be claimed.
+	#[pallet::storage]
+	pub type PendingSlashes<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Rewards obtained per unit of stake by the stakers of a candidate in a given session.
+	///
+	/// First key is the session, and second one is the candidate. Only used when rewards are
+	/// claimed by stakers.
+	#[pallet::storage]
+	pub type RewardPerStake<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Blake2_128Concat,
+		T::AccountId,
+		FixedU128,
+		OptionQuery,
+	>;
+
+	/// Rewards recorded for stakers in a given session that were not claimed yet.
+	#[pallet::storage]
+	pub type UnclaimedRewards<T: Config> =
+		StorageMap<_, Twox64Concat, SessionIndex, BalanceOf<T>, ValueQuery>;
+
+	/// Last session a staker claimed rewards for from a given candidate.
+	///
+	/// First key is the candidate, and second one is the staker.
+	#[pallet::storage]
+	pub type LastClaimedSession<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		SessionIndex,
+		OptionQuery,
+	>;
+
 	/// Percentage of reward to be re-invested in collators.
 	#[pallet::storage]
 	pub type AutoCompound<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, Percent, ValueQuery>;
 
-	#[pallet::genesis_config]
-	#[derive(DefaultNoBound)]
-	pub struct GenesisConfig<T: Config> {
-		pub invulnerables: Vec<T::AccountId>,
-		pub candidacy_bond: BalanceOf<T>,
-		pub min_stake: BalanceOf<T>,
-		pub desired_candidates: u32,
-		pub collator_reward_percentage: Percent,
-		pub extra_reward: BalanceOf<T>,
-	}
+	/// Identifier of the next nomination pool to be created.
+	#[pallet::storage]
+	pub type NextPoolId<T> = StorageValue<_, PoolId, ValueQuery>;
 
-	#[pallet::genesis_build]
-	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+	/// Nomination pools, which stake as a single staker on behalf of their members.
+	#[pallet::storage]
+	pub type Pools<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolId, PoolInfo<T::AccountId, BalanceOf<T>>, OptionQuery>;
+
+	/// Points held by the members of each nomination pool, which represent their share of the
+	/// funds of the pool.
+	#[pallet::storage]
+	pub type PoolMembers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		PoolId,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// Funds of the accounts staking on behalf of others, such as nomination pools and the liquid
+	/// staking account. They include the stake deposited in candidates, the funds being unstaked
+	/// and the funds refunded to them, but not any balance sent directly to these accounts, so
+	/// that the value of their points cannot be manipulated through donations.
+	#[pallet::storage]
+	pub type SharedFunds<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
+
+	/// Where the staking rewards of an account are delivered to.
+	#[pallet::storage]
+	pub type RewardDestinations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, RewardDestination<T::AccountId>, ValueQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		pub invulnerables: Vec<T::AccountId>,
+		pub candidacy_bond: BalanceOf<T>,
+		pub min_stake: BalanceOf<T>,
+		pub desired_candidates: u32,
+		pub max_commission: Percent,
+		pub extra_reward: BalanceOf<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
 		fn build(&self) {
 			assert!(
 				self.min_stake <= self.candidacy_bond,
@@ -381,7 +960,7 @@ pub mod pallet {
 			CandidacyBond::<T>::put(self.candidacy_bond);
 			MinStake::<T>::put(self.min_stake);
 			Invulnerables::<T>::put(bounded_invulnerables);
-			CollatorRewardPercentage::<T>::put(self.collator_reward_percentage);
+			MaxCommission::<T>::put(self.max_commission);
 			ExtraReward::<T>::put(self.extra_reward);
 		}
 	}
@@ -401,8 +980,15 @@ pub mod pallet {
 		NewCandidacyBond { bond_amount: BalanceOf<T> },
 		/// A new candidate joined.
 		CandidateAdded { account_id: T::AccountId, deposit: BalanceOf<T> },
+		/// A candidate updated its deposit.
+		CandidateBondUpdated { account_id: T::AccountId, deposit: BalanceOf<T> },
 		/// A candidate was removed.
 		CandidateRemoved { account_id: T::AccountId },
+		/// A candidate was flagged to be kicked out, and will be removed in the following blocks.
+		CandidateFlaggedAsStale { account_id: T::AccountId, reason: KickReason },
+		/// A candidate flagged as stale could not be removed. It is no longer flagged, and will be
+		/// flagged again when the session ends if it should still be kicked out.
+		CandidateKickFailed { account_id: T::AccountId, error: DispatchError },
 		/// An account was replaced in the candidate list by another one.
 		CandidateReplaced {
 			old: T::AccountId,
@@ -426,22 +1012,98 @@ pub mod pallet {
 		},
 		/// A staker removed stake from a candidate
 		StakeRemoved { staker: T::AccountId, candidate: T::AccountId, amount: BalanceOf<T> },
+		/// Funds pending to be unstaked were staked again in a candidate.
+		StakeRebonded { staker: T::AccountId, candidate: T::AccountId, amount: BalanceOf<T> },
+		/// An account staked on a candidate on behalf of a staker.
+		StakeFunded {
+			funder: T::AccountId,
+			staker: T::AccountId,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// A staker moved stake from a candidate to another one.
+		StakeMoved {
+			staker: T::AccountId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 		/// A staking reward was delivered.
 		StakingRewardReceived { staker: T::AccountId, amount: BalanceOf<T>, session: SessionIndex },
+		/// A staker claimed the rewards obtained from staking on a candidate.
+		RewardsClaimed { staker: T::AccountId, candidate: T::AccountId, amount: BalanceOf<T> },
 		/// AutoCompound percentage was set.
 		AutoCompoundPercentageSet { staker: T::AccountId, percentage: Percent },
-		/// Collator reward percentage was set.
-		CollatorRewardPercentageSet { percentage: Percent },
+		/// The destination of the staking rewards of an account was set.
+		RewardDestinationSet { staker: T::AccountId, destination: RewardDestination<T::AccountId> },
+		/// The maximum commission for candidates was set.
+		MaxCommissionSet { commission: Percent },
+		/// A candidate set its commission.
+		CommissionSet { candidate: T::AccountId, commission: Percent },
+		/// A candidate requested a commission increase.
+		CommissionChangeRequested {
+			candidate: T::AccountId,
+			commission: Percent,
+			block: BlockNumberFor<T>,
+		},
 		/// The extra reward was set.
 		ExtraRewardSet { amount: BalanceOf<T> },
 		/// The extra reward was removed.
 		ExtraRewardRemoved { amount_left: BalanceOf<T>, receiver: Option<T::AccountId> },
 		/// The minimum amount to stake was changed.
 		NewMinStake { min_stake: BalanceOf<T> },
+		/// The minimum amount candidates must stake on themselves was changed.
+		NewMinSelfStake { min_self_stake: BalanceOf<T> },
+		/// The maximum stake a candidate can receive was changed.
+		NewMaxCandidateStake { max_candidate_stake: BalanceOf<T> },
+		/// The lock tiers stakers can choose from were changed.
+		LockTiersSet { tiers: Vec<LockTier<BlockNumberFor<T>>> },
+		/// The stake of a staker in a candidate was locked.
+		StakeLocked {
+			staker: T::AccountId,
+			candidate: T::AccountId,
+			until: BlockNumberFor<T>,
+			multiplier: FixedU128,
+		},
+		/// The lock on the stake of a staker in a candidate was removed.
+		StakeUnlocked { staker: T::AccountId, candidate: T::AccountId },
 		/// A session just ended.
 		SessionEnded { index: SessionIndex, rewards: BalanceOf<T> },
 		/// The extra reward pot account was funded.
 		ExtraRewardPotFunded { pot: T::AccountId, amount: BalanceOf<T> },
+		/// Funds held by a staker were slashed due to an offence committed by a candidate.
+		Slashed { staker: T::AccountId, candidate: T::AccountId, amount: BalanceOf<T> },
+		/// A slash was reported and will be applied in a future session.
+		SlashDeferred { candidate: T::AccountId, fraction: Perbill, session: SessionIndex },
+		/// A deferred slash was cancelled.
+		DeferredSlashCancelled { candidate: T::AccountId, session: SessionIndex },
+		/// A nomination pool was created.
+		PoolCreated { pool_id: PoolId, creator: T::AccountId, account: T::AccountId },
+		/// A member joined a nomination pool, or added funds to it.
+		PoolJoined {
+			pool_id: PoolId,
+			member: T::AccountId,
+			amount: BalanceOf<T>,
+			points: BalanceOf<T>,
+		},
+		/// A member withdrew funds from a nomination pool.
+		PoolLeft {
+			pool_id: PoolId,
+			member: T::AccountId,
+			amount: BalanceOf<T>,
+			points: BalanceOf<T>,
+		},
+		/// A nomination pool was dissolved after all its members left.
+		PoolDissolved { pool_id: PoolId },
+		/// Stake was deposited through liquid staking, and the derivative minted for it.
+		LiquidStaked {
+			staker: T::AccountId,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+			minted: BalanceOf<T>,
+		},
+		/// The liquid staking derivative was redeemed for the stake it represents.
+		LiquidUnstaked { staker: T::AccountId, amount: BalanceOf<T>, burned: BalanceOf<T> },
 	}
 
 	#[pallet::error]
@@ -474,7 +1136,8 @@ pub mod pallet {
 		TooManyUnstakingRequests,
 		/// Cannot take some candidate's slot while the candidate list is not full.
 		CanRegister,
-		/// Invalid value for MinStake. It must be lower than or equal to `CandidacyBond`.
+		/// Invalid value for MinStake. It must be lower than or equal to `CandidacyBond`, and to
+		/// `MinSelfStake` if the latter is not zero.
 		InvalidMinStake,
 		/// Invalid value for CandidacyBond. It must be higher than or equal to `MinStake`.
 		InvalidCandidacyBond,
@@ -490,6 +1153,69 @@ pub mod pallet {
 		NothingToUnstake,
 		/// Cannot add more stakers to a given candidate.
 		TooManyStakers,
+		/// The amount to unstake is zero or greater than the current stake.
+		InvalidUnstakeAmount,
+		/// The amount to rebond is zero or greater than the funds pending to be unstaked.
+		InvalidRebondAmount,
+		/// The commission is higher than [`MaxCommission`].
+		InvalidCommission,
+		/// The fraction to slash cannot be zero.
+		InvalidSlashFraction,
+		/// Too many slashes were reported for the same session.
+		TooManyUnappliedSlashes,
+		/// There is no deferred slash for the given candidate and session.
+		SlashNotFound,
+		/// Rewards are pushed to stakers, so they cannot be claimed.
+		RewardsNotClaimable,
+		/// There are no rewards to be claimed.
+		NoPendingRewards,
+		/// Stake cannot be moved to the candidate it is deposited in.
+		SameCandidate,
+		/// Stake was moved too recently. It can be moved again once [`Config::StakeMoveCooldown`]
+		/// blocks have passed.
+		StakeMoveTooSoon,
+		/// The stake of the staker in the candidate is funded by a different account.
+		StakeFundedByOtherAccount,
+		/// The caller does not fund the stake of the staker in the candidate.
+		NotStakeFunder,
+		/// Stake cannot be funded on behalf of the caller itself.
+		CannotStakeForSelf,
+		/// Invalid value for MinSelfStake. It must be either zero or at least `MinStake`.
+		InvalidMinSelfStake,
+		/// The stake of the candidate on itself would be lower than [`MinSelfStake`].
+		InsufficientSelfStake,
+		/// The deposit of the candidate would be lower than [`CandidacyBond`].
+		InsufficientDeposit,
+		/// The total stake of the candidate would exceed [`MaxCandidateStake`].
+		MaxCandidateStakeExceeded,
+		/// The lock tier does not exist or its multiplier is lower than one.
+		InvalidLockTier,
+		/// The stake is locked and the lock did not expire yet.
+		StakeLocked,
+		/// The new lock would expire before the existing one.
+		LockShortened,
+		/// The stake is not locked.
+		NotLocked,
+		/// The account has no stake.
+		NotStaker,
+		/// The account already has stake.
+		AlreadyStaker,
+		/// At least one candidate must be nominated.
+		EmptyTargets,
+		/// The nomination pool does not exist.
+		PoolNotFound,
+		/// The amount of points is zero or greater than the points of the member.
+		InvalidPoolPoints,
+		/// The nomination pool does not back any candidate.
+		NoPoolCandidates,
+		/// The nomination pool has no funds left, so it cannot be joined.
+		PoolDepleted,
+		/// Liquid staking is disabled.
+		LiquidStakingDisabled,
+		/// The liquid staking account has no funds left, so no derivative can be minted.
+		LiquidStakingDepleted,
+		/// The candidate has slashes pending to be applied.
+		PendingSlash,
 	}
 
 	#[pallet::hooks]
@@ -508,23 +1234,58 @@ pub mod pallet {
 				T::MaxCandidates::get() >= T::MaxStakedCandidates::get(),
 				"MaxCandidates must be greater than or equal to MaxStakedCandidates"
 			);
+			assert!(T::RewardPageSize::get() > 0, "RewardPageSize must be greater than zero");
+			assert!(
+				T::MaxKickWeight::get().all_gte(
+					T::DbWeight::get()
+						.reads(1)
+						.saturating_add(T::WeightInfo::kick_stale_candidate())
+				),
+				"MaxKickWeight must allow kicking at least one stale candidate"
+			);
+			assert!(!T::SessionLength::get().is_zero(), "SessionLength must be greater than zero");
+			assert!(
+				T::RewardsMode::get() == RewardsMode::Push || T::RewardsHistoryDepth::get() > 0,
+				"RewardsHistoryDepth must be greater than zero when rewards are claimed"
+			);
 		}
 
 		/// Rewards are delivered at the beginning of each block. The underlined assumption is that
-		/// the number of collators to be rewarded is lower than the number of blocks in
-		/// a given session.
+		/// the number of collators to be rewarded plus the number of pages of their stakers is
+		/// lower than the number of blocks in a given session.
+		///
+		/// Each block either rewards one collator along with the first page of its stakers, or
+		/// continues with the next page of stakers as indicated by the [`CurrentRewardCursor`],
+		/// until all collators (and their stakers) are rewarded for the previous session. Each
+		/// page contains at most [`Config::RewardPageSize`] stakers.
 		///
-		/// Only one collator and its stakers are rewarded per block, until all
-		/// collators (and their stakers) are rewarded for the previous session.
+		/// When rewards are claimed by stakers only the rewards per unit of stake are recorded
+		/// for each collator, so there are no pages of stakers to be rewarded.
+		///
+		/// Candidates flagged as stale when the last session ended are removed first, as many as
+		/// [`Config::MaxKickWeight`] allows.
 		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
-			let mut weight = T::DbWeight::get().reads_writes(1, 0);
-			let current_session = CurrentSession::<T>::get();
-			if current_session > 0 {
-				let (rewarded_stakers, compounded_stakers) =
-					Self::reward_one_collator(current_session - 1);
+			let mut weight = Self::kick_stale_candidates(T::MaxKickWeight::get());
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 0));
+			let mut maybe_cursor = CurrentRewardCursor::<T>::get();
+			if maybe_cursor.is_none() {
+				let current_session = CurrentSession::<T>::get();
+				if current_session > 0 {
+					let (cursor, locks_weight) = Self::reward_one_collator(current_session - 1);
+					maybe_cursor = cursor;
+					weight.saturating_accrue(locks_weight);
+					weight.saturating_accrue(T::WeightInfo::reward_one_collator(
+						Candidates::<T>::count(),
+					));
+					if T::RewardsMode::get() == RewardsMode::Pull {
+						weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+					}
+				}
+			}
+			if let Some(cursor) = maybe_cursor {
+				let (rewarded_stakers, compounded_stakers) = Self::reward_stakers_page(cursor);
 				if !rewarded_stakers.is_zero() {
-					weight = weight.saturating_add(T::WeightInfo::reward_one_collator(
-						CandidateList::<T>::decode_len().unwrap_or_default() as u32,
+					weight.saturating_accrue(T::WeightInfo::reward_stakers_page(
 						rewarded_stakers,
 						compounded_stakers * 100 / rewarded_stakers,
 					));
@@ -586,10 +1347,8 @@ pub mod pallet {
 
 			// don't wipe out the collator set
 			if new.is_empty() {
-				// Casting `u32` to `usize` should be safe on all machines running this.
 				ensure!(
-					CandidateList::<T>::decode_len().unwrap_or_default()
-						>= T::MinEligibleCollators::get() as usize,
+					Candidates::<T>::count() >= T::MinEligibleCollators::get(),
 					Error::<T>::TooFewEligibleCollators
 				);
 			}
@@ -664,12 +1423,22 @@ pub mod pallet {
 		/// Set the candidacy bond amount, which represents the required amount to reserve for an
 		/// account to become a candidate. The candidacy bond does not count as stake.
 		///
+		/// When the bond is increased, existing candidates have
+		/// [`Config::CandidacyBondGracePeriod`] blocks to top up their deposit via
+		/// [`Pallet::update_candidacy_bond`]. Candidates still under the new bond are removed
+		/// afterwards.
+		///
 		/// The origin for this call must be the `UpdateOrigin`.
 		#[pallet::call_index(2)]
 		#[pallet::weight(T::WeightInfo::set_candidacy_bond())]
 		pub fn set_candidacy_bond(origin: OriginFor<T>, bond: BalanceOf<T>) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
 			ensure!(bond >= MinStake::<T>::get(), Error::<T>::InvalidCandidacyBond);
+			if bond > CandidacyBond::<T>::get() {
+				CandidacyBondGraceEnd::<T>::put(
+					Self::current_block_number().saturating_add(T::CandidacyBondGracePeriod::get()),
+				);
+			}
 			CandidacyBond::<T>::put(bond);
 			Self::deposit_event(Event::NewCandidacyBond { bond_amount: bond });
 			Ok(())
@@ -679,32 +1448,21 @@ pub mod pallet {
 		/// registered session keys and (b) be able to reserve the `CandidacyBond`.
 		/// The `CandidacyBond` amount is automatically reserved from the balance of the caller.
 		///
+		/// The candidate keeps `commission` from the rewards, which cannot be higher than
+		/// [`MaxCommission`].
+		///
+		/// The caller also stakes `stake` on itself, which can be zero. The resulting self-stake,
+		/// including any stake retained from a previous candidacy, must reach [`MinSelfStake`].
+		///
 		/// This call is not available to `Invulnerable` collators.
 		#[pallet::call_index(3)]
 		#[pallet::weight(T::WeightInfo::register_as_candidate(T::MaxCandidates::get()))]
-		pub fn register_as_candidate(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin)?;
-
-			// ensure we are below limit.
-			let length: u32 = CandidateList::<T>::decode_len()
-				.unwrap_or_default()
-				.try_into()
-				.unwrap_or_default();
-			ensure!(length < T::MaxCandidates::get(), Error::<T>::TooManyCandidates);
-			ensure!(!Self::is_invulnerable(&who), Error::<T>::AlreadyInvulnerable);
-
-			let validator_key =
-				T::CollatorIdOf::convert(who.clone()).ok_or(Error::<T>::NoAssociatedCollatorId)?;
-			ensure!(
-				T::CollatorRegistration::is_registered(&validator_key),
-				Error::<T>::CollatorNotRegistered
-			);
-
-			Self::do_register_as_candidate(&who)?;
-			// Safe to do unchecked add here because we ensure above that `length <
-			// T::MaxCandidates::get()`, and since `T::MaxCandidates` is `u32` it can be at most
-			// `u32::MAX`, therefore `length + 1` cannot overflow.
-			Ok(Some(T::WeightInfo::register_as_candidate(length + 1)).into())
+		pub fn register_as_candidate(
+			origin: OriginFor<T>,
+			commission: Percent,
+			stake: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			Self::register_as_candidate_with_hint(origin, commission, stake, None)
 		}
 
 		/// Deregister `origin` as a collator candidate. No rewards will be delivered to this
@@ -720,11 +1478,11 @@ pub mod pallet {
 				Self::eligible_collators() > T::MinEligibleCollators::get(),
 				Error::<T>::TooFewEligibleCollators
 			);
-			let length = CandidateList::<T>::decode_len().unwrap_or_default();
+			let length = Candidates::<T>::count();
 			// Do remove their last authored block.
 			Self::try_remove_candidate_from_account(&who, true, true)?;
 
-			Ok(Some(T::WeightInfo::leave_intent(length.saturating_sub(1) as u32)).into())
+			Ok(Some(T::WeightInfo::leave_intent(length.saturating_sub(1))).into())
 		}
 
 		/// Add a new account `who` to the list of `Invulnerables` collators. `who` must have
@@ -771,10 +1529,7 @@ pub mod pallet {
 					.unwrap_or_default()
 					.try_into()
 					.unwrap_or(T::MaxInvulnerables::get().saturating_sub(1)),
-				CandidateList::<T>::decode_len()
-					.unwrap_or_default()
-					.try_into()
-					.unwrap_or(T::MaxCandidates::get()),
+				Candidates::<T>::count(),
 			);
 
 			Ok(Some(weight_used).into())
@@ -809,68 +1564,38 @@ pub mod pallet {
 		/// reserving the [`CandidacyBond`] and adding stake to itself. The stake added by the caller
 		/// must be greater than the existing stake of the target it is trying to replace.
 		///
+		/// The new candidate keeps `commission` from the rewards, which cannot be higher than
+		/// [`MaxCommission`].
+		///
 		/// This call will fail if the caller is already a collator candidate or invulnerable, the
 		/// caller does not have registered session keys, the target is not a collator candidate,
-		/// the list of candidates is not full,
-		/// and/or the candidacy bond or stake cannot be reserved.
+		/// the list of candidates is not full, the resulting stake of the caller on itself is lower
+		/// than [`MinSelfStake`], and/or the candidacy bond or stake cannot be reserved.
 		#[pallet::call_index(7)]
 		#[pallet::weight(T::WeightInfo::take_candidate_slot())]
 		pub fn take_candidate_slot(
 			origin: OriginFor<T>,
 			stake: BalanceOf<T>,
 			target: T::AccountId,
+			commission: Percent,
 		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-
-			ensure!(!Self::is_invulnerable(&who), Error::<T>::AlreadyInvulnerable);
-			ensure!(Self::get_candidate(&who).is_err(), Error::<T>::AlreadyCandidate);
-
-			let collator_key =
-				T::CollatorIdOf::convert(who.clone()).ok_or(Error::<T>::NoAssociatedCollatorId)?;
-			ensure!(
-				T::CollatorRegistration::is_registered(&collator_key),
-				Error::<T>::CollatorNotRegistered
-			);
-
-			// only allow this operation if the candidate list is full
-			let length = CandidateList::<T>::decode_len().unwrap_or_default();
-			ensure!(length == T::MaxCandidates::get() as usize, Error::<T>::CanRegister);
-
-			// Remove old candidate
-			let target_info = Self::try_remove_candidate_from_account(&target, true, false)?;
-			ensure!(stake > target_info.stake, Error::<T>::InsufficientStake);
-
-			// Register the new candidate
-			let candidate = Self::do_register_as_candidate(&who)?;
-			Self::do_stake_at_position(&who, stake, 0, true)?;
-
-			Self::deposit_event(Event::CandidateReplaced {
-				old: target,
-				new: who,
-				deposit: candidate.deposit,
-				stake,
-			});
-			Ok(())
+			Self::take_candidate_slot_with_hint(origin, stake, target, commission, None)
 		}
 
 		/// Allows a user to stake on a collator candidate.
 		///
 		/// The call will fail if:
 		///     - `origin` does not have the at least `MinStake` deposited in the candidate.
-		///     - `candidate` is not in the [`CandidateList`].
+		///     - `candidate` is not in [`Candidates`].
 		#[pallet::call_index(8)]
-		#[pallet::weight(T::WeightInfo::stake(T::MaxCandidates::get()))]
+		#[pallet::weight(T::WeightInfo::stake(T::MaxCandidates::get())
+			.saturating_add(Pallet::<T>::settle_rewards_weight()))]
 		pub fn stake(
 			origin: OriginFor<T>,
 			candidate: T::AccountId,
 			stake: BalanceOf<T>,
 		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin)?;
-			Self::do_stake_for_account(&who, &candidate, stake, true)?;
-			Ok(Some(T::WeightInfo::stake(
-				CandidateList::<T>::decode_len().unwrap_or_default() as u32
-			))
-			.into())
+			Self::stake_with_hint(origin, candidate, stake, None)
 		}
 
 		/// Removes stake from a collator candidate.
@@ -878,28 +1603,39 @@ pub mod pallet {
 		/// If the candidate is an active collator, the caller will get the funds after a delay. Otherwise,
 		/// funds will be returned immediately.
 		///
-		/// The candidate will have its position in the [`CandidateList`] updated.
+		/// The candidate will have its position in the list of candidates updated.
 		#[pallet::call_index(9)]
 		#[pallet::weight(T::WeightInfo::unstake_from(
 			T::MaxCandidates::get(),
 			T::MaxStakedCandidates::get()
-		))]
+		).saturating_add(Pallet::<T>::settle_rewards_weight()))]
 		pub fn unstake_from(
 			origin: OriginFor<T>,
 			candidate: T::AccountId,
 		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin)?;
-			let (has_penalty, maybe_position) = match Self::get_candidate(&candidate) {
-				Ok(pos) => (true, Some(pos)),
-				Err(_) => (false, None),
-			};
-			let (_, unstaking_requests) =
-				Self::do_unstake(&who, &candidate, has_penalty, maybe_position, true)?;
-			Ok(Some(T::WeightInfo::unstake_from(
-				CandidateList::<T>::decode_len().unwrap_or_default() as u32,
-				unstaking_requests,
-			))
-			.into())
+			Self::unstake_from_with_hint(origin, candidate, None)
+		}
+
+		/// Removes part of the stake deposited in a collator candidate.
+		///
+		/// The remaining stake must be either zero or at least [`MinStake`]. The user keeps the
+		/// session in which the stake was first deposited, hence it remains eligible for rewards.
+		///
+		/// If the candidate is an active collator, the caller will get the funds after a delay. Otherwise,
+		/// funds will be returned immediately.
+		///
+		/// The candidate will have its position in the list of candidates updated.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::unstake_amount(
+			T::MaxCandidates::get(),
+			T::MaxStakedCandidates::get()
+		).saturating_add(Pallet::<T>::settle_rewards_weight()))]
+		pub fn unstake_amount(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			Self::unstake_amount_with_hint(origin, candidate, amount, None)
 		}
 
 		/// Removes all stake of a user from all candidates.
@@ -909,33 +1645,54 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::unstake_all(
 			T::MaxCandidates::get(),
 			T::MaxStakedCandidates::get()
-		))]
+		).saturating_add(Pallet::<T>::settle_rewards_weight()
+			.saturating_mul(T::MaxStakedCandidates::get().into())))]
 		pub fn unstake_all(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			let candidate_map: BTreeMap<T::AccountId, usize> = CandidateList::<T>::get()
-				.iter()
-				.enumerate()
-				.map(|(pos, c)| (c.who.clone(), pos))
-				.collect();
 			let mut operations = 0;
-			for (candidate, staker, info) in Stake::<T>::iter() {
-				if staker == who && !info.stake.is_zero() {
-					let (is_candidate, maybe_position) = match candidate_map.get(&candidate) {
-						None => (false, None),
-						Some(pos) => (true, Some(*pos)),
-					};
-					Self::do_unstake(&who, &candidate, is_candidate, maybe_position, false)?;
-					operations += 1;
-				}
+			for candidate in StakedCandidates::<T>::get(&who) {
+				let is_candidate = Candidates::<T>::contains_key(&candidate);
+				Self::do_unstake(
+					&who,
+					&candidate,
+					is_candidate,
+					true,
+					StakeChangeReason::Unstaked,
+				)?;
+				operations += 1;
 			}
-			CandidateList::<T>::mutate(|candidates| candidates.sort_by_key(|c| c.stake));
-			Ok(Some(T::WeightInfo::unstake_all(
-				CandidateList::<T>::decode_len().unwrap_or_default() as u32,
-				operations,
-			))
+			Ok(Some(
+				T::WeightInfo::unstake_all(Candidates::<T>::count(), operations).saturating_add(
+					Self::settle_rewards_weight().saturating_mul(operations.into()),
+				),
+			)
 			.into())
 		}
 
+		/// Stakes again on a candidate funds that are still waiting for the unstaking delay to pass.
+		///
+		/// Funds are taken from the pending [`UnstakeRequest`]s towards `candidate`, starting from
+		/// the ones that would be released the latest. Requests that can already be claimed cannot
+		/// be rebonded. As funds remain on hold, there is no need for the caller to have free
+		/// balance.
+		///
+		/// The call will fail if:
+		///     - `amount` is zero or greater than the funds pending to be unstaked from `candidate`.
+		///     - `candidate` is not in [`Candidates`].
+		///     - the resulting stake in `candidate` does not reach `MinStake`.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::rebond(
+			T::MaxCandidates::get(),
+			T::MaxStakedCandidates::get()
+		).saturating_add(Pallet::<T>::settle_rewards_weight()))]
+		pub fn rebond(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			Self::rebond_with_hint(origin, candidate, amount, None)
+		}
+
 		/// Claims all pending [`UnstakeRequest`] for a given account.
 		#[pallet::call_index(11)]
 		#[pallet::weight(T::WeightInfo::claim(T::MaxStakedCandidates::get()))]
@@ -965,19 +1722,19 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Sets the percentage of rewards that collators will take for producing blocks.
+		/// Sets the maximum percentage of rewards that candidates can take for producing blocks.
+		///
+		/// Candidates whose commission is higher than the new maximum will be rewarded as if their
+		/// commission was the maximum one.
 		///
 		/// The origin for this call must be the `UpdateOrigin`.
-		#[pallet::call_index(13)]
-		#[pallet::weight(T::WeightInfo::set_collator_reward_percentage())]
-		pub fn set_collator_reward_percentage(
-			origin: OriginFor<T>,
-			percent: Percent,
-		) -> DispatchResult {
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::set_max_commission())]
+		pub fn set_max_commission(origin: OriginFor<T>, commission: Percent) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
 
-			CollatorRewardPercentage::<T>::put(percent);
-			Self::deposit_event(Event::CollatorRewardPercentageSet { percentage: percent });
+			MaxCommission::<T>::put(commission);
+			Self::deposit_event(Event::MaxCommissionSet { commission });
 			Ok(())
 		}
 
@@ -1002,6 +1759,9 @@ pub mod pallet {
 
 		/// Sets minimum amount that can be staked on a candidate.
 		///
+		/// The amount cannot be higher than [`CandidacyBond`], nor than [`MinSelfStake`] unless
+		/// the latter is zero.
+		///
 		/// The origin for this call must be the `UpdateOrigin`.
 		#[pallet::call_index(15)]
 		#[pallet::weight(T::WeightInfo::set_minimum_stake())]
@@ -1011,12 +1771,211 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
 			ensure!(new_min_stake <= CandidacyBond::<T>::get(), Error::<T>::InvalidMinStake);
+			let min_self_stake = MinSelfStake::<T>::get();
+			ensure!(
+				min_self_stake.is_zero() || min_self_stake >= new_min_stake,
+				Error::<T>::InvalidMinStake
+			);
 
 			MinStake::<T>::put(new_min_stake);
 			Self::deposit_event(Event::NewMinStake { min_stake: new_min_stake });
 			Ok(())
 		}
 
+		/// Sets the minimum amount candidates must stake on themselves. Candidates whose stake
+		/// on themselves is lower are removed when the next session starts.
+		///
+		/// The amount must be either zero, which disables the requirement, or at least
+		/// [`MinStake`].
+		///
+		/// The origin for this call must be the `UpdateOrigin`.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::set_minimum_self_stake())]
+		pub fn set_minimum_self_stake(
+			origin: OriginFor<T>,
+			new_min_self_stake: BalanceOf<T>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				new_min_self_stake.is_zero() || new_min_self_stake >= MinStake::<T>::get(),
+				Error::<T>::InvalidMinSelfStake
+			);
+
+			MinSelfStake::<T>::put(new_min_self_stake);
+			Self::deposit_event(Event::NewMinSelfStake { min_self_stake: new_min_self_stake });
+			Ok(())
+		}
+
+		/// Sets the maximum total stake a candidate can receive, or removes the limit if zero.
+		///
+		/// Candidates already over the new limit keep their stake, but the part above the limit
+		/// does not count towards their position in the list of candidates, which is sorted again.
+		///
+		/// The origin for this call must be the `UpdateOrigin`.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::set_max_candidate_stake(T::MaxCandidates::get()))]
+		pub fn set_max_candidate_stake(
+			origin: OriginFor<T>,
+			max_candidate_stake: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			MaxCandidateStake::<T>::put(max_candidate_stake);
+			let candidates = Self::sort_candidate_list();
+			Self::deposit_event(Event::NewMaxCandidateStake { max_candidate_stake });
+			Ok(Some(T::WeightInfo::set_max_candidate_stake(candidates)).into())
+		}
+
+		/// Sets the lock tiers stakers can choose from when locking their stake.
+		///
+		/// All multipliers must be at least one. Existing locks keep the multiplier they were
+		/// created with.
+		///
+		/// The origin for this call must be the `UpdateOrigin`.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::set_lock_tiers(tiers.len() as u32))]
+		pub fn set_lock_tiers(
+			origin: OriginFor<T>,
+			tiers: BoundedVec<LockTier<BlockNumberFor<T>>, T::MaxLockTiers>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				tiers.iter().all(|tier| tier.multiplier >= FixedU128::from_u32(1)),
+				Error::<T>::InvalidLockTier
+			);
+
+			LockTiers::<T>::put(&tiers);
+			Self::deposit_event(Event::LockTiersSet { tiers: tiers.into_inner() });
+			Ok(())
+		}
+
+		/// Stakes `stake` on `candidate` and locks the whole stake of the caller in it for the
+		/// duration of the lock tier at index `tier` in [`LockTiers`]. Zero can be passed as
+		/// `stake` to lock the existing stake only.
+		///
+		/// Locked stake cannot be removed until the lock expires, unless the candidate leaves. In
+		/// exchange, the multiplier of the tier is applied to the stake when distributing rewards.
+		/// Stake added later to the same candidate is also locked. An existing lock can only be
+		/// extended, in which case the new multiplier is applied.
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::stake_with_lock(T::MaxCandidates::get())
+			.saturating_add(Pallet::<T>::settle_rewards_weight()))]
+		pub fn stake_with_lock(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			stake: BalanceOf<T>,
+			tier: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_funded(&who, &candidate)?;
+			let tier = LockTiers::<T>::get()
+				.get(tier as usize)
+				.cloned()
+				.ok_or(Error::<T>::InvalidLockTier)?;
+			let until = Self::current_block_number().saturating_add(tier.duration);
+			if let Some(lock) = StakeLocks::<T>::get(&candidate, &who) {
+				ensure!(until >= lock.until, Error::<T>::LockShortened);
+			}
+
+			let moved = if stake.is_zero() {
+				ensure!(Candidates::<T>::contains_key(&candidate), Error::<T>::NotCandidate);
+				ensure!(
+					!Stake::<T>::get(&candidate, &who).stake.is_zero(),
+					Error::<T>::InsufficientStake
+				);
+				// The lock bonus is about to change, so pending rewards must be settled first.
+				Self::settle_rewards(&who, &candidate);
+				0
+			} else {
+				Self::do_stake_for_account(
+					&who,
+					&candidate,
+					stake,
+					true,
+					true,
+					StakeChangeReason::Staked,
+				)?
+			};
+			// The bonus of the existing lock, if any, is replaced when refreshing it.
+			let bonus = StakeLocks::<T>::get(&candidate, &who)
+				.map(|lock| lock.bonus)
+				.unwrap_or_default();
+			StakeLocks::<T>::insert(
+				&candidate,
+				&who,
+				StakeLock { until, multiplier: tier.multiplier, bonus },
+			);
+			Self::refresh_lock_bonus(&who, &candidate);
+
+			Self::deposit_event(Event::StakeLocked {
+				staker: who,
+				candidate,
+				until,
+				multiplier: tier.multiplier,
+			});
+			Ok(Some(
+				T::WeightInfo::stake_with_lock(moved).saturating_add(Self::settle_rewards_weight()),
+			)
+			.into())
+		}
+
+		/// Removes the lock on the stake of `staker` in `candidate` once it expired. Expired locks
+		/// no longer get the reward multiplier, and are removed anyway before the rewards of the
+		/// candidate are shared.
+		///
+		/// This call can be made by any account.
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::unlock_stake()
+			.saturating_add(Pallet::<T>::settle_rewards_weight()))]
+		pub fn unlock_stake(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			staker: T::AccountId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let lock = StakeLocks::<T>::get(&candidate, &staker).ok_or(Error::<T>::NotLocked)?;
+			ensure!(Self::current_block_number() >= lock.until, Error::<T>::StakeLocked);
+
+			Self::settle_rewards(&staker, &candidate);
+			Self::remove_stake_lock(&staker, &candidate);
+			Ok(())
+		}
+
+		/// Updates the deposit of the caller, which must be a candidate. The deposit cannot be
+		/// lower than [`CandidacyBond`].
+		///
+		/// Increasing the deposit holds the difference from the balance of the caller. When it is
+		/// decreased, the difference is returned after [`Config::CollatorUnstakingDelay`] blocks.
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::update_candidacy_bond())]
+		pub fn update_candidacy_bond(
+			origin: OriginFor<T>,
+			new_deposit: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(new_deposit >= CandidacyBond::<T>::get(), Error::<T>::InsufficientDeposit);
+			let mut candidate = Self::get_candidate(&who)?;
+
+			if new_deposit > candidate.deposit {
+				Self::hold_stake(&who, new_deposit.saturating_sub(candidate.deposit))?;
+			} else if new_deposit < candidate.deposit {
+				Self::add_unstake_request(
+					&who,
+					&who,
+					candidate.deposit.saturating_sub(new_deposit),
+					T::CollatorUnstakingDelay::get(),
+				)?;
+			}
+			candidate.deposit = new_deposit;
+			Candidates::<T>::insert(&who, candidate);
+
+			Self::deposit_event(Event::CandidateBondUpdated {
+				account_id: who,
+				deposit: new_deposit,
+			});
+			Ok(())
+		}
+
 		/// Stops the extra rewards.
 		///
 		/// The origin for this call must be the `UpdateOrigin`.
@@ -1062,450 +2021,2523 @@ pub mod pallet {
 			});
 			Ok(())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		/// Get a unique, inaccessible account ID from the `PotId`.
-		pub fn account_id() -> T::AccountId {
-			T::PotId::get().into_account_truncating()
+		/// Sets the percentage of rewards the caller, which must be a candidate, keeps for
+		/// producing blocks.
+		///
+		/// Decreasing the commission takes effect immediately. Increases take effect after
+		/// [`Config::CommissionChangeDelay`] blocks, so that stakers have time to react.
+		/// The commission cannot be higher than [`MaxCommission`].
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::set_commission(T::MaxCandidates::get()))]
+		pub fn set_commission(
+			origin: OriginFor<T>,
+			commission: Percent,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(commission <= MaxCommission::<T>::get(), Error::<T>::InvalidCommission);
+			let mut candidate = Self::get_candidate(&who)?;
+
+			if commission <= candidate.commission {
+				candidate.commission = commission;
+				Candidates::<T>::insert(&who, candidate);
+				CommissionChangeRequests::<T>::remove(&who);
+				Self::deposit_event(Event::CommissionSet { candidate: who, commission });
+			} else {
+				let block = Self::current_block_number() + T::CommissionChangeDelay::get();
+				CommissionChangeRequests::<T>::insert(
+					&who,
+					CommissionChangeRequest { block, commission },
+				);
+				Self::deposit_event(Event::CommissionChangeRequested {
+					candidate: who,
+					commission,
+					block,
+				});
+			}
+			Ok(Some(T::WeightInfo::set_commission(Candidates::<T>::count())).into())
 		}
 
-		/// Get a unique, inaccessible account ID from the `ExtraRewardPotId`.
-		pub fn extra_reward_account_id() -> T::AccountId {
-			T::ExtraRewardPotId::get().into_account_truncating()
-		}
+		/// Reports an offence committed by a candidate, so that `fraction` of its candidacy bond
+		/// and of the stake backing it is slashed after [`Config::SlashDeferDuration`] sessions.
+		///
+		/// The origin for this call must be the `UpdateOrigin`.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::slash_candidate(T::MaxCandidates::get())
+			.saturating_add(Pallet::<T>::max_apply_slash_weight()))]
+		pub fn slash_candidate(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			fraction: Perbill,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::do_report_offence(&candidate, fraction)?;
 
-		/// Checks whether a given account is a candidate and returns its position if successful.
-		pub fn get_candidate(account: &T::AccountId) -> Result<usize, DispatchError> {
-			match CandidateList::<T>::get().iter().position(|c| c.who == *account) {
-				Some(pos) => Ok(pos),
-				None => Err(Error::<T>::NotCandidate.into()),
+			let mut weight = T::WeightInfo::slash_candidate(Candidates::<T>::count());
+			if T::SlashDeferDuration::get().is_zero() {
+				weight.saturating_accrue(Self::max_apply_slash_weight());
 			}
+			Ok(Some(weight).into())
 		}
 
-		/// Checks whether a given account is an invulnerable.
-		pub fn is_invulnerable(account: &T::AccountId) -> bool {
-			Invulnerables::<T>::get().binary_search(account).is_ok()
+		/// Cancels a slash that was deferred to the given session.
+		///
+		/// The origin for this call must be the `UpdateOrigin`.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::cancel_deferred_slash(T::MaxCandidates::get()))]
+		pub fn cancel_deferred_slash(
+			origin: OriginFor<T>,
+			session: SessionIndex,
+			candidate: T::AccountId,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			UnappliedSlashes::<T>::try_mutate_exists(session, |maybe_slashes| -> DispatchResult {
+				let slashes = maybe_slashes.as_mut().ok_or(Error::<T>::SlashNotFound)?;
+				let position = slashes
+					.iter()
+					.position(|slash| slash.candidate == candidate)
+					.ok_or(Error::<T>::SlashNotFound)?;
+				slashes.remove(position);
+				if slashes.is_empty() {
+					*maybe_slashes = None;
+				}
+				Ok(())
+			})?;
+			Self::note_slash_resolved(&candidate);
+			Self::deposit_event(Event::DeferredSlashCancelled { candidate, session });
+			Ok(())
+		}
+
+		/// Claims the rewards the caller obtained from staking on `candidate` during the last
+		/// [`Config::RewardsHistoryDepth`] sessions. Part of the rewards is staked again on the
+		/// candidate according to the auto-compound percentage of the caller.
+		///
+		/// Only available when rewards are claimed by stakers.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::claim_rewards(T::RewardsHistoryDepth::get()))]
+		pub fn claim_rewards(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let sessions = Self::do_claim_rewards_and_sort(&who, &candidate)?;
+			Ok(Some(T::WeightInfo::claim_rewards(sessions)).into())
+		}
+
+		/// Claims the rewards `staker` obtained from staking on `candidate` on its behalf. Rewards
+		/// are delivered to `staker` as if it claimed them via [`Pallet::claim_rewards`].
+		///
+		/// Only available when rewards are claimed by stakers.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::claim_rewards(T::RewardsHistoryDepth::get()))]
+		pub fn claim_rewards_for(
+			origin: OriginFor<T>,
+			staker: T::AccountId,
+			candidate: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let sessions = Self::do_claim_rewards_and_sort(&staker, &candidate)?;
+			Ok(Some(T::WeightInfo::claim_rewards(sessions)).into())
+		}
+
+		/// Moves `amount` from the stake deposited in `from` to `to`, without going through the
+		/// unstaking delay. Funds remain on hold during the whole operation.
+		///
+		/// The stake left in `from` must be either zero or at least [`MinStake`]. Once stake is
+		/// moved, it cannot be moved again until [`Config::StakeMoveCooldown`] blocks have passed.
+		///
+		/// As the moved stake already counts towards the rewards of `from` in the current session,
+		/// the whole stake of the caller in `to` only receives rewards from the next session on.
+		///
+		/// The call will fail if:
+		///     - `from` and `to` are the same account.
+		///     - `from` has slashes pending to be applied.
+		///     - `amount` is zero or greater than the stake deposited in `from`.
+		///     - `to` is not in [`Candidates`].
+		///     - the resulting stake in `to` does not reach `MinStake`.
+		///     - the caller moved stake less than [`Config::StakeMoveCooldown`] blocks ago.
+		///     - the stake of the caller in `from` or `to` is funded by another account.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::move_stake(T::MaxCandidates::get())
+			.saturating_add(Pallet::<T>::settle_rewards_weight().saturating_mul(2)))]
+		pub fn move_stake(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(from != to, Error::<T>::SameCandidate);
+			ensure!(!Self::has_pending_slash(&from), Error::<T>::PendingSlash);
+			Self::ensure_stake_move_allowed(&who)?;
+
+			Self::ensure_not_funded(&who, &from)?;
+			Self::ensure_not_funded(&who, &to)?;
+			Self::settle_rewards(&who, &from);
+			Self::ensure_can_unstake(&who, &from, amount)?;
+			let moved_from =
+				Self::remove_stake(&who, &from, amount, true, StakeChangeReason::Moved);
+			let moved_to = Self::do_stake_for_account(
+				&who,
+				&to,
+				amount,
+				true,
+				false,
+				StakeChangeReason::Moved,
+			)?;
+
+			Self::note_stake_move(&who);
+			Self::deposit_event(Event::StakeMoved { staker: who, from, to, amount });
+			Ok(Some(
+				T::WeightInfo::move_stake(moved_from.max(moved_to))
+					.saturating_add(Self::settle_rewards_weight().saturating_mul(2)),
+			)
+			.into())
+		}
+
+		/// Stakes `amount` on `candidate` on behalf of `beneficiary`.
+		///
+		/// Funds are transferred from the caller to `beneficiary` and held there, so that
+		/// `beneficiary` is the staker receiving the rewards. Whenever the position is unstaked,
+		/// either via [`Pallet::unstake_for`] or by `beneficiary`, funds are returned to the caller,
+		/// including any rewards auto-compounded on it.
+		///
+		/// The call will fail if:
+		///     - `beneficiary` is the caller.
+		///     - `beneficiary` already has stake in `candidate` not funded by the caller.
+		///     - `candidate` is not in [`Candidates`].
+		///     - the resulting stake in `candidate` does not reach `MinStake`.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::stake_for(T::MaxCandidates::get())
+			.saturating_add(Pallet::<T>::settle_rewards_weight()))]
+		pub fn stake_for(
+			origin: OriginFor<T>,
+			beneficiary: T::AccountId,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(who != beneficiary, Error::<T>::CannotStakeForSelf);
+			match StakeFunders::<T>::get(&candidate, &beneficiary) {
+				Some(funder) => ensure!(funder == who, Error::<T>::StakeFundedByOtherAccount),
+				None => ensure!(
+					Stake::<T>::get(&candidate, &beneficiary).stake.is_zero(),
+					Error::<T>::StakeFundedByOtherAccount
+				),
+			}
+
+			T::Currency::transfer(&who, &beneficiary, amount, Preserve)?;
+			let moved = Self::do_stake_for_account(
+				&beneficiary,
+				&candidate,
+				amount,
+				true,
+				true,
+				StakeChangeReason::Staked,
+			)?;
+			StakeFunders::<T>::insert(&candidate, &beneficiary, &who);
+			Self::deposit_event(Event::StakeFunded {
+				funder: who,
+				staker: beneficiary,
+				candidate,
+				amount,
+			});
+			Ok(Some(T::WeightInfo::stake_for(moved).saturating_add(Self::settle_rewards_weight()))
+				.into())
+		}
+
+		/// Withdraws the stake deposited in `candidate` on behalf of `beneficiary` via
+		/// [`Pallet::stake_for`].
+		///
+		/// If the candidate is an active collator, the caller will get the funds after a delay.
+		/// Otherwise, funds will be returned immediately.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::unstake_for(
+			T::MaxCandidates::get(),
+			T::MaxStakedCandidates::get()
+		).saturating_add(Pallet::<T>::settle_rewards_weight()))]
+		pub fn unstake_for(
+			origin: OriginFor<T>,
+			beneficiary: T::AccountId,
+			candidate: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				StakeFunders::<T>::get(&candidate, &beneficiary) == Some(who),
+				Error::<T>::NotStakeFunder
+			);
+			let has_penalty = Candidates::<T>::contains_key(&candidate);
+			let (moved, unstaking_requests) = Self::do_unstake(
+				&beneficiary,
+				&candidate,
+				has_penalty,
+				true,
+				StakeChangeReason::Unstaked,
+			)?;
+			Ok(Some(
+				T::WeightInfo::unstake_for(moved, unstaking_requests)
+					.saturating_add(Self::settle_rewards_weight()),
+			)
+			.into())
+		}
+
+		/// Sets where the staking rewards of the caller are delivered to.
+		///
+		/// Rewards obtained by candidates as commission are never staked again, so they are
+		/// transferred to the candidate when the destination is [`RewardDestination::Staked`].
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::set_reward_destination())]
+		pub fn set_reward_destination(
+			origin: OriginFor<T>,
+			destination: RewardDestination<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_reward_destination(&who, destination);
+			Ok(())
+		}
+
+		/// Creates a nomination pool staking on `candidates`, and joins it with `amount`.
+		///
+		/// The pool stakes as a single staker, so its members can stake amounts lower than
+		/// [`MinStake`] and share a single slot of [`Config::MaxStakers`] in every candidate.
+		/// The existential deposit is transferred from the caller to the pool account, and
+		/// returned when the pool is dissolved.
+		///
+		/// `amount` is split evenly among `candidates`, so every candidate must receive at least
+		/// [`MinStake`].
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::create_pool(candidates.len() as u32)
+			.saturating_add(Pallet::<T>::settle_rewards_weight()
+				.saturating_mul(candidates.len() as u64)))]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			candidates: BoundedVec<T::AccountId, T::MaxStakedCandidates>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut candidates = candidates.into_inner();
+			candidates.sort();
+			candidates.dedup();
+			ensure!(!candidates.is_empty(), Error::<T>::NoPoolCandidates);
+
+			let pool_id = NextPoolId::<T>::get();
+			let pool_account = Self::pool_account_id(pool_id);
+			T::Currency::transfer(&who, &pool_account, T::Currency::minimum_balance(), Preserve)?;
+			Pools::<T>::insert(
+				pool_id,
+				PoolInfo { creator: who.clone(), points: Zero::zero(), members: 0 },
+			);
+			NextPoolId::<T>::put(pool_id.saturating_add(1));
+			SharedFunds::<T>::insert(&pool_account, BalanceOf::<T>::zero());
+			// Rewards are staked again, so that they are shared among members through the value
+			// of their points.
+			RewardDestinations::<T>::insert(&pool_account, RewardDestination::Staked);
+			Self::deposit_event(Event::PoolCreated {
+				pool_id,
+				creator: who.clone(),
+				account: pool_account,
+			});
+			Self::do_join_pool(&who, pool_id, amount, candidates)
+		}
+
+		/// Joins the nomination pool `pool_id` with `amount`, or adds `amount` to the funds of the
+		/// caller in it.
+		///
+		/// The caller receives points for the share of the funds of the pool it contributes.
+		/// Funds are split evenly among the candidates the pool backs.
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::join_pool(T::MaxStakedCandidates::get())
+			.saturating_add(Pallet::<T>::settle_rewards_weight()
+				.saturating_mul(T::MaxStakedCandidates::get().into())))]
+		pub fn join_pool(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let candidates = StakedCandidates::<T>::get(Self::pool_account_id(pool_id))
+				.into_iter()
+				.filter(|candidate| Candidates::<T>::contains_key(candidate))
+				.collect::<Vec<_>>();
+			let count = candidates.len() as u32;
+			Self::do_join_pool(&who, pool_id, amount, candidates)?;
+			Ok(Some(
+				T::WeightInfo::join_pool(count)
+					.saturating_add(Self::settle_rewards_weight().saturating_mul(count.into())),
+			)
+			.into())
+		}
+
+		/// Withdraws the funds represented by `points` from the nomination pool `pool_id`.
+		///
+		/// Funds not staked by the pool are returned right away. The rest is unstaked from the
+		/// candidates the pool backs, starting from the last one, and returned to the caller after
+		/// [`Config::UserUnstakingDelay`] blocks through its own unstaking requests. The pool is
+		/// dissolved once all its points are withdrawn, and the last member receives whatever is
+		/// left in it.
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::leave_pool(T::MaxStakedCandidates::get())
+			.saturating_add(Pallet::<T>::settle_rewards_weight()
+				.saturating_mul(T::MaxStakedCandidates::get().into())))]
+		pub fn leave_pool(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			points: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let member_points = PoolMembers::<T>::get(pool_id, &who);
+			ensure!(!points.is_zero() && points <= member_points, Error::<T>::InvalidPoolPoints);
+
+			let pool_account = Self::pool_account_id(pool_id);
+			let count = Self::settle_all_rewards(&pool_account)?;
+			let value = SharedFunds::<T>::get(&pool_account).unwrap_or_default();
+			let amount = if points == pool.points {
+				Self::total_shared_funds(&pool_account)
+			} else {
+				FixedU128::checked_from_rational(value, pool.points)
+					.unwrap_or_default()
+					.saturating_mul_int(points)
+			};
+
+			Self::withdraw_shared_funds(&pool_account, &who, amount)?;
+			SharedFunds::<T>::insert(&pool_account, value.saturating_sub(amount));
+
+			let member_points = member_points.saturating_sub(points);
+			if member_points.is_zero() {
+				PoolMembers::<T>::remove(pool_id, &who);
+				pool.members.saturating_dec();
+			} else {
+				PoolMembers::<T>::insert(pool_id, &who, member_points);
+			}
+			pool.points.saturating_reduce(points);
+			Self::deposit_event(Event::PoolLeft { pool_id, member: who, amount, points });
+			if pool.points.is_zero() {
+				// Whatever is left, such as the existential deposit, goes back to the creator.
+				let left = T::Currency::balance(&pool_account);
+				T::Currency::transfer(&pool_account, &pool.creator, left, Expendable)?;
+				SharedFunds::<T>::remove(&pool_account);
+				RewardDestinations::<T>::remove(&pool_account);
+				Pools::<T>::remove(pool_id);
+				Self::deposit_event(Event::PoolDissolved { pool_id });
+			} else {
+				Pools::<T>::insert(pool_id, pool);
+			}
+			Ok(Some(
+				T::WeightInfo::leave_pool(count)
+					.saturating_add(Self::settle_rewards_weight().saturating_mul(count.into())),
+			)
+			.into())
+		}
+
+		/// Stakes `amount` on `candidate` through liquid staking, minting the liquid staking
+		/// derivative to the caller.
+		///
+		/// Stake is deposited by the liquid staking account, which stakes again all the rewards it
+		/// obtains. The derivative is minted at the current exchange rate, which is the
+		/// [`SharedFunds`] of the liquid staking account divided by the supply of the derivative,
+		/// so the rate grows as rewards accrue. The first caller pays the existential deposit of
+		/// the account.
+		///
+		/// The call will fail if:
+		///     - liquid staking is disabled.
+		///     - `candidate` is not in [`Candidates`].
+		///     - the resulting stake of the liquid staking account in `candidate` does not reach
+		///       `MinStake`.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::liquid_stake(T::MaxCandidates::get())
+			.saturating_add(Pallet::<T>::settle_rewards_weight()
+				.saturating_mul(T::MaxStakedCandidates::get().into())))]
+		pub fn liquid_stake(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let asset_id =
+				T::LiquidStakingAssetId::get().ok_or(Error::<T>::LiquidStakingDisabled)?;
+			let account = Self::liquid_staking_account_id();
+			let count = Self::settle_all_rewards(&account)?;
+			let value = SharedFunds::<T>::get(&account).unwrap_or_default();
+			let supply = T::Assets::total_issuance(asset_id.clone());
+			let minted = if supply.is_zero() {
+				amount
+			} else {
+				ensure!(!value.is_zero(), Error::<T>::LiquidStakingDepleted);
+				FixedU128::checked_from_rational(supply, value)
+					.unwrap_or_default()
+					.saturating_mul_int(amount)
+			};
+			ensure!(!minted.is_zero(), Error::<T>::InsufficientStake);
+
+			if T::Currency::balance(&account).is_zero() {
+				T::Currency::transfer(&who, &account, T::Currency::minimum_balance(), Preserve)?;
+				// Rewards are staked again, so that they increase the exchange rate.
+				RewardDestinations::<T>::insert(&account, RewardDestination::Staked);
+			}
+			T::Currency::transfer(&who, &account, amount, Preserve)?;
+			let moved = Self::do_stake_for_account(
+				&account,
+				&candidate,
+				amount,
+				true,
+				true,
+				StakeChangeReason::Staked,
+			)?;
+			SharedFunds::<T>::insert(&account, value.saturating_add(amount));
+			T::Assets::mint_into(asset_id, &who, minted)?;
+			Self::deposit_event(Event::LiquidStaked { staker: who, candidate, amount, minted });
+			Ok(Some(
+				T::WeightInfo::liquid_stake(moved)
+					.saturating_add(Self::settle_rewards_weight().saturating_mul(count.into())),
+			)
+			.into())
+		}
+
+		/// Burns `amount` of the liquid staking derivative held by the caller, and withdraws the
+		/// stake it represents at the current exchange rate.
+		///
+		/// Funds not staked by the liquid staking account are returned right away. The rest is
+		/// unstaked from the candidates the account backs, starting from the last one, and
+		/// returned to the caller after [`Config::UserUnstakingDelay`] blocks through its own
+		/// unstaking requests. Burning the whole supply withdraws whatever is left in the account.
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::liquid_unstake(T::MaxStakedCandidates::get())
+			.saturating_add(Pallet::<T>::settle_rewards_weight()
+				.saturating_mul(T::MaxStakedCandidates::get().into())))]
+		pub fn liquid_unstake(
+			origin: OriginFor<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let asset_id =
+				T::LiquidStakingAssetId::get().ok_or(Error::<T>::LiquidStakingDisabled)?;
+			ensure!(!amount.is_zero(), Error::<T>::InvalidUnstakeAmount);
+			let account = Self::liquid_staking_account_id();
+			let count = Self::settle_all_rewards(&account)?;
+			let value = SharedFunds::<T>::get(&account).unwrap_or_default();
+			let supply = T::Assets::total_issuance(asset_id.clone());
+			let withdrawn = if amount == supply {
+				Self::total_shared_funds(&account)
+			} else {
+				FixedU128::checked_from_rational(value, supply)
+					.unwrap_or_default()
+					.saturating_mul_int(amount)
+			};
+
+			T::Assets::burn_from(asset_id, &who, amount, Exact, Polite)?;
+			Self::withdraw_shared_funds(&account, &who, withdrawn)?;
+			if amount == supply {
+				SharedFunds::<T>::remove(&account);
+			} else {
+				SharedFunds::<T>::insert(&account, value.saturating_sub(withdrawn));
+			}
+			Self::deposit_event(Event::LiquidUnstaked {
+				staker: who,
+				amount: withdrawn,
+				burned: amount,
+			});
+			Ok(Some(
+				T::WeightInfo::liquid_unstake(count)
+					.saturating_add(Self::settle_rewards_weight().saturating_mul(count.into())),
+			)
+			.into())
+		}
+
+		/// Same as [`Pallet::register_as_candidate`], but `hint` is the candidate expected to come
+		/// right after the caller in the list of candidates. A valid hint avoids searching for the
+		/// position of the caller in the list.
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::register_as_candidate(T::MaxCandidates::get()))]
+		pub fn register_as_candidate_with_hint(
+			origin: OriginFor<T>,
+			commission: Percent,
+			stake: BalanceOf<T>,
+			hint: Option<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			// ensure we are below limit.
+			let length = Candidates::<T>::count();
+			ensure!(length < T::MaxCandidates::get(), Error::<T>::TooManyCandidates);
+			ensure!(!Self::is_invulnerable(&who), Error::<T>::AlreadyInvulnerable);
+
+			let validator_key =
+				T::CollatorIdOf::convert(who.clone()).ok_or(Error::<T>::NoAssociatedCollatorId)?;
+			ensure!(
+				T::CollatorRegistration::is_registered(&validator_key),
+				Error::<T>::CollatorNotRegistered
+			);
+
+			Self::do_register_as_candidate(&who, commission, hint.as_ref())?;
+			if !stake.is_zero() {
+				Self::do_stake_for_account(
+					&who,
+					&who,
+					stake,
+					false,
+					true,
+					StakeChangeReason::Staked,
+				)?;
+				Self::reassign_candidate_position(&who, hint.as_ref());
+			}
+			ensure!(
+				Stake::<T>::get(&who, &who).stake >= MinSelfStake::<T>::get(),
+				Error::<T>::InsufficientSelfStake
+			);
+			// Safe to do unchecked add here because we ensure above that `length <
+			// T::MaxCandidates::get()`, and since `T::MaxCandidates` is `u32` it can be at most
+			// `u32::MAX`, therefore `length + 1` cannot overflow.
+			Ok(Some(T::WeightInfo::register_as_candidate(length + 1)).into())
+		}
+		/// Same as [`Pallet::take_candidate_slot`], but `hint` is the candidate expected to come
+		/// right after the caller in the list of candidates once the stake is added.
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::take_candidate_slot())]
+		pub fn take_candidate_slot_with_hint(
+			origin: OriginFor<T>,
+			stake: BalanceOf<T>,
+			target: T::AccountId,
+			commission: Percent,
+			hint: Option<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!Self::is_invulnerable(&who), Error::<T>::AlreadyInvulnerable);
+			ensure!(!Candidates::<T>::contains_key(&who), Error::<T>::AlreadyCandidate);
+
+			let collator_key =
+				T::CollatorIdOf::convert(who.clone()).ok_or(Error::<T>::NoAssociatedCollatorId)?;
+			ensure!(
+				T::CollatorRegistration::is_registered(&collator_key),
+				Error::<T>::CollatorNotRegistered
+			);
+
+			// only allow this operation if the candidate list is full
+			let length = Candidates::<T>::count();
+			ensure!(length == T::MaxCandidates::get(), Error::<T>::CanRegister);
+
+			// Remove old candidate
+			let target_info = Self::try_remove_candidate_from_account(&target, true, false)?;
+			ensure!(stake > target_info.stake, Error::<T>::InsufficientStake);
+
+			// Register the new candidate
+			let candidate = Self::do_register_as_candidate(&who, commission, None)?;
+			Self::do_stake_for_account(&who, &who, stake, false, true, StakeChangeReason::Staked)?;
+			Self::reassign_candidate_position(&who, hint.as_ref());
+			ensure!(
+				Stake::<T>::get(&who, &who).stake >= MinSelfStake::<T>::get(),
+				Error::<T>::InsufficientSelfStake
+			);
+
+			Self::deposit_event(Event::CandidateReplaced {
+				old: target,
+				new: who,
+				deposit: candidate.deposit,
+				stake,
+			});
+			Ok(())
+		}
+		/// Same as [`Pallet::stake`], but `hint` is the candidate expected to come right after
+		/// `candidate` in the list of candidates once the stake is added. A valid hint avoids
+		/// searching for the new position of `candidate` in the list, which reduces the fees paid.
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::stake(T::MaxCandidates::get())
+			.saturating_add(Pallet::<T>::settle_rewards_weight()))]
+		pub fn stake_with_hint(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			stake: BalanceOf<T>,
+			hint: Option<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_funded(&who, &candidate)?;
+			Self::do_stake_for_account(
+				&who,
+				&candidate,
+				stake,
+				false,
+				true,
+				StakeChangeReason::Staked,
+			)?;
+			let moved = Self::reassign_candidate_position(&candidate, hint.as_ref());
+			Ok(Some(T::WeightInfo::stake(moved).saturating_add(Self::settle_rewards_weight()))
+				.into())
+		}
+		/// Same as [`Pallet::unstake_from`], but `hint` is the candidate expected to come right
+		/// after `candidate` in the list of candidates once the stake is removed.
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::unstake_from(
+			T::MaxCandidates::get(),
+			T::MaxStakedCandidates::get()
+		).saturating_add(Pallet::<T>::settle_rewards_weight()))]
+		pub fn unstake_from_with_hint(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			hint: Option<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let has_penalty = Candidates::<T>::contains_key(&candidate);
+			let (_, unstaking_requests) = Self::do_unstake(
+				&who,
+				&candidate,
+				has_penalty,
+				false,
+				StakeChangeReason::Unstaked,
+			)?;
+			let moved = Self::reassign_candidate_position(&candidate, hint.as_ref());
+			Ok(Some(
+				T::WeightInfo::unstake_from(moved, unstaking_requests)
+					.saturating_add(Self::settle_rewards_weight()),
+			)
+			.into())
+		}
+		/// Same as [`Pallet::unstake_amount`], but `hint` is the candidate expected to come right
+		/// after `candidate` in the list of candidates once the stake is removed.
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::unstake_amount(
+			T::MaxCandidates::get(),
+			T::MaxStakedCandidates::get()
+		).saturating_add(Pallet::<T>::settle_rewards_weight()))]
+		pub fn unstake_amount_with_hint(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+			hint: Option<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let has_penalty = Candidates::<T>::contains_key(&candidate);
+			let (_, unstaking_requests) = Self::do_unstake_amount(
+				&who,
+				&candidate,
+				amount,
+				has_penalty,
+				false,
+				StakeChangeReason::Unstaked,
+			)?;
+			let moved = Self::reassign_candidate_position(&candidate, hint.as_ref());
+			Ok(Some(
+				T::WeightInfo::unstake_amount(moved, unstaking_requests)
+					.saturating_add(Self::settle_rewards_weight()),
+			)
+			.into())
+		}
+		/// Same as [`Pallet::rebond`], but `hint` is the candidate expected to come right after
+		/// `candidate` in the list of candidates once the stake is added.
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::rebond(
+			T::MaxCandidates::get(),
+			T::MaxStakedCandidates::get()
+		).saturating_add(Pallet::<T>::settle_rewards_weight()))]
+		pub fn rebond_with_hint(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+			hint: Option<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::InvalidRebondAmount);
+			Self::get_candidate(&candidate)?;
+			Self::ensure_not_funded(&who, &candidate)?;
+			let unstaking_requests = Self::do_take_pending_unstake(&who, &candidate, amount)?;
+			Self::do_stake_for_account(
+				&who,
+				&candidate,
+				amount,
+				false,
+				false,
+				StakeChangeReason::Staked,
+			)?;
+			let moved = Self::reassign_candidate_position(&candidate, hint.as_ref());
+			Self::deposit_event(Event::StakeRebonded { staker: who, candidate, amount });
+			Ok(Some(
+				T::WeightInfo::rebond(moved, unstaking_requests)
+					.saturating_add(Self::settle_rewards_weight()),
+			)
+			.into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Get a unique, inaccessible account ID from the `PotId`.
+		pub fn account_id() -> T::AccountId {
+			T::PotId::get().into_account_truncating()
+		}
+
+		/// Get a unique, inaccessible account ID for the nomination pool `pool_id`.
+		pub fn pool_account_id(pool_id: PoolId) -> T::AccountId {
+			T::PotId::get().into_sub_account_truncating((b"pool", pool_id))
+		}
+
+		/// Get a unique, inaccessible account ID for the stake deposited through liquid staking.
+		pub fn liquid_staking_account_id() -> T::AccountId {
+			T::PotId::get().into_sub_account_truncating(b"liquid")
+		}
+
+		/// Get a unique, inaccessible account ID from the `ExtraRewardPotId`.
+		pub fn extra_reward_account_id() -> T::AccountId {
+			T::ExtraRewardPotId::get().into_account_truncating()
+		}
+
+		/// Checks whether a given account is a candidate and returns its information if successful.
+		pub fn get_candidate(
+			account: &T::AccountId,
+		) -> Result<CandidateInfo<T::AccountId, BalanceOf<T>>, DispatchError> {
+			Candidates::<T>::get(account).ok_or_else(|| Error::<T>::NotCandidate.into())
+		}
+
+		/// Checks whether a given account is an invulnerable.
+		pub fn is_invulnerable(account: &T::AccountId) -> bool {
+			Invulnerables::<T>::get().binary_search(account).is_ok()
+		}
+
+		/// Registers a given account as candidate.
+		///
+		/// The account has to reserve the candidacy bond. If the account was previously a candidate
+		/// the retained stake will be re-included. `hint` is the candidate expected to come right
+		/// after the new one in the list of candidates.
+		///
+		/// Returns the registered candidate.
+		pub fn do_register_as_candidate(
+			who: &T::AccountId,
+			commission: Percent,
+			hint: Option<&T::AccountId>,
+		) -> Result<CandidateInfo<T::AccountId, BalanceOf<T>>, DispatchError> {
+			ensure!(commission <= MaxCommission::<T>::get(), Error::<T>::InvalidCommission);
+			let bond = CandidacyBond::<T>::get();
+
+			// In case the staker already had non-claimed stake we calculate it now.
+			let mut stakers = 0;
+			let already_staked: BalanceOf<T> = Stake::<T>::iter_prefix_values(who).fold(
+				Zero::zero(),
+				|acc, StakeInfo { stake, .. }| {
+					if !stake.is_zero() {
+						stakers += 1;
+					}
+					acc.saturating_add(stake)
+				},
+			);
+
+			ensure!(!Candidates::<T>::contains_key(who), Error::<T>::AlreadyCandidate);
+			ensure!(
+				Candidates::<T>::count() < T::MaxCandidates::get(),
+				Error::<T>::InsertToCandidateListFailed
+			);
+			// First authored block is current block plus kick threshold to handle session delay
+			LastAuthoredBlock::<T>::insert(
+				who.clone(),
+				Self::current_block_number() + T::KickThreshold::get(),
+			);
+			let candidate = CandidateInfo {
+				who: who.clone(),
+				stake: already_staked,
+				deposit: bond,
+				stakers,
+				commission,
+			};
+			Self::hold_stake(who, bond)?;
+			Candidates::<T>::insert(who, candidate.clone());
+			Self::insert_into_candidate_list(who, already_staked, hint);
+			PendingExCandidates::<T>::remove(who);
+
+			Self::deposit_event(Event::CandidateAdded { account_id: who.clone(), deposit: bond });
+			Ok(candidate)
+		}
+
+		/// Sets where the staking rewards of `who` are delivered to.
+		fn do_set_reward_destination(
+			who: &T::AccountId,
+			destination: RewardDestination<T::AccountId>,
+		) {
+			if destination == RewardDestination::Free {
+				RewardDestinations::<T>::remove(who);
+			} else {
+				RewardDestinations::<T>::insert(who, destination.clone());
+			}
+			Self::deposit_event(Event::RewardDestinationSet { staker: who.clone(), destination });
+		}
+
+		/// Claims all pending unstaking requests for a given user.
+		///
+		/// Requests coming from a candidate with pending slashes are kept until the slashes are
+		/// either applied or cancelled, so that the funds cannot escape them.
+		///
+		/// Returns the amount of operations performed.
+		pub fn do_claim(who: &T::AccountId) -> Result<u32, DispatchError> {
+			let mut claimed: BalanceOf<T> = 0u32.into();
+			let mut pos = 0;
+			UnstakingRequests::<T>::mutate_exists(who, |maybe_requests| {
+				if let Some(requests) = maybe_requests {
+					let curr_block = Self::current_block_number();
+					requests.retain(|request| {
+						if request.block > curr_block || Self::has_pending_slash(&request.candidate)
+						{
+							return true;
+						}
+						pos += 1;
+						claimed.saturating_accrue(request.amount);
+						Self::unindex_unstake_requests(&request.candidate, who, 1);
+						false
+					});
+					if requests.is_empty() {
+						*maybe_requests = None;
+					}
+				}
+			});
+			if !claimed.is_zero() {
+				Self::release_stake(who, claimed)?;
+				Self::deposit_event(Event::StakeClaimed { staker: who.clone(), amount: claimed });
+			}
+			Ok(pos as u32)
+		}
+
+		/// Removes `amount` from the unstaking requests of a given user towards `candidate` that
+		/// are still waiting for the delay to pass, starting from the ones that would be released
+		/// the latest. Funds are kept on hold.
+		///
+		/// Only requests for `candidate` are taken, so that funds pending a slash on another
+		/// candidate cannot escape it by being rebonded elsewhere.
+		///
+		/// Returns the number of unstaking requests the user originally had.
+		fn do_take_pending_unstake(
+			who: &T::AccountId,
+			candidate: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> Result<u32, DispatchError> {
+			UnstakingRequests::<T>::try_mutate_exists(
+				who,
+				|maybe_requests| -> Result<u32, DispatchError> {
+					let requests =
+						maybe_requests.as_mut().ok_or(Error::<T>::InvalidRebondAmount)?;
+					let original_len = requests.len() as u32;
+					let curr_block = Self::current_block_number();
+					let mut remaining = amount;
+					while !remaining.is_zero() {
+						let last = requests
+							.iter()
+							.rposition(|request| request.candidate == *candidate)
+							.ok_or(Error::<T>::InvalidRebondAmount)?;
+						ensure!(requests[last].block > curr_block, Error::<T>::InvalidRebondAmount);
+						if requests[last].amount > remaining {
+							requests[last].amount.saturating_reduce(remaining);
+							remaining = Zero::zero();
+						} else {
+							remaining.saturating_reduce(requests[last].amount);
+							requests.remove(last);
+							Self::unindex_unstake_requests(candidate, who, 1);
+						}
+					}
+					if requests.is_empty() {
+						*maybe_requests = None;
+					}
+					Ok(original_len)
+				},
+			)
+		}
+
+		/// Adds stake into a given candidate by providing its address.
+		///
+		/// If `hold` is false the funds are assumed to be already held by the pallet, as it happens
+		/// when rebonding pending unstaking requests.
+		///
+		/// Returns the number of candidates the given one was moved past when sorting.
+		fn do_stake_for_account(
+			staker: &T::AccountId,
+			candidate: &T::AccountId,
+			amount: BalanceOf<T>,
+			sort: bool,
+			hold: bool,
+			reason: StakeChangeReason,
+		) -> Result<u32, DispatchError> {
+			ensure!(Candidates::<T>::contains_key(candidate), Error::<T>::NotCandidate);
+			// Pending rewards might be auto-compounded, so they must be settled first.
+			Self::settle_rewards(staker, candidate);
+			let mut old_stake = Zero::zero();
+			Candidates::<T>::try_mutate(candidate, |maybe_candidate| -> DispatchResult {
+				let candidate_info = maybe_candidate.as_mut().ok_or(Error::<T>::NotCandidate)?;
+				ensure!(
+					amount <= Self::stake_headroom(candidate_info.stake),
+					Error::<T>::MaxCandidateStakeExceeded
+				);
+				Stake::<T>::try_mutate(candidate, staker, |info| -> DispatchResult {
+					let final_staker_stake = info.stake.saturating_add(amount);
+					ensure!(
+						final_staker_stake >= MinStake::<T>::get(),
+						Error::<T>::InsufficientStake
+					);
+					old_stake = info.stake;
+					if info.stake.is_zero() {
+						ensure!(
+							candidate_info.stakers < T::MaxStakers::get(),
+							Error::<T>::TooManyStakers
+						);
+						StakedCandidates::<T>::try_mutate(staker, |candidates| {
+							candidates.try_push(candidate.clone())
+						})
+						.map_err(|_| Error::<T>::TooManyStakedCandidates)?;
+						candidate_info.stakers.saturating_inc();
+						info.session = CurrentSession::<T>::get();
+					}
+					// Moved stake already counted towards the rewards of another candidate during
+					// this session, so the position only earns rewards from the next one.
+					if reason == StakeChangeReason::Moved {
+						info.session = CurrentSession::<T>::get();
+					}
+					if hold {
+						Self::hold_stake(staker, amount)?;
+					}
+					info.stake = final_staker_stake;
+					candidate_info.stake.saturating_accrue(amount);
+
+					Self::deposit_event(Event::StakeAdded {
+						staker: staker.clone(),
+						candidate: candidate.clone(),
+						amount,
+					});
+					Ok(())
+				})
+			})?;
+			Self::refresh_lock_bonus(staker, candidate);
+			T::OnStakeChange::on_stake_change(
+				staker,
+				candidate,
+				old_stake,
+				old_stake.saturating_add(amount),
+				reason,
+			);
+			let moved = if sort { Self::reassign_candidate_position(candidate, None) } else { 0 };
+			Ok(moved)
+		}
+
+		/// Relocate a candidate after modifying its stake, sorting update.
+		///
+		/// If `hint` is a valid position, that is, the candidate that should come right after the
+		/// given one, it is used without searching the list. Otherwise, only the candidates between
+		/// the old and the new position are visited, so the cost of this operation does not depend
+		/// on the total number of candidates.
+		///
+		/// Returns the number of candidates the given one was moved past.
+		fn reassign_candidate_position(who: &T::AccountId, hint: Option<&T::AccountId>) -> u32 {
+			let max_stake = MaxCandidateStake::<T>::get();
+			let stake = match Candidates::<T>::get(who) {
+				Some(info) => Self::ranking_stake(info.stake, max_stake),
+				None => return 0,
+			};
+			let node = match Self::remove_from_candidate_list(who) {
+				Some(node) => node,
+				None => return 0,
+			};
+			if let Some((prev, next)) =
+				hint.and_then(|hint| Self::hinted_position(hint, stake, max_stake))
+			{
+				Self::link_candidate(who, prev, next);
+				return 0;
+			}
+			let (mut prev, mut next) = (node.prev, node.next);
+			let mut moved = 0u32;
+			// Move towards the tail while the next candidate has less stake.
+			while let Some(next_candidate) = next.clone() {
+				if Self::ranking_stake(Self::candidate_stake(&next_candidate), max_stake) >= stake {
+					break;
+				}
+				next = CandidateListNodes::<T>::get(&next_candidate).and_then(|node| node.next);
+				prev = Some(next_candidate);
+				moved.saturating_inc();
+			}
+			// Move towards the head while the previous candidate has greater or equal stake.
+			while let Some(prev_candidate) = prev.clone() {
+				if Self::ranking_stake(Self::candidate_stake(&prev_candidate), max_stake) < stake {
+					break;
+				}
+				prev = CandidateListNodes::<T>::get(&prev_candidate).and_then(|node| node.prev);
+				next = Some(prev_candidate);
+				moved.saturating_inc();
+			}
+			Self::link_candidate(who, prev, next);
+			moved
+		}
+
+		/// Inserts a candidate in the list of candidates, right before the first candidate with
+		/// greater or equal stake.
+		///
+		/// The list is only searched if `hint` is not a valid position and the candidate does not
+		/// belong in the head of the list.
+		pub(crate) fn insert_into_candidate_list(
+			who: &T::AccountId,
+			stake: BalanceOf<T>,
+			hint: Option<&T::AccountId>,
+		) {
+			let max_stake = MaxCandidateStake::<T>::get();
+			let stake = Self::ranking_stake(stake, max_stake);
+			let head = CandidateListHead::<T>::get();
+			if let Some((prev, next)) =
+				hint.and_then(|hint| Self::hinted_position(hint, stake, max_stake)).or_else(|| {
+					head.as_ref().and_then(|head| Self::hinted_position(head, stake, max_stake))
+				}) {
+				Self::link_candidate(who, prev, next);
+				return;
+			}
+			let mut prev = None;
+			let mut next = head;
+			while let Some(next_candidate) = next.clone() {
+				if Self::ranking_stake(Self::candidate_stake(&next_candidate), max_stake) >= stake {
+					break;
+				}
+				next = CandidateListNodes::<T>::get(&next_candidate).and_then(|node| node.next);
+				prev = Some(next_candidate);
+			}
+			Self::link_candidate(who, prev, next);
+		}
+
+		/// Returns the neighbours a candidate with a ranking stake of `stake` would have if it was
+		/// placed right before `hint` in the list of candidates, as long as the list remains
+		/// sorted. Only `hint` and its previous candidate are checked.
+		fn hinted_position(
+			hint: &T::AccountId,
+			stake: BalanceOf<T>,
+			max_stake: BalanceOf<T>,
+		) -> Option<(Option<T::AccountId>, Option<T::AccountId>)> {
+			let node = CandidateListNodes::<T>::get(hint)?;
+			if Self::ranking_stake(Self::candidate_stake(hint), max_stake) < stake {
+				return None;
+			}
+			if let Some(prev) = &node.prev {
+				if Self::ranking_stake(Self::candidate_stake(prev), max_stake) >= stake {
+					return None;
+				}
+			}
+			Some((node.prev, Some(hint.clone())))
+		}
+
+		/// Removes a candidate from the list of candidates, linking its neighbours together.
+		///
+		/// Returns the node the candidate had, if any.
+		fn remove_from_candidate_list(who: &T::AccountId) -> Option<ListNode<T::AccountId>> {
+			let node = CandidateListNodes::<T>::take(who)?;
+			match &node.prev {
+				Some(prev) => CandidateListNodes::<T>::mutate(prev, |maybe_node| {
+					if let Some(prev_node) = maybe_node {
+						prev_node.next = node.next.clone();
+					}
+				}),
+				None => CandidateListHead::<T>::set(node.next.clone()),
+			}
+			match &node.next {
+				Some(next) => CandidateListNodes::<T>::mutate(next, |maybe_node| {
+					if let Some(next_node) = maybe_node {
+						next_node.prev = node.prev.clone();
+					}
+				}),
+				None => CandidateListTail::<T>::set(node.prev.clone()),
+			}
+			Some(node)
+		}
+
+		/// Places a candidate in the list of candidates between `prev` and `next`, which must be
+		/// adjacent.
+		fn link_candidate(
+			who: &T::AccountId,
+			prev: Option<T::AccountId>,
+			next: Option<T::AccountId>,
+		) {
+			match &prev {
+				Some(prev) => CandidateListNodes::<T>::mutate(prev, |maybe_node| {
+					if let Some(prev_node) = maybe_node {
+						prev_node.next = Some(who.clone());
+					}
+				}),
+				None => CandidateListHead::<T>::put(who),
+			}
+			match &next {
+				Some(next) => CandidateListNodes::<T>::mutate(next, |maybe_node| {
+					if let Some(next_node) = maybe_node {
+						next_node.prev = Some(who.clone());
+					}
+				}),
+				None => CandidateListTail::<T>::put(who),
+			}
+			CandidateListNodes::<T>::insert(who, ListNode { prev, next });
+		}
+
+		/// Returns the total stake of a given candidate, or zero if it is not a candidate.
+		fn candidate_stake(who: &T::AccountId) -> BalanceOf<T> {
+			Candidates::<T>::get(who).map(|info| info.stake).unwrap_or_default()
+		}
+
+		/// Returns the part of `stake` that counts towards the position of a candidate in the list
+		/// of candidates, given the current [`MaxCandidateStake`].
+		fn ranking_stake(stake: BalanceOf<T>, max_stake: BalanceOf<T>) -> BalanceOf<T> {
+			if max_stake.is_zero() {
+				stake
+			} else {
+				stake.min(max_stake)
+			}
+		}
+
+		/// Returns the amount of stake a candidate holding `stake` can still receive before
+		/// reaching [`MaxCandidateStake`].
+		fn stake_headroom(stake: BalanceOf<T>) -> BalanceOf<T> {
+			let max_stake = MaxCandidateStake::<T>::get();
+			if max_stake.is_zero() {
+				BalanceOf::<T>::max_value()
+			} else {
+				max_stake.saturating_sub(stake)
+			}
+		}
+
+		/// Limits the amount of rewards auto-compounded in `candidate` to the stake it can still
+		/// receive. The rest is kept by the staker.
+		fn compoundable_amount(candidate: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+			amount.min(Self::stake_headroom(Self::candidate_stake(candidate)))
+		}
+
+		/// Sorts the list of candidates again from scratch, as needed when [`MaxCandidateStake`]
+		/// changes.
+		///
+		/// Returns the number of candidates in the list.
+		fn sort_candidate_list() -> u32 {
+			let max_stake = MaxCandidateStake::<T>::get();
+			let mut candidates = Self::candidate_list();
+			// The sort is stable, so candidates with the same ranking stake keep their order.
+			candidates.sort_by_key(|candidate| Self::ranking_stake(candidate.stake, max_stake));
+			for (index, candidate) in candidates.iter().enumerate() {
+				let prev = index
+					.checked_sub(1)
+					.and_then(|prev| candidates.get(prev))
+					.map(|c| c.who.clone());
+				let next = candidates.get(index + 1).map(|c| c.who.clone());
+				CandidateListNodes::<T>::insert(&candidate.who, ListNode { prev, next });
+			}
+			CandidateListHead::<T>::set(candidates.first().map(|c| c.who.clone()));
+			CandidateListTail::<T>::set(candidates.last().map(|c| c.who.clone()));
+			candidates.len() as u32
+		}
+
+		/// Returns all candidates sorted in ascending order by total stake.
+		///
+		/// Note this iterates over all candidates, so it should be used with care.
+		pub fn candidate_list() -> Vec<CandidateInfo<T::AccountId, BalanceOf<T>>> {
+			let mut candidates = Vec::new();
+			let mut next = CandidateListHead::<T>::get();
+			while let Some(candidate) = next {
+				next = CandidateListNodes::<T>::get(&candidate).and_then(|node| node.next);
+				if let Some(info) = Candidates::<T>::get(&candidate) {
+					candidates.push(info);
+				}
+			}
+			candidates
+		}
+
+		/// Returns up to `count` candidates with the highest stake, starting from the highest one.
+		fn top_candidates(count: usize) -> Vec<T::AccountId> {
+			let mut candidates = Vec::new();
+			let mut prev = CandidateListTail::<T>::get();
+			while let Some(candidate) = prev {
+				if candidates.len() >= count {
+					break;
+				}
+				prev = CandidateListNodes::<T>::get(&candidate).and_then(|node| node.prev);
+				// Candidates about to be kicked out are not selected.
+				if !StaleCandidates::<T>::contains_key(&candidate) {
+					candidates.push(candidate);
+				}
+			}
+			candidates
+		}
+
+		/// Return the total number of accounts that are eligible collators (candidates and
+		/// invulnerables). Candidates flagged as stale are not eligible.
+		pub fn eligible_collators() -> u32 {
+			Candidates::<T>::count()
+				.saturating_sub(StaleCandidates::<T>::count())
+				.saturating_add(Invulnerables::<T>::decode_len().unwrap_or_default() as u32)
+		}
+
+		/// Unstakes all funds deposited in a given `candidate`.
+		///
+		/// If the target is not a candidate or if the operation does not carry a delay, the deposit
+		/// is immediately returned, unless the candidate has slashes pending. Otherwise, a delay is
+		/// applied.
+		///
+		/// Returns the number of candidates the given one was moved past when sorting and the
+		/// number of unstaking requests the user originally had.
+		fn do_unstake(
+			staker: &T::AccountId,
+			candidate: &T::AccountId,
+			has_penalty: bool,
+			sort: bool,
+			reason: StakeChangeReason,
+		) -> Result<(u32, u32), DispatchError> {
+			// Pending rewards might be auto-compounded, so they must be settled first.
+			Self::settle_rewards(staker, candidate);
+			let stake = Stake::<T>::get(candidate, staker).stake;
+			Self::do_unstake_amount(staker, candidate, stake, has_penalty, sort, reason)
+		}
+
+		/// Unstakes `amount` from the funds deposited in a given `candidate`.
+		///
+		/// The stake left must be either zero, in which case the staker no longer backs the
+		/// candidate, or greater than or equal to [`MinStake`].
+		///
+		/// If the target is not a candidate or if the operation does not carry a delay, the deposit
+		/// is immediately returned, unless the candidate has slashes pending. Otherwise, a delay is
+		/// applied. Funds staked via [`Pallet::stake_for`] are returned to the account funding them
+		/// instead of the staker.
+		///
+		/// Returns the number of candidates the given one was moved past when sorting and the
+		/// number of unstaking requests the account receiving the funds originally had.
+		fn do_unstake_amount(
+			staker: &T::AccountId,
+			candidate: &T::AccountId,
+			amount: BalanceOf<T>,
+			has_penalty: bool,
+			sort: bool,
+			reason: StakeChangeReason,
+		) -> Result<(u32, u32), DispatchError> {
+			Self::settle_rewards(staker, candidate);
+			Self::ensure_can_unstake(staker, candidate, amount)?;
+			let mut unstaking_requests = 0;
+
+			// Funds staked on behalf of the staker are returned to the account funding them.
+			let account = match StakeFunders::<T>::get(candidate, staker) {
+				Some(funder) => {
+					T::Currency::transfer_on_hold(
+						&HoldReason::Staking.into(),
+						staker,
+						&funder,
+						amount,
+						Exact,
+						Restriction::OnHold,
+						Polite,
+					)?;
+					funder
+				},
+				None => staker.clone(),
+			};
+			if !has_penalty {
+				unstaking_requests = Self::release_unstaked(&account, candidate, amount)?;
+			} else {
+				let delay = if staker == candidate {
+					T::CollatorUnstakingDelay::get()
+				} else {
+					T::UserUnstakingDelay::get()
+				};
+				unstaking_requests = Self::add_unstake_request(&account, candidate, amount, delay)?;
+			}
+			let moved = Self::remove_stake(staker, candidate, amount, sort, reason);
+
+			Ok((moved, unstaking_requests))
+		}
+
+		/// Returns `amount`, unstaked from `candidate`, to `account`.
+		///
+		/// While `candidate` has slashes pending, the funds are kept on hold in an unstaking request
+		/// instead, so that the slashes can still reach them.
+		///
+		/// Returns the number of unstaking requests the user originally had, if any were created.
+		fn release_unstaked(
+			account: &T::AccountId,
+			candidate: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> Result<u32, DispatchError> {
+			if Self::has_pending_slash(candidate) {
+				Self::add_unstake_request(account, candidate, amount, Zero::zero())
+			} else {
+				Self::release_stake(account, amount)?;
+				Ok(0)
+			}
 		}
 
-		/// Adds stake into a given candidate by providing its address.
-		fn do_stake_for_account(
-			staker: &T::AccountId,
+		/// Adds an unstaking request for `amount`, removed from `candidate`, to the requests of
+		/// `account`, so that it can be claimed after `delay` blocks.
+		///
+		/// Returns the number of unstaking requests the account originally had.
+		fn add_unstake_request(
+			account: &T::AccountId,
 			candidate: &T::AccountId,
 			amount: BalanceOf<T>,
-			sort: bool,
-		) -> Result<usize, DispatchError> {
-			let position = Self::get_candidate(candidate)?;
-			Self::do_stake_at_position(staker, amount, position, sort)
+			delay: BlockNumberFor<T>,
+		) -> Result<u32, DispatchError> {
+			UnstakingRequests::<T>::try_mutate(account, |requests| {
+				let unstaking_requests = requests.len() as u32;
+				let block = Self::current_block_number() + delay;
+				let pos =
+					requests.binary_search_by_key(&block, |r| r.block).unwrap_or_else(|pos| pos);
+				requests
+					.try_insert(pos, UnstakeRequest { candidate: candidate.clone(), block, amount })
+					.map_err(|_| Error::<T>::TooManyUnstakingRequests)?;
+				Self::index_unstake_request(candidate, account)?;
+				Self::deposit_event(Event::UnstakeRequestCreated {
+					staker: account.clone(),
+					candidate: candidate.clone(),
+					amount,
+					block,
+				});
+				Ok(unstaking_requests)
+			})
 		}
 
-		/// Registers a given account as candidate.
+		/// Worst-case weight of slashing a single candidate.
+		pub fn max_apply_slash_weight() -> Weight {
+			T::WeightInfo::apply_slash(T::MaxStakers::get(), Self::max_candidate_unstake_requests())
+		}
+
+		/// Maximum number of unstaking requests that can be pending towards a single candidate.
+		pub fn max_candidate_unstake_requests() -> u32 {
+			T::MaxStakers::get().saturating_mul(T::MaxStakedCandidates::get())
+		}
+
+		/// Tracks a new unstaking request of `account` towards `candidate` in
+		/// [`CandidateUnstakers`].
+		fn index_unstake_request(
+			candidate: &T::AccountId,
+			account: &T::AccountId,
+		) -> DispatchResult {
+			CandidateUnstakeRequestCount::<T>::try_mutate(candidate, |count| -> DispatchResult {
+				ensure!(
+					*count < Self::max_candidate_unstake_requests(),
+					Error::<T>::TooManyUnstakingRequests
+				);
+				count.saturating_inc();
+				Ok(())
+			})?;
+			CandidateUnstakers::<T>::mutate(candidate, account, |requests| {
+				*requests = Some(requests.unwrap_or_default().saturating_add(1));
+			});
+			Ok(())
+		}
+
+		/// Stops tracking `removed` unstaking requests of `account` towards `candidate` in
+		/// [`CandidateUnstakers`].
+		fn unindex_unstake_requests(
+			candidate: &T::AccountId,
+			account: &T::AccountId,
+			removed: u32,
+		) {
+			if removed.is_zero() {
+				return;
+			}
+			CandidateUnstakers::<T>::mutate_exists(candidate, account, |requests| {
+				*requests = requests
+					.map(|requests| requests.saturating_sub(removed))
+					.filter(|requests| !requests.is_zero());
+			});
+			CandidateUnstakeRequestCount::<T>::mutate_exists(candidate, |count| {
+				*count = count
+					.map(|count| count.saturating_sub(removed))
+					.filter(|count| !count.is_zero());
+			});
+		}
+
+		/// Removes all the stake deposited by `staker` in `candidate` and keeps the funds on hold as
+		/// [`IdleStake`], so that they can be nominated again without any delay.
 		///
-		/// The account has to reserve the candidacy bond. If the account was previously a candidate
-		/// the retained stake will be re-included.
+		/// The funds are meant to be staked right away on other candidates, so the same rules as in
+		/// [`Pallet::move_stake`] apply: `candidate` must not have slashes pending to be applied.
+		fn do_unstake_to_idle(staker: &T::AccountId, candidate: &T::AccountId) -> DispatchResult {
+			ensure!(!Self::has_pending_slash(candidate), Error::<T>::PendingSlash);
+			Self::ensure_not_funded(staker, candidate)?;
+			Self::settle_rewards(staker, candidate);
+			let stake = Stake::<T>::get(candidate, staker).stake;
+			Self::ensure_can_unstake(staker, candidate, stake)?;
+			Self::remove_stake(staker, candidate, stake, true, StakeChangeReason::Moved);
+			IdleStake::<T>::mutate(staker, |idle| idle.saturating_accrue(stake));
+			Ok(())
+		}
+
+		/// Ensures `who` did not move stake between candidates during the last
+		/// [`Config::StakeMoveCooldown`] blocks.
+		fn ensure_stake_move_allowed(who: &T::AccountId) -> DispatchResult {
+			if let Some(last_move) = LastStakeMove::<T>::get(who) {
+				ensure!(
+					Self::current_block_number()
+						>= last_move.saturating_add(T::StakeMoveCooldown::get()),
+					Error::<T>::StakeMoveTooSoon
+				);
+			}
+			Ok(())
+		}
+
+		/// Records that `who` just moved stake between candidates, starting the cooldown.
+		fn note_stake_move(who: &T::AccountId) {
+			if T::StakeMoveCooldown::get().is_zero() {
+				LastStakeMove::<T>::remove(who);
+			} else {
+				LastStakeMove::<T>::insert(who, Self::current_block_number());
+			}
+		}
+
+		/// Adds `amount` from `member` to the nomination pool `pool_id`, issuing the corresponding
+		/// points. The funds of the pool that are neither staked nor being unstaked, such as
+		/// refunded stake, are then staked evenly on `candidates` along with `amount`.
+		fn do_join_pool(
+			member: &T::AccountId,
+			pool_id: PoolId,
+			amount: BalanceOf<T>,
+			candidates: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(!amount.is_zero(), Error::<T>::InsufficientStake);
+			ensure!(!candidates.is_empty(), Error::<T>::NoPoolCandidates);
+			let pool_account = Self::pool_account_id(pool_id);
+			Self::settle_all_rewards(&pool_account)?;
+			let value = SharedFunds::<T>::get(&pool_account).unwrap_or_default();
+			let points = if pool.points.is_zero() {
+				amount
+			} else {
+				ensure!(!value.is_zero(), Error::<T>::PoolDepleted);
+				FixedU128::checked_from_rational(pool.points, value)
+					.unwrap_or_default()
+					.saturating_mul_int(amount)
+			};
+			ensure!(!points.is_zero(), Error::<T>::InsufficientStake);
+
+			T::Currency::transfer(member, &pool_account, amount, Preserve)?;
+			SharedFunds::<T>::insert(&pool_account, value.saturating_add(amount));
+			let funds = Self::unstaked_shared_funds(&pool_account);
+			let count: BalanceOf<T> = (candidates.len() as u32).into();
+			let share = funds / count;
+			for (index, candidate) in candidates.iter().enumerate() {
+				// The remainder of the split is staked on the first candidate.
+				let stake = if index == 0 { share.saturating_add(funds % count) } else { share };
+				if !stake.is_zero() {
+					Self::do_stake_for_account(
+						&pool_account,
+						candidate,
+						stake,
+						true,
+						true,
+						StakeChangeReason::Staked,
+					)?;
+				}
+			}
+
+			PoolMembers::<T>::mutate(pool_id, member, |member_points| {
+				if member_points.is_zero() {
+					pool.members.saturating_inc();
+				}
+				member_points.saturating_accrue(points);
+			});
+			pool.points.saturating_accrue(points);
+			Pools::<T>::insert(pool_id, pool);
+			Self::deposit_event(Event::PoolJoined {
+				pool_id,
+				member: member.clone(),
+				amount,
+				points,
+			});
+			Ok(())
+		}
+
+		/// Claims the pending rewards of an account staking on behalf of others, such as a
+		/// nomination pool or the liquid staking account, so that they are included in its funds.
+		/// The funds it finished unstaking are released as well, so that they can be staked again.
 		///
-		/// Returns the registered candidate.
-		pub fn do_register_as_candidate(
-			who: &T::AccountId,
-		) -> Result<CandidateInfo<T::AccountId, BalanceOf<T>>, DispatchError> {
-			let bond = CandidacyBond::<T>::get();
+		/// Returns the number of candidates the account backs.
+		fn settle_all_rewards(account: &T::AccountId) -> Result<u32, DispatchError> {
+			let candidates = StakedCandidates::<T>::get(account);
+			for candidate in candidates.iter() {
+				Self::settle_rewards(account, candidate);
+			}
+			Self::do_claim(account)?;
+			Ok(candidates.len() as u32)
+		}
 
-			// In case the staker already had non-claimed stake we calculate it now.
-			let mut stakers = 0;
-			let already_staked: BalanceOf<T> = Stake::<T>::iter_prefix_values(who).fold(
-				Zero::zero(),
-				|acc, StakeInfo { stake, .. }| {
-					if !stake.is_zero() {
-						stakers += 1;
-					}
-					acc.saturating_add(stake)
-				},
-			);
+		/// Returns the funds of an account staking on behalf of others that are either deposited
+		/// in candidates or being unstaked.
+		fn committed_shared_funds(account: &T::AccountId) -> BalanceOf<T> {
+			let unstaking = UnstakingRequests::<T>::get(account)
+				.iter()
+				.fold(BalanceOf::<T>::zero(), |acc, request| acc.saturating_add(request.amount));
+			StakedCandidates::<T>::get(account).iter().fold(unstaking, |acc, candidate| {
+				acc.saturating_add(Stake::<T>::get(candidate, account).stake)
+			})
+		}
 
-			// First authored block is current block plus kick threshold to handle session delay
-			let candidate = CandidateList::<T>::try_mutate(
-				|candidates| -> Result<CandidateInfo<T::AccountId, BalanceOf<T>>, DispatchError> {
-					ensure!(
-						!candidates.iter().any(|candidate_info| candidate_info.who == *who),
-						Error::<T>::AlreadyCandidate
-					);
-					LastAuthoredBlock::<T>::insert(
-						who.clone(),
-						Self::current_block_number() + T::KickThreshold::get(),
-					);
-					let info = CandidateInfo {
-						who: who.clone(),
-						stake: already_staked,
-						deposit: bond,
-						stakers,
-					};
-					T::Currency::hold(&HoldReason::Staking.into(), who, bond)?;
-					candidates
-						.try_insert(0, info.clone())
-						.map_err(|_| Error::<T>::InsertToCandidateListFailed)?;
-					PendingExCandidates::<T>::remove(who);
-					Ok(info)
-				},
-			)?;
+		/// Returns the [`SharedFunds`] of an account staking on behalf of others that are neither
+		/// deposited in candidates nor being unstaked, such as refunded stake and claimed rewards.
+		fn unstaked_shared_funds(account: &T::AccountId) -> BalanceOf<T> {
+			SharedFunds::<T>::get(account)
+				.unwrap_or_default()
+				.saturating_sub(Self::committed_shared_funds(account))
+				.min(T::Currency::reducible_balance(account, Preserve, Polite))
+		}
 
-			Self::deposit_event(Event::CandidateAdded { account_id: who.clone(), deposit: bond });
-			Ok(candidate)
+		/// Returns all the funds held by an account staking on behalf of others, which are its
+		/// committed funds and its free balance above the existential deposit. Unlike
+		/// [`SharedFunds`], this includes any balance sent directly to the account.
+		fn total_shared_funds(account: &T::AccountId) -> BalanceOf<T> {
+			T::Currency::reducible_balance(account, Preserve, Polite)
+				.saturating_add(Self::committed_shared_funds(account))
 		}
 
-		/// Claims all pending unstaking requests for a given user.
+		/// Adds `amount` to the [`SharedFunds`] of `account`, if it stakes on behalf of others.
+		fn accrue_shared_funds(account: &T::AccountId, amount: BalanceOf<T>) {
+			// Accounts not staking on behalf of others are left untouched.
+			let _ = SharedFunds::<T>::try_mutate_exists(account, |funds| -> Result<(), ()> {
+				funds.as_mut().ok_or(())?.saturating_accrue(amount);
+				Ok(())
+			});
+		}
+
+		/// Removes `amount` from the [`SharedFunds`] of `account`, if it stakes on behalf of
+		/// others.
+		fn reduce_shared_funds(account: &T::AccountId, amount: BalanceOf<T>) {
+			let _ = SharedFunds::<T>::try_mutate_exists(account, |funds| -> Result<(), ()> {
+				funds.as_mut().ok_or(())?.saturating_reduce(amount);
+				Ok(())
+			});
+		}
+
+		/// Withdraws `amount` from the funds of an account staking on behalf of others to
+		/// `receiver`.
 		///
-		/// Returns the amount of operations performed.
-		pub fn do_claim(who: &T::AccountId) -> Result<u32, DispatchError> {
-			let mut claimed: BalanceOf<T> = 0u32.into();
-			let mut pos = 0;
-			UnstakingRequests::<T>::mutate_exists(who, |maybe_requests| {
-				if let Some(requests) = maybe_requests {
-					let curr_block = Self::current_block_number();
-					for request in requests.iter() {
-						if request.block > curr_block {
-							break;
-						}
-						pos += 1;
-						claimed.saturating_accrue(request.amount);
-					}
-					requests.drain(..pos);
-					return if requests.is_empty() { None } else { Some(()) };
+		/// Funds not staked are transferred right away, leaving out any balance sent directly to
+		/// the account unless everything is withdrawn. The rest is unstaked from the candidates
+		/// the account backs, starting from the last one, and moved to `receiver`. Stake is taken
+		/// so that every candidate is left with either nothing or at least [`MinStake`]. If that
+		/// is not enough, the whole stake in a candidate is unstaked and the account keeps the
+		/// excess as its own unstaking request. Finally, funds the account is already unstaking
+		/// are handed over to `receiver`.
+		fn withdraw_shared_funds(
+			account: &T::AccountId,
+			receiver: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			// Balance sent directly to the account is only withdrawn along with everything else.
+			let free = if amount >= Self::total_shared_funds(account) {
+				T::Currency::reducible_balance(account, Preserve, Polite)
+			} else {
+				Self::unstaked_shared_funds(account)
+			};
+			let from_free = free.min(amount);
+			if !from_free.is_zero() {
+				T::Currency::transfer(account, receiver, from_free, Preserve)?;
+			}
+			let mut remaining = amount.saturating_sub(from_free);
+			let min_stake = MinStake::<T>::get();
+			for candidate in StakedCandidates::<T>::get(account).iter().rev() {
+				if remaining.is_zero() {
+					break;
+				}
+				let stake = Stake::<T>::get(candidate, account).stake;
+				let taken = if remaining >= stake {
+					stake
+				} else {
+					remaining.min(stake.saturating_sub(min_stake))
+				};
+				if !taken.is_zero() {
+					Self::unstake_shared_funds(account, receiver, candidate, taken, taken)?;
+					remaining.saturating_reduce(taken);
 				}
-				None
-			});
-			if !claimed.is_zero() {
-				T::Currency::release(&HoldReason::Staking.into(), who, claimed, Exact)?;
-				Self::deposit_event(Event::StakeClaimed { staker: who.clone(), amount: claimed });
 			}
-			Ok(pos as u32)
+			// Every candidate left has more stake than the remaining amount.
+			if let Some(candidate) = StakedCandidates::<T>::get(account).last() {
+				if !remaining.is_zero() {
+					let stake = Stake::<T>::get(candidate, account).stake;
+					Self::unstake_shared_funds(account, receiver, candidate, stake, remaining)?;
+					remaining = Zero::zero();
+				}
+			}
+			if !remaining.is_zero() {
+				Self::transfer_unstake_requests(account, receiver, remaining)?;
+			}
+			Ok(())
 		}
 
-		/// Adds stake into a given candidate by providing its position in [`CandidateList`].
+		/// Unstakes `stake` from the funds deposited in `candidate` by an account staking on
+		/// behalf of others, and moves `amount` out of them to `receiver`. The account keeps the
+		/// rest.
 		///
-		/// Returns the position of the candidate in the list after adding the stake.
-		fn do_stake_at_position(
-			staker: &T::AccountId,
+		/// These funds are kept on hold during the unstaking delay if the candidate is still a
+		/// candidate or has slashes pending, and released immediately otherwise.
+		fn unstake_shared_funds(
+			account: &T::AccountId,
+			receiver: &T::AccountId,
+			candidate: &T::AccountId,
+			stake: BalanceOf<T>,
 			amount: BalanceOf<T>,
-			position: usize,
-			sort: bool,
-		) -> Result<usize, DispatchError> {
+		) -> DispatchResult {
+			Self::ensure_can_unstake(account, candidate, stake)?;
+			T::Currency::transfer_on_hold(
+				&HoldReason::Staking.into(),
+				account,
+				receiver,
+				amount,
+				Exact,
+				Restriction::OnHold,
+				Polite,
+			)?;
+			let excess = stake.saturating_sub(amount);
+			if Candidates::<T>::contains_key(candidate) {
+				let delay = T::UserUnstakingDelay::get();
+				Self::add_unstake_request(receiver, candidate, amount, delay)?;
+				if !excess.is_zero() {
+					Self::add_unstake_request(account, candidate, excess, delay)?;
+				}
+			} else {
+				Self::release_unstaked(receiver, candidate, amount)?;
+				if !excess.is_zero() {
+					Self::release_unstaked(account, candidate, excess)?;
+				}
+			}
+			Self::remove_stake(account, candidate, stake, true, StakeChangeReason::Unstaked);
+			Ok(())
+		}
+
+		/// Moves `amount` from the unstaking requests of `account` to `receiver`, starting from the
+		/// ones that would be released the latest. The requests keep their candidate and the
+		/// block they are released at.
+		fn transfer_unstake_requests(
+			account: &T::AccountId,
+			receiver: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let mut requests = UnstakingRequests::<T>::get(account);
+			let mut transferred = Vec::new();
+			let mut remaining = amount;
+			while !remaining.is_zero() {
+				let request = requests.last_mut().ok_or(Error::<T>::InvalidUnstakeAmount)?;
+				let taken = remaining.min(request.amount);
+				request.amount.saturating_reduce(taken);
+				remaining.saturating_reduce(taken);
+				transferred.push((request.candidate.clone(), request.block, taken));
+				if request.amount.is_zero() {
+					let candidate = request.candidate.clone();
+					requests.pop();
+					Self::unindex_unstake_requests(&candidate, account, 1);
+				}
+			}
+			if requests.is_empty() {
+				UnstakingRequests::<T>::remove(account);
+			} else {
+				UnstakingRequests::<T>::insert(account, requests);
+			}
+
+			let now = Self::current_block_number();
+			for (candidate, block, taken) in transferred {
+				T::Currency::transfer_on_hold(
+					&HoldReason::Staking.into(),
+					account,
+					receiver,
+					taken,
+					Exact,
+					Restriction::OnHold,
+					Polite,
+				)?;
+				Self::add_unstake_request(receiver, &candidate, taken, block.saturating_sub(now))?;
+			}
+			Ok(())
+		}
+
+		/// Ensures the stake of `staker` in `candidate` is not funded by another account.
+		fn ensure_not_funded(staker: &T::AccountId, candidate: &T::AccountId) -> DispatchResult {
 			ensure!(
-				position < CandidateList::<T>::decode_len().unwrap_or_default(),
-				Error::<T>::NotCandidate
+				!StakeFunders::<T>::contains_key(candidate, staker),
+				Error::<T>::StakeFundedByOtherAccount
 			);
+			Ok(())
+		}
+
+		/// Checks whether `amount` can be unstaked from the funds deposited by `staker` in
+		/// `candidate`.
+		///
+		/// The stake left must be either zero or greater than or equal to [`MinStake`]. Candidates
+		/// cannot leave less than [`MinSelfStake`] staked on themselves, and locked stake cannot be
+		/// removed until the lock expires, unless the candidate left.
+		fn ensure_can_unstake(
+			staker: &T::AccountId,
+			candidate: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let stake = Stake::<T>::get(candidate, staker).stake;
+			ensure!(!stake.is_zero(), Error::<T>::NothingToUnstake);
+			ensure!(!amount.is_zero() && amount <= stake, Error::<T>::InvalidUnstakeAmount);
+			let remaining = stake.saturating_sub(amount);
 			ensure!(
-				StakeCount::<T>::get(staker) < T::MaxStakedCandidates::get(),
-				Error::<T>::TooManyStakedCandidates,
+				remaining.is_zero() || remaining >= MinStake::<T>::get(),
+				Error::<T>::InsufficientStake
 			);
-			CandidateList::<T>::try_mutate(|candidates| -> DispatchResult {
-				let candidate = &mut candidates[position];
-				Stake::<T>::try_mutate(candidate.who.clone(), staker, |info| -> DispatchResult {
-					let final_staker_stake = info.stake.saturating_add(amount);
+			if Candidates::<T>::contains_key(candidate) {
+				if staker == candidate {
 					ensure!(
-						final_staker_stake >= MinStake::<T>::get(),
-						Error::<T>::InsufficientStake
+						remaining >= MinSelfStake::<T>::get(),
+						Error::<T>::InsufficientSelfStake
 					);
-					if info.stake.is_zero() {
-						ensure!(
-							candidate.stakers < T::MaxStakers::get(),
-							Error::<T>::TooManyStakers
-						);
-						StakeCount::<T>::mutate(staker, |count| count.saturating_inc());
-						candidate.stakers.saturating_inc();
-						info.session = CurrentSession::<T>::get();
-					}
-					T::Currency::hold(&HoldReason::Staking.into(), staker, amount)?;
-					info.stake = final_staker_stake;
-					candidate.stake.saturating_accrue(amount);
+				}
+				if let Some(lock) = StakeLocks::<T>::get(candidate, staker) {
+					ensure!(Self::current_block_number() >= lock.until, Error::<T>::StakeLocked);
+				}
+			}
+			Ok(())
+		}
 
-					Self::deposit_event(Event::StakeAdded {
-						staker: staker.clone(),
-						candidate: candidate.who.clone(),
-						amount,
-					});
-					Ok(())
-				})?;
-				Ok(())
-			})?;
-			let final_position =
-				if sort { Self::reassign_candidate_position(position)? } else { position };
-			Ok(final_position)
+		/// Removes `amount` from the stake deposited by `staker` in `candidate`, leaving the funds
+		/// untouched. The amount must have been checked via [`Self::ensure_can_unstake`], so any
+		/// lock on the stake is either expired or no longer relevant and it is removed.
+		///
+		/// Returns the number of candidates the given one was moved past when sorting.
+		fn remove_stake(
+			staker: &T::AccountId,
+			candidate: &T::AccountId,
+			amount: BalanceOf<T>,
+			sort: bool,
+			reason: StakeChangeReason,
+		) -> u32 {
+			Self::remove_stake_lock(staker, candidate);
+			let mut info = Stake::<T>::get(candidate, staker);
+			let old_stake = info.stake;
+			let remaining = info.stake.saturating_sub(amount);
+			let is_full_unstake = remaining.is_zero();
+			if is_full_unstake {
+				Stake::<T>::remove(candidate, staker);
+				LastClaimedSession::<T>::remove(candidate, staker);
+				StakeFunders::<T>::remove(candidate, staker);
+				Self::remove_staked_candidate(staker, candidate);
+			} else {
+				info.stake = remaining;
+				Stake::<T>::insert(candidate, staker, info);
+			}
+			let mut moved = 0;
+			if let Some(mut candidate_info) = Candidates::<T>::get(candidate) {
+				candidate_info.stake.saturating_reduce(amount);
+				if is_full_unstake {
+					candidate_info.stakers.saturating_dec();
+				}
+				Candidates::<T>::insert(candidate, candidate_info);
+				if sort {
+					moved = Self::reassign_candidate_position(candidate, None);
+				}
+			}
+			Self::deposit_event(Event::StakeRemoved {
+				staker: staker.clone(),
+				candidate: candidate.clone(),
+				amount,
+			});
+			T::OnStakeChange::on_stake_change(staker, candidate, old_stake, remaining, reason);
+			moved
 		}
 
-		/// Relocate a candidate after modifying its stake, sorting update.
+		/// Returns the weight of `stake`, deposited by `staker` in `candidate`, when distributing
+		/// rewards, which includes the bonus given by its lock, if any and not expired yet.
+		fn reward_weight(
+			staker: &T::AccountId,
+			candidate: &T::AccountId,
+			stake: BalanceOf<T>,
+		) -> BalanceOf<T> {
+			StakeLocks::<T>::get(candidate, staker)
+				.filter(|lock| Self::current_block_number() < lock.until)
+				.map_or(stake, |lock| stake.saturating_add(lock.bonus))
+		}
+
+		/// Returns the weight of `stake`, deposited in `candidate` by all its stakers, when
+		/// distributing rewards, which includes the bonus given by their locks.
+		fn candidate_reward_weight(candidate: &T::AccountId, stake: BalanceOf<T>) -> BalanceOf<T> {
+			stake.saturating_add(CandidateLockBonus::<T>::get(candidate))
+		}
+
+		/// Recalculates the bonus given by the lock of `staker` in `candidate`, if any, after its
+		/// stake or multiplier changed. The lock is removed if there is no stake left.
+		fn refresh_lock_bonus(staker: &T::AccountId, candidate: &T::AccountId) {
+			StakeLocks::<T>::mutate_exists(candidate, staker, |maybe_lock| {
+				if let Some(lock) = maybe_lock {
+					let stake = Stake::<T>::get(candidate, staker).stake;
+					let bonus = lock.multiplier.saturating_mul_int(stake).saturating_sub(stake);
+					Self::update_candidate_lock_bonus(candidate, lock.bonus, bonus);
+					lock.bonus = bonus;
+					if stake.is_zero() {
+						*maybe_lock = None;
+					}
+				}
+			});
+		}
+
+		/// Removes the locks on the stake deposited in `candidate` that already expired, along with
+		/// the bonus they give.
 		///
-		/// Returns the final position of the candidate.
-		fn reassign_candidate_position(position: usize) -> Result<usize, DispatchError> {
-			CandidateList::<T>::try_mutate(|candidates| -> Result<usize, DispatchError> {
-				let info = candidates.remove(position);
-				let new_pos = candidates
-					.iter()
-					.position(|candidate| candidate.stake >= info.stake)
-					.unwrap_or_else(|| candidates.len());
-				candidates
-					.try_insert(new_pos, info)
-					.map_err(|_| Error::<T>::InsertToCandidateListFailed)?;
-				Ok(new_pos)
-			})
+		/// Returns the weight consumed.
+		fn remove_expired_stake_locks(candidate: &T::AccountId) -> Weight {
+			if !CandidateLockBonus::<T>::contains_key(candidate) {
+				return T::DbWeight::get().reads(1);
+			}
+			let now = Self::current_block_number();
+			let mut locks = 0u64;
+			let expired = StakeLocks::<T>::iter_prefix(candidate)
+				.inspect(|_| locks.saturating_inc())
+				.filter(|(_, lock)| now >= lock.until)
+				.map(|(staker, _)| staker)
+				.collect::<Vec<_>>();
+			for staker in expired.iter() {
+				Self::remove_stake_lock(staker, candidate);
+			}
+			let removed = expired.len() as u64;
+			T::DbWeight::get().reads_writes(
+				locks.saturating_add(removed).saturating_add(1),
+				removed.saturating_mul(2),
+			)
 		}
 
-		/// Return the total number of accounts that are eligible collators (candidates and
-		/// invulnerables).
-		pub fn eligible_collators() -> u32 {
-			CandidateList::<T>::decode_len()
-				.unwrap_or_default()
-				.saturating_add(Invulnerables::<T>::decode_len().unwrap_or_default())
-				.try_into()
-				.unwrap_or(u32::MAX)
+		/// Removes the lock of `staker` in `candidate`, if any, along with the bonus it gives.
+		fn remove_stake_lock(staker: &T::AccountId, candidate: &T::AccountId) {
+			if let Some(lock) = StakeLocks::<T>::take(candidate, staker) {
+				Self::update_candidate_lock_bonus(candidate, lock.bonus, Zero::zero());
+				Self::deposit_event(Event::StakeUnlocked {
+					staker: staker.clone(),
+					candidate: candidate.clone(),
+				});
+			}
+		}
+
+		/// Replaces `old_bonus` with `new_bonus` in the total bonus given by the locks on the stake
+		/// deposited in `candidate`.
+		fn update_candidate_lock_bonus(
+			candidate: &T::AccountId,
+			old_bonus: BalanceOf<T>,
+			new_bonus: BalanceOf<T>,
+		) {
+			CandidateLockBonus::<T>::mutate_exists(candidate, |maybe_bonus| {
+				let bonus = maybe_bonus
+					.unwrap_or_default()
+					.saturating_sub(old_bonus)
+					.saturating_add(new_bonus);
+				*maybe_bonus = if bonus.is_zero() { None } else { Some(bonus) };
+			});
+		}
+
+		/// Removes a candidate from the candidates staked on by a given staker.
+		fn remove_staked_candidate(staker: &T::AccountId, candidate: &T::AccountId) {
+			StakedCandidates::<T>::mutate_exists(staker, |maybe_candidates| {
+				if let Some(candidates) = maybe_candidates {
+					candidates.retain(|c| c != candidate);
+					if candidates.is_empty() {
+						*maybe_candidates = None;
+					}
+				}
+			});
+		}
+
+		/// Removes a candidate, identified by its account, if it exists and refunds the stake.
+		///
+		/// Returns the candidate info.
+		fn try_remove_candidate_from_account(
+			who: &T::AccountId,
+			remove_last_authored: bool,
+			has_penalty: bool,
+		) -> Result<CandidateInfo<T::AccountId, BalanceOf<T>>, DispatchError> {
+			let candidate = Candidates::<T>::take(who).ok_or(Error::<T>::NotCandidate)?;
+			Self::remove_from_candidate_list(who);
+			if remove_last_authored {
+				LastAuthoredBlock::<T>::remove(who)
+			};
+			let stake = Stake::<T>::get(who, who).stake;
+			if !stake.is_zero() {
+				Self::do_unstake(
+					who,
+					who,
+					has_penalty,
+					false,
+					StakeChangeReason::CandidateRemoved,
+				)?;
+			}
+
+			// Return the bond too.
+			if has_penalty {
+				Self::add_unstake_request(
+					who,
+					who,
+					candidate.deposit,
+					T::CollatorUnstakingDelay::get(),
+				)?;
+			} else {
+				Self::release_unstaked(who, who, candidate.deposit)?;
+			}
+
+			CommissionChangeRequests::<T>::remove(who);
+			StaleCandidates::<T>::remove(who);
+			PendingExCandidates::<T>::set(who, true);
+			Self::deposit_event(Event::CandidateRemoved { account_id: who.clone() });
+			Ok(candidate)
 		}
 
-		/// Unstakes all funds deposited in a given `candidate`.
+		/// Slashes `fraction` of the funds at stake in `candidate`. This includes the candidacy bond,
+		/// the stake deposited on the candidate and the stake removed from it that is still waiting
+		/// in [`UnstakingRequests`]. Slashed funds are handled by [`Config::OnSlash`].
 		///
-		/// If the target is not a candidate or if the operation does not carry a delay, the deposit
-		/// is immediately returned. Otherwise, a delay is applied.
+		/// Stakers may end up with less stake than [`MinStake`] after being slashed. If their stake
+		/// drops to zero they no longer back the candidate.
 		///
-		/// Returns the amount unstaked and the number of unstaking requests the user originally had.
-		fn do_unstake(
-			staker: &T::AccountId,
-			candidate: &T::AccountId,
-			has_penalty: bool,
-			maybe_position: Option<usize>,
-			sort: bool,
-		) -> Result<(BalanceOf<T>, u32), DispatchError> {
-			let stake = Stake::<T>::take(candidate, staker).stake;
-			let mut unstaking_requests = 0;
-			ensure!(!stake.is_zero(), Error::<T>::NothingToUnstake);
+		/// Note this iterates over all unstaking requests, so it should only be used in rare cases,
+		/// such as reported offences.
+		///
+		/// Returns the total amount slashed.
+		pub fn do_slash(candidate: &T::AccountId, fraction: Perbill) -> BalanceOf<T> {
+			let mut total_slashed: BalanceOf<T> = Zero::zero();
+			if fraction.is_zero() {
+				return total_slashed;
+			}
+			let mut maybe_info = Candidates::<T>::get(candidate);
+
+			// Slash the candidacy bond.
+			if let Some(info) = maybe_info.as_mut() {
+				let slashed =
+					Self::slash_held(candidate, candidate, fraction.mul_floor(info.deposit));
+				info.deposit.saturating_reduce(slashed);
+				total_slashed.saturating_accrue(slashed);
+			}
 
-			if !has_penalty {
-				T::Currency::release(&HoldReason::Staking.into(), staker, stake, Exact)?;
-			} else {
-				let delay = if staker == candidate {
-					T::CollatorUnstakingDelay::get()
-				} else {
-					T::UserUnstakingDelay::get()
-				};
-				UnstakingRequests::<T>::try_mutate(staker, |requests| -> DispatchResult {
-					unstaking_requests = requests.len();
-					let block = Self::current_block_number() + delay;
-					let pos = requests
-						.binary_search_by_key(&block, |r| r.block)
-						.unwrap_or_else(|pos| pos);
-					requests
-						.try_insert(pos, UnstakeRequest { block, amount: stake })
-						.map_err(|_| Error::<T>::TooManyUnstakingRequests)?;
-					Self::deposit_event(Event::UnstakeRequestCreated {
-						staker: staker.clone(),
-						candidate: candidate.clone(),
-						amount: stake,
-						block,
-					});
-					Ok(())
-				})?;
-			}
-			StakeCount::<T>::mutate_exists(staker, |count| {
-				if let Some(c) = count.as_mut() {
-					c.saturating_dec();
-					match c {
-						0 => None,
-						_ => Some(*c),
-					}
+			// Slash the stake deposited on the candidate.
+			let mut stake_slashed: BalanceOf<T> = Zero::zero();
+			let mut stakers_removed = 0u32;
+			let stakes = Stake::<T>::iter_prefix(candidate).collect::<Vec<_>>();
+			for (staker, mut info) in stakes {
+				let old_stake = info.stake;
+				let slashed = Self::slash_held(&staker, candidate, fraction.mul_floor(info.stake));
+				info.stake.saturating_reduce(slashed);
+				stake_slashed.saturating_accrue(slashed);
+				if info.stake.is_zero() {
+					Stake::<T>::remove(candidate, &staker);
+					LastClaimedSession::<T>::remove(candidate, &staker);
+					StakeFunders::<T>::remove(candidate, &staker);
+					Self::remove_staked_candidate(&staker, candidate);
+					stakers_removed.saturating_inc();
 				} else {
-					// This should never occur.
-					None
+					Stake::<T>::insert(candidate, &staker, info);
 				}
-			});
-			if let Some(position) = maybe_position {
-				CandidateList::<T>::mutate(|candidates| {
-					candidates[position].stake.saturating_reduce(stake);
-					candidates[position].stakers.saturating_dec();
+				Self::refresh_lock_bonus(&staker, candidate);
+				T::OnStakeChange::on_stake_change(
+					&staker,
+					candidate,
+					old_stake,
+					info.stake,
+					StakeChangeReason::Slashed,
+				);
+			}
+			total_slashed.saturating_accrue(stake_slashed);
+			if let Some(mut info) = maybe_info {
+				info.stake.saturating_reduce(stake_slashed);
+				info.stakers.saturating_reduce(stakers_removed);
+				Candidates::<T>::insert(candidate, info);
+				Self::reassign_candidate_position(candidate, None);
+			}
+
+			// Slash the stake pending to be unstaked from the candidate.
+			let accounts = CandidateUnstakers::<T>::iter_key_prefix(candidate).collect::<Vec<_>>();
+			for account in accounts {
+				let mut removed = 0u32;
+				UnstakingRequests::<T>::mutate_exists(&account, |maybe_requests| {
+					if let Some(requests) = maybe_requests {
+						for request in requests.iter_mut().filter(|r| r.candidate == *candidate) {
+							let slashed = Self::slash_held(
+								&account,
+								candidate,
+								fraction.mul_floor(request.amount),
+							);
+							request.amount.saturating_reduce(slashed);
+							total_slashed.saturating_accrue(slashed);
+						}
+						let original_len = requests.len();
+						requests.retain(|r| !r.amount.is_zero());
+						removed = original_len.saturating_sub(requests.len()) as u32;
+						if requests.is_empty() {
+							*maybe_requests = None;
+						}
+					}
 				});
-				if sort {
-					Self::reassign_candidate_position(position)?;
-				}
+				Self::unindex_unstake_requests(candidate, &account, removed);
 			}
-			Self::deposit_event(Event::StakeRemoved {
-				staker: staker.clone(),
+
+			total_slashed
+		}
+
+		/// Schedules `fraction` of the funds at stake in `candidate` to be slashed after
+		/// [`Config::SlashDeferDuration`] sessions. If the candidate already has a slash scheduled
+		/// for the same session, the highest fraction is kept.
+		///
+		/// The slash is applied immediately if slashes are not deferred.
+		pub fn do_report_offence(candidate: &T::AccountId, fraction: Perbill) -> DispatchResult {
+			ensure!(!fraction.is_zero(), Error::<T>::InvalidSlashFraction);
+			// Former candidates can still be reported while funds at stake in them remain.
+			ensure!(
+				Candidates::<T>::contains_key(candidate)
+					|| !CandidateUnstakeRequestCount::<T>::get(candidate).is_zero()
+					|| Stake::<T>::iter_prefix(candidate).next().is_some(),
+				Error::<T>::NotCandidate
+			);
+
+			let defer_duration = T::SlashDeferDuration::get();
+			if defer_duration.is_zero() {
+				Self::do_slash(candidate, fraction);
+				return Ok(());
+			}
+			let session = CurrentSession::<T>::get().saturating_add(defer_duration);
+			UnappliedSlashes::<T>::try_mutate(session, |slashes| -> DispatchResult {
+				if let Some(slash) = slashes.iter_mut().find(|slash| slash.candidate == *candidate)
+				{
+					slash.fraction = slash.fraction.max(fraction);
+				} else {
+					slashes
+						.try_push(UnappliedSlash { candidate: candidate.clone(), fraction })
+						.map_err(|_| Error::<T>::TooManyUnappliedSlashes)?;
+					PendingSlashes::<T>::mutate(candidate, |count| count.saturating_inc());
+				}
+				Ok(())
+			})?;
+			Self::deposit_event(Event::SlashDeferred {
 				candidate: candidate.clone(),
-				amount: stake,
+				fraction,
+				session,
 			});
-
-			Ok((stake, unstaking_requests as u32))
+			Ok(())
 		}
 
-		/// Removes a candidate, identified by its index, if it exists and refunds the stake.
+		/// Applies all slashes deferred to a given session.
 		///
-		/// Returns the candidate info.
-		fn try_remove_candidate_at_position(
-			idx: usize,
-			remove_last_authored: bool,
-			has_penalty: bool,
-		) -> Result<CandidateInfo<T::AccountId, BalanceOf<T>>, DispatchError> {
-			CandidateList::<T>::try_mutate(
-				|candidates| -> Result<CandidateInfo<T::AccountId, BalanceOf<T>>, DispatchError> {
-					let candidate = candidates.remove(idx);
-					if remove_last_authored {
-						LastAuthoredBlock::<T>::remove(candidate.who.clone())
-					};
-					let stake = Stake::<T>::get(&candidate.who, &candidate.who).stake;
-					if !stake.is_zero() {
-						Self::do_unstake(&candidate.who, &candidate.who, has_penalty, None, false)?;
-					}
+		/// Returns the number of slashes applied.
+		pub(crate) fn apply_unapplied_slashes(session: SessionIndex) -> u32 {
+			let slashes = UnappliedSlashes::<T>::take(session);
+			for slash in slashes.iter() {
+				Self::note_slash_resolved(&slash.candidate);
+				Self::do_slash(&slash.candidate, slash.fraction);
+			}
+			slashes.len() as u32
+		}
 
-					// Return the bond too.
-					if has_penalty {
-						UnstakingRequests::<T>::try_mutate(
-							&candidate.who,
-							|requests| -> DispatchResult {
-								requests
-									.try_push(UnstakeRequest {
-										block: Self::current_block_number()
-											+ T::CollatorUnstakingDelay::get(),
-										amount: candidate.deposit,
-									})
-									.map_err(|_| Error::<T>::TooManyUnstakingRequests)?;
-								Ok(())
-							},
-						)?;
-					} else {
-						T::Currency::release(
-							&HoldReason::Staking.into(),
-							&candidate.who,
-							candidate.deposit,
-							Exact,
-						)?;
-					}
+		/// Notes that one of the slashes deferred for `candidate` was either applied or cancelled.
+		fn note_slash_resolved(candidate: &T::AccountId) {
+			PendingSlashes::<T>::mutate_exists(candidate, |count| {
+				*count =
+					count.map(|count| count.saturating_sub(1)).filter(|count| !count.is_zero());
+			});
+		}
 
-					PendingExCandidates::<T>::set(&candidate.who, true);
-					Self::deposit_event(Event::CandidateRemoved {
-						account_id: candidate.who.clone(),
-					});
-					Ok(candidate)
-				},
-			)
+		/// Whether `candidate` has slashes deferred to a future session.
+		pub fn has_pending_slash(candidate: &T::AccountId) -> bool {
+			PendingSlashes::<T>::contains_key(candidate)
 		}
 
-		/// Removes a candidate, identified by its account, if it exists and refunds the stake.
+		/// Holds `amount` from the free balance of `who` for staking.
+		pub(crate) fn hold_stake(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+			T::Currency::hold(&HoldReason::Staking.into(), who, amount)?;
+			TotalStaked::<T>::mutate(|total| total.saturating_accrue(amount));
+			Ok(())
+		}
+
+		/// Releases `amount` held for staking back to the free balance of `who`.
+		fn release_stake(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+			T::Currency::release(&HoldReason::Staking.into(), who, amount, Exact)?;
+			TotalStaked::<T>::mutate(|total| total.saturating_reduce(amount));
+			Ok(())
+		}
+
+		/// Slashes `amount` from the funds held by the pallet for `staker` due to an offence
+		/// committed by `candidate`.
 		///
-		/// Returns the candidate info.
-		fn try_remove_candidate_from_account(
-			who: &T::AccountId,
-			remove_last_authored: bool,
-			has_penalty: bool,
-		) -> Result<CandidateInfo<T::AccountId, BalanceOf<T>>, DispatchError> {
-			let idx = Self::get_candidate(who)?;
-			Self::try_remove_candidate_at_position(idx, remove_last_authored, has_penalty)
+		/// Returns the amount actually slashed.
+		fn slash_held(
+			staker: &T::AccountId,
+			candidate: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> BalanceOf<T> {
+			if amount.is_zero() {
+				return amount;
+			}
+			let (credit, missing) = T::Currency::slash(&HoldReason::Staking.into(), staker, amount);
+			T::OnSlash::on_unbalanced(credit);
+			let slashed = amount.saturating_sub(missing);
+			TotalStaked::<T>::mutate(|total| total.saturating_reduce(slashed));
+			Self::reduce_shared_funds(staker, slashed);
+			Self::deposit_event(Event::Slashed {
+				staker: staker.clone(),
+				candidate: candidate.clone(),
+				amount: slashed,
+			});
+			slashed
 		}
 
-		/// Distributes the rewards associated with a given collator, obtained during the previous session.
-		/// This includes specific rewards for the collator plus rewards for the stakers.
+		/// Rewards a given collator for the blocks produced during the previous session.
 		///
-		/// The collator must be a candidate in order to receive the rewards.
+		/// The collator must be a candidate in order to receive the rewards. Rewards for its stakers
+		/// are distributed afterwards in pages via [`Self::reward_stakers_page`], or recorded in
+		/// [`RewardPerStake`] if stakers claim them.
 		///
-		/// Returns the number of rewarded stakers.
+		/// Returns the cursor to distribute the rewards among the stakers, if any.
 		fn do_reward_collator(
 			collator: &T::AccountId,
 			blocks: u32,
 			session: SessionIndex,
+		) -> Option<RewardCursor<T::AccountId, BalanceOf<T>>> {
+			if !Candidates::<T>::contains_key(collator) {
+				return None;
+			}
+			let (_, rewardable_blocks) = TotalBlocks::<T>::get(session);
+			// We cannot divide by zero.
+			if rewardable_blocks.is_zero() {
+				log::debug!(
+					"Rewardable blocks is zero. Skipping rewards for collators and stakers..."
+				);
+				return None;
+			}
+			if blocks > rewardable_blocks {
+				// The only case this could happen is if the candidate was an invulnerable during the session.
+				log::warn!(
+					"Cannot reward collator {:?} for producing more blocks than rewardable ones",
+					collator
+				);
+				return None;
+			}
+
+			Self::apply_commission_change(collator);
+			let collator_info = Self::get_candidate(collator).ok()?;
+			let commission = collator_info.commission.min(MaxCommission::<T>::get());
+			let (collator_only_reward, stakers_rewards) = Self::split_rewards(
+				Rewards::<T>::get(session),
+				commission,
+				blocks,
+				rewardable_blocks,
+			);
+			// Reward collator. Note these rewards are not autocompounded.
+			if let Err(error) = Self::do_reward_single(collator, collator_only_reward, session) {
+				log::warn!(target: LOG_TARGET, "Failure rewarding collator {:?}: {:?}", collator, error);
+			}
+
+			// Again, we cannot divide by zero.
+			if collator_info.stake.is_zero() {
+				log::debug!(
+					"Candidate {:?} has no stakers. Skipping rewards for stakers...",
+					collator
+				);
+				return None;
+			}
+
+			let total_weight = Self::candidate_reward_weight(collator, collator_info.stake);
+			if T::RewardsMode::get() == RewardsMode::Pull {
+				if let Some(reward_per_stake) =
+					FixedU128::checked_from_rational(stakers_rewards, total_weight)
+				{
+					RewardPerStake::<T>::insert(session, collator, reward_per_stake);
+					UnclaimedRewards::<T>::mutate(session, |unclaimed| {
+						unclaimed.saturating_accrue(stakers_rewards)
+					});
+				}
+				return None;
+			}
+			Some(RewardCursor {
+				session,
+				collator: collator.clone(),
+				last_staker: None,
+				total_stake: total_weight,
+				stakers_rewards,
+				remaining_rewards: stakers_rewards,
+			})
+		}
+
+		/// Distributes the rewards among the next page of stakers of the collator being rewarded.
+		///
+		/// At most [`Config::RewardPageSize`] stakers are processed. Rewards are calculated based
+		/// on the stake the collator had when the distribution started, and they never exceed the
+		/// rewards available for the stakers.
+		///
+		/// Returns the number of stakers processed and the number of auto compounds.
+		pub(crate) fn reward_stakers_page(
+			mut cursor: RewardCursor<T::AccountId, BalanceOf<T>>,
 		) -> (u32, u32) {
 			let mut total_stakers = 0;
 			let mut total_compound = 0;
-			if let Ok(pos) = Self::get_candidate(collator) {
-				let (_, rewardable_blocks) = TotalBlocks::<T>::get(session);
-				// We cannot divide by zero.
-				if rewardable_blocks.is_zero() {
-					log::debug!(
-						"Rewardable blocks is zero. Skipping rewards for collators and stakers..."
-					);
-					return (0, 0);
+			let collator = cursor.collator.clone();
+			let session = cursor.session;
+			if !Candidates::<T>::contains_key(&collator) {
+				log::warn!("Collator {:?} is no longer a candidate", collator);
+				CurrentRewardCursor::<T>::kill();
+				return (0, 0);
+			}
+
+			let page_size = T::RewardPageSize::get() as usize;
+			let mut page = match &cursor.last_staker {
+				Some(last_staker) => Stake::<T>::iter_prefix_from(
+					&collator,
+					Stake::<T>::hashed_key_for(&collator, last_staker),
+				),
+				None => Stake::<T>::iter_prefix(&collator),
+			}
+			// Take one more to know whether there are more pages left.
+			.take(page_size.saturating_add(1))
+			.collect::<Vec<_>>();
+			let is_last_page = page.len() <= page_size;
+			page.truncate(page_size);
+
+			for (staker, info) in page.iter() {
+				total_stakers += 1;
+				if info.session >= session {
+					// This staker joined during the session rewards are being distributed for.
+					// No rewards for the staker for this session.
+					continue;
 				}
-				if blocks > rewardable_blocks {
-					// The only case this could happen is if the candidate was an invulnerable during the session.
-					log::warn!("Cannot reward collator {:?} for producing more blocks than rewardable ones", collator);
-					return (0, 0);
+				let weight = Self::reward_weight(staker, &collator, info.stake);
+				let staker_reward: BalanceOf<T> =
+					Perbill::from_rational(weight, cursor.total_stake)
+						.mul_floor(cursor.stakers_rewards)
+						.min(cursor.remaining_rewards);
+				match Self::do_reward_single(staker, staker_reward, session) {
+					Err(error) => log::warn!(
+						target: LOG_TARGET,
+						"Failure rewarding staker {:?}: {:?}",
+						staker,
+						error
+					),
+					Ok(compound_amount) => {
+						cursor.remaining_rewards.saturating_reduce(staker_reward);
+						// AutoCompound
+						total_compound += 1;
+						let compound_amount = Self::compoundable_amount(&collator, compound_amount);
+						if !compound_amount.is_zero() {
+							// We sort at the end, when the whole stake is included.
+							if let Err(error) = Self::do_stake_for_account(
+								staker,
+								&collator,
+								compound_amount,
+								false,
+								true,
+								StakeChangeReason::AutoCompounded,
+							) {
+								log::warn!(
+									target: LOG_TARGET,
+									"Failure autocompounding for staker {:?} to candidate {:?}: {:?}",
+									staker,
+									collator,
+									error
+								);
+							}
+						}
+					},
 				}
+			}
+			// No need to sort again if no new investments were made.
+			if !total_compound.is_zero() {
+				Self::reassign_candidate_position(&collator, None);
+			}
 
-				if let Some(collator_info) = CandidateList::<T>::get().get(pos) {
-					let total_rewards = Rewards::<T>::get(session);
-					let rewards_all: BalanceOf<T> =
-						total_rewards.saturating_mul(blocks.into()) / rewardable_blocks.into();
-					let collator_percentage = CollatorRewardPercentage::<T>::get();
-					let collator_only_reward = collator_percentage.mul_floor(rewards_all);
-					// Reward collator. Note these rewards are not autocompounded.
-					if let Err(error) =
-						Self::do_reward_single(collator, collator_only_reward, session)
-					{
-						log::warn!(target: LOG_TARGET, "Failure rewarding collator {:?}: {:?}", collator, error);
-					}
+			if is_last_page {
+				CurrentRewardCursor::<T>::kill();
+			} else {
+				cursor.last_staker = page.last().map(|(staker, _)| staker.clone());
+				CurrentRewardCursor::<T>::put(cursor);
+			}
 
-					// Again, we cannot divide by zero.
-					if collator_info.stake.is_zero() {
-						log::debug!(
-							"Candidate {:?} has no stakers. Skipping rewards for stakers...",
-							collator
-						);
-						return (0, 0);
-					}
+			(total_stakers, total_compound)
+		}
 
-					// Reward stakers
-					let stakers_only_rewards = rewards_all.saturating_sub(collator_only_reward);
-					Stake::<T>::iter_prefix(collator).for_each(|(staker, info)| {
-						if info.session >= session {
-							// This staker joined during the session rewards are being distributed for.
-							// No rewards for the staker for this session.
-							return;
-						}
-						total_stakers += 1;
-						let staker_reward: BalanceOf<T> =
-							Perbill::from_rational(info.stake, collator_info.stake)
-								.mul_floor(stakers_only_rewards);
-						if let Err(error) = Self::do_reward_single(&staker, staker_reward, session)
-						{
-							log::warn!(target: LOG_TARGET, "Failure rewarding staker {:?}: {:?}", staker, error);
-						} else {
-							// AutoCompound
-							total_compound += 1;
-							let compound_percentage = AutoCompound::<T>::get(staker.clone());
-							let compound_amount = compound_percentage.mul_floor(staker_reward);
-							if !compound_amount.is_zero() {
-								// We sort at the end, when the whole stake is included.
-								if let Err(error) =
-									Self::do_stake_at_position(&staker, compound_amount, pos, false)
-								{
-									log::warn!(
-										target: LOG_TARGET,
-										"Failure autocompounding for staker {:?} to candidate {:?}: {:?}",
-										staker,
-										collator,
-										error
-									);
-								}
-							}
-						}
+		/// Claims the rewards `staker` obtained from staking on `candidate` and sorts the candidate
+		/// list afterwards, as rewards might have been auto-compounded.
+		///
+		/// Returns the number of sessions rewards were claimed for.
+		fn do_claim_rewards_and_sort(
+			staker: &T::AccountId,
+			candidate: &T::AccountId,
+		) -> Result<u32, DispatchError> {
+			ensure!(T::RewardsMode::get() == RewardsMode::Pull, Error::<T>::RewardsNotClaimable);
+			let (rewards, sessions) = Self::do_claim_rewards(staker, candidate)?;
+			ensure!(!rewards.is_zero(), Error::<T>::NoPendingRewards);
+			Self::reassign_candidate_position(candidate, None);
+			Ok(sessions)
+		}
+
+		/// Claims the rewards `staker` obtained from staking on `candidate` during the sessions
+		/// that are still claimable, skipping the session the stake was first deposited in.
+		/// Rewards are calculated using the current stake and lock bonus, so they must be claimed
+		/// before any of them changes.
+		///
+		/// Part of the rewards is staked again on the candidate according to the auto-compound
+		/// percentage of the staker. The candidate list is not sorted.
+		///
+		/// Returns the amount of rewards claimed and the number of sessions processed.
+		pub fn do_claim_rewards(
+			staker: &T::AccountId,
+			candidate: &T::AccountId,
+		) -> Result<(BalanceOf<T>, u32), DispatchError> {
+			let info = Stake::<T>::get(candidate, staker);
+			if info.stake.is_zero() {
+				return Ok((Zero::zero(), 0));
+			}
+			let current_session = CurrentSession::<T>::get();
+			// Rewards for the previous session might not be recorded yet.
+			let mut end_session = current_session;
+			if ProducedBlocks::<T>::contains_key(current_session.saturating_sub(1), candidate) {
+				end_session.saturating_dec();
+			}
+			let start_session = LastClaimedSession::<T>::get(candidate, staker)
+				.map_or(info.session, |session| session.max(info.session))
+				.saturating_add(1)
+				.max(current_session.saturating_sub(T::RewardsHistoryDepth::get()));
+			if start_session >= end_session {
+				return Ok((Zero::zero(), 0));
+			}
+
+			let weight = Self::reward_weight(staker, candidate, info.stake);
+			let mut rewards: BalanceOf<T> = Zero::zero();
+			for session in start_session..end_session {
+				if let Some(reward_per_stake) = RewardPerStake::<T>::get(session, candidate) {
+					UnclaimedRewards::<T>::mutate(session, |unclaimed| {
+						let reward = reward_per_stake.saturating_mul_int(weight).min(*unclaimed);
+						unclaimed.saturating_reduce(reward);
+						rewards.saturating_accrue(reward);
 					});
-					// No need to sort again if no new investments were made.
-					if !total_compound.is_zero() {
-						let _ = Self::reassign_candidate_position(pos);
+				}
+			}
+			LastClaimedSession::<T>::insert(candidate, staker, end_session - 1);
+
+			if !rewards.is_zero() {
+				let compound_amount =
+					Self::compoundable_amount(candidate, Self::pay_reward(staker, rewards)?);
+				Self::deposit_event(Event::RewardsClaimed {
+					staker: staker.clone(),
+					candidate: candidate.clone(),
+					amount: rewards,
+				});
+				if !compound_amount.is_zero() {
+					if Candidates::<T>::contains_key(candidate) {
+						if let Err(error) = Self::do_stake_for_account(
+							staker,
+							candidate,
+							compound_amount,
+							false,
+							true,
+							StakeChangeReason::AutoCompounded,
+						) {
+							log::warn!(
+								target: LOG_TARGET,
+								"Failure autocompounding for staker {:?} to candidate {:?}: {:?}",
+								staker,
+								candidate,
+								error
+							);
+						}
 					}
-				} else {
-					log::warn!("Collator {:?} is no longer a candidate", collator);
 				}
 			}
+			Ok((rewards, end_session - start_session))
+		}
 
-			(total_stakers, total_compound)
+		/// Claims the pending rewards of `staker` from `candidate` before its stake changes, if
+		/// rewards are claimed by stakers. Failures do not prevent the stake from changing.
+		fn settle_rewards(staker: &T::AccountId, candidate: &T::AccountId) {
+			if T::RewardsMode::get() == RewardsMode::Push {
+				return;
+			}
+			if let Err(error) =
+				with_storage_layer(|| Self::do_claim_rewards(staker, candidate).map(|_| ()))
+			{
+				log::warn!(
+					target: LOG_TARGET,
+					"Failure claiming rewards for staker {:?} from candidate {:?}: {:?}",
+					staker,
+					candidate,
+					error
+				);
+			}
+		}
+
+		/// Weight of settling the pending rewards of a staker before its stake changes.
+		pub fn settle_rewards_weight() -> Weight {
+			match T::RewardsMode::get() {
+				RewardsMode::Push => Weight::zero(),
+				RewardsMode::Pull => T::WeightInfo::claim_rewards(T::RewardsHistoryDepth::get()),
+			}
+		}
+
+		/// Splits the share of `total_rewards` obtained by a candidate for producing `blocks` out of
+		/// `rewardable_blocks` into the commission of the candidate and the rewards for its stakers.
+		fn split_rewards(
+			total_rewards: BalanceOf<T>,
+			commission: Percent,
+			blocks: u32,
+			rewardable_blocks: u32,
+		) -> (BalanceOf<T>, BalanceOf<T>) {
+			// We cannot divide by zero.
+			if rewardable_blocks.is_zero() {
+				return (Zero::zero(), Zero::zero());
+			}
+			let rewards_all: BalanceOf<T> =
+				total_rewards.saturating_mul(blocks.into()) / rewardable_blocks.into();
+			let collator_only_reward = commission.mul_floor(rewards_all);
+			(collator_only_reward, rewards_all.saturating_sub(collator_only_reward))
+		}
+
+		/// Returns the commission that will be applied when rewarding a given candidate, including
+		/// any commission increase whose delay already passed.
+		fn effective_commission(candidate: &CandidateInfo<T::AccountId, BalanceOf<T>>) -> Percent {
+			let commission = match CommissionChangeRequests::<T>::get(&candidate.who) {
+				Some(request) if request.block <= Self::current_block_number() => {
+					request.commission
+				},
+				_ => candidate.commission,
+			};
+			commission.min(MaxCommission::<T>::get())
+		}
+
+		/// Applies the commission increase requested by a given candidate, if the delay passed.
+		fn apply_commission_change(candidate: &T::AccountId) {
+			if let Some(request) = CommissionChangeRequests::<T>::get(candidate) {
+				if request.block <= Self::current_block_number() {
+					Candidates::<T>::mutate(candidate, |maybe_info| {
+						if let Some(info) = maybe_info {
+							info.commission = request.commission;
+						}
+					});
+					CommissionChangeRequests::<T>::remove(candidate);
+					Self::deposit_event(Event::CommissionSet {
+						candidate: candidate.clone(),
+						commission: request.commission,
+					});
+				}
+			}
 		}
 
+		/// Delivers `reward` to `who` for a given session according to its [`RewardDestination`].
+		///
+		/// Returns the part of the reward that should be staked again.
 		fn do_reward_single(
 			who: &T::AccountId,
 			reward: BalanceOf<T>,
 			session: SessionIndex,
-		) -> DispatchResult {
-			T::Currency::transfer(&Self::account_id(), who, reward, Preserve)?;
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let compound_amount = Self::pay_reward(who, reward)?;
 			Self::deposit_event(Event::StakingRewardReceived {
 				staker: who.clone(),
 				amount: reward,
 				session,
 			});
-			Ok(())
+			Ok(compound_amount)
+		}
+
+		/// Transfers `reward` from the pot according to the [`RewardDestination`] of `who`.
+		///
+		/// Returns the part of the reward that should be staked again.
+		fn pay_reward(
+			who: &T::AccountId,
+			reward: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let pot = Self::account_id();
+			match RewardDestinations::<T>::get(who) {
+				RewardDestination::Free => {
+					T::Currency::transfer(&pot, who, reward, Preserve)?;
+					Self::accrue_shared_funds(who, reward);
+					Ok(AutoCompound::<T>::get(who).mul_floor(reward))
+				},
+				RewardDestination::Staked => {
+					T::Currency::transfer(&pot, who, reward, Preserve)?;
+					Self::accrue_shared_funds(who, reward);
+					Ok(reward)
+				},
+				RewardDestination::Account(account) => {
+					T::Currency::transfer(&pot, &account, reward, Preserve)?;
+					Ok(Zero::zero())
+				},
+				RewardDestination::Burn => {
+					// Dropping the withdrawn funds reduces the total issuance.
+					let _ = T::Currency::withdraw(&pot, reward, Exact, Preserve, Polite)?;
+					Ok(Zero::zero())
+				},
+			}
 		}
 
 		/// Gets the current block number
@@ -1515,98 +4547,347 @@ pub mod pallet {
 
 		/// Assemble the current set of candidates and invulnerables into the next collator set.
 		///
+		/// Candidates are selected by their position in the list of candidates, where stake above
+		/// [`MaxCandidateStake`] is not taken into account.
+		///
 		/// This is done on the fly, as frequent as we are told to do so, as the session manager.
 		pub fn assemble_collators() -> Vec<T::AccountId> {
 			// Casting `u32` to `usize` should be safe on all machines running this.
 			let desired_candidates = DesiredCandidates::<T>::get() as usize;
 			let mut collators = Invulnerables::<T>::get().to_vec();
-			collators.extend(
-				CandidateList::<T>::get()
-					.iter()
-					.rev()
-					.take(desired_candidates)
-					.cloned()
-					.map(|candidate_info| candidate_info.who),
-			);
+			collators.extend(Self::top_candidates(desired_candidates));
 			collators
 		}
 
-		/// Kicks out candidates that did not produce a block in the kick threshold, and refunds
-		/// the stakers. The candidate is refunded after a delay.
-		///
-		/// Return value is the number of candidates left in the list.
-		pub fn kick_stale_candidates() -> u32 {
-			let now = Self::current_block_number();
-			let kick_threshold = T::KickThreshold::get();
-			let min_collators = T::MinEligibleCollators::get();
-			let candidacy_bond = CandidacyBond::<T>::get();
-			let candidates = CandidateList::<T>::get();
-			candidates
-                .into_iter()
-                .filter_map(|candidate| {
-                    let last_block = LastAuthoredBlock::<T>::get(candidate.who.clone());
-                    let since_last = now.saturating_sub(last_block);
-
-                    let is_invulnerable = Self::is_invulnerable(&candidate.who);
-                    let is_lazy = since_last >= kick_threshold;
-
-                    if is_invulnerable {
-                        // If they are invulnerable there is no reason for them to be in `CandidateList` also.
-                        // We don't even care about the min collators here, because an Account
-                        // should not be a collator twice.
-                        let _ = Self::try_remove_candidate_from_account(&candidate.who, false, false);
-                        None
-                    } else if Self::eligible_collators() <= min_collators || (!is_lazy && candidate.deposit.saturating_add(candidate.stake) >= candidacy_bond) {
-                        // Either this is a good collator (not lazy) or we are at the minimum
-                        // that the system needs. They get to stay, as long as they have sufficient deposit plus stake.
-                        Some(candidate)
-                    } else {
-                        // This collator has not produced a block recently enough. Bye bye.
-                        let _ = Self::try_remove_candidate_from_account(&candidate.who, true, true);
-                        None
-                    }
-                })
-                .count()
-                .try_into()
-                .expect("filter_map operation can't result in a bounded vec larger than its original; qed")
+		/// Flags the candidates that should be kicked out, such as those that did not produce a
+		/// block in the kick threshold. Flagged candidates are no longer selected as collators,
+		/// and are removed later on by [`Self::kick_stale_candidates`].
+		///
+		/// Return value is the number of candidates flagged.
+		pub fn flag_stale_candidates() -> u32 {
+			let mut eligible_collators = Self::eligible_collators();
+			let mut flagged = 0u32;
+			for candidate in Self::candidate_list() {
+				if StaleCandidates::<T>::contains_key(&candidate.who) {
+					continue;
+				}
+				if let Some(reason) = Self::kick_reason(&candidate, eligible_collators) {
+					eligible_collators.saturating_dec();
+					flagged.saturating_inc();
+					StaleCandidates::<T>::insert(&candidate.who, reason);
+					Self::deposit_event(Event::CandidateFlaggedAsStale {
+						account_id: candidate.who,
+						reason,
+					});
+				}
+			}
+			flagged
+		}
+
+		/// Kicks out candidates flagged as stale within the given weight limit, and refunds the
+		/// stakers. The candidate is refunded after a delay.
+		///
+		/// Return value is the weight consumed.
+		pub fn kick_stale_candidates(max_weight: Weight) -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+			let kick_weight = T::WeightInfo::kick_stale_candidate();
+			let mut stale_candidates = StaleCandidates::<T>::iter();
+			while weight.saturating_add(kick_weight).all_lte(max_weight) {
+				if let Some((account, reason)) = stale_candidates.next() {
+					// If they are invulnerable there is no reason for them to be in `Candidates`
+					// also. We don't even care about the min collators here, because an Account
+					// should not be a collator twice. Otherwise, this collator has not produced a
+					// block recently enough, or does not have sufficient deposit or stake on
+					// itself. Bye bye.
+					let penalize = reason != KickReason::Invulnerable;
+					// Candidates that cannot be removed lose the flag, so that they are not tried
+					// again every block at the expense of the rest. They are flagged again when the
+					// session ends if they should still be kicked out.
+					if let Err(error) = with_storage_layer(|| {
+						Self::try_remove_candidate_from_account(&account, penalize, penalize)
+					}) {
+						log::warn!(
+							target: LOG_TARGET,
+							"Could not kick out candidate {:?}: {:?}",
+							account,
+							error
+						);
+						StaleCandidates::<T>::remove(&account);
+						Self::deposit_event(Event::CandidateKickFailed {
+							account_id: account,
+							error,
+						});
+					}
+					weight.saturating_accrue(kick_weight);
+				} else {
+					break;
+				}
+			}
+			weight
+		}
+
+		/// Returns the reason why a candidate should be kicked out of the candidate list when a
+		/// new session starts, if any, given the current number of eligible collators.
+		///
+		/// Candidates get to stay if they are good collators (not lazy) and have sufficient deposit
+		/// and stake on themselves, or if we are at the minimum number of collators the
+		/// system needs.
+		fn kick_reason(
+			candidate: &CandidateInfo<T::AccountId, BalanceOf<T>>,
+			eligible_collators: u32,
+		) -> Option<KickReason> {
+			if Self::is_invulnerable(&candidate.who) {
+				return Some(KickReason::Invulnerable);
+			}
+			if eligible_collators <= T::MinEligibleCollators::get() {
+				return None;
+			}
+			let now = Self::current_block_number();
+			let last_block = LastAuthoredBlock::<T>::get(&candidate.who);
+			let since_last = now.saturating_sub(last_block);
+			if since_last >= T::KickThreshold::get() {
+				Some(KickReason::Lazy)
+			} else if candidate.deposit < CandidacyBond::<T>::get()
+				&& now >= CandidacyBondGraceEnd::<T>::get()
+			{
+				Some(KickReason::InsufficientBond)
+			} else if Stake::<T>::get(&candidate.who, &candidate.who).stake
+				< MinSelfStake::<T>::get()
+			{
+				Some(KickReason::InsufficientSelfStake)
+			} else {
+				None
+			}
+		}
+
+		/// Simulates the selection of the collator set for the next session without modifying
+		/// the storage, as done by [`Self::flag_stale_candidates`] and
+		/// [`Self::assemble_collators`].
+		///
+		/// Candidates already flagged as stale are reported as kicked out.
+		pub fn preview_next_collators() -> CollatorSetPreview<T::AccountId, BalanceOf<T>> {
+			let mut eligible_collators = Self::eligible_collators();
+			let mut kicked = Vec::new();
+			let mut candidates = Vec::new();
+			for candidate in Self::candidate_list() {
+				if let Some(reason) = StaleCandidates::<T>::get(&candidate.who) {
+					kicked.push((candidate.who, reason));
+					continue;
+				}
+				match Self::kick_reason(&candidate, eligible_collators) {
+					Some(reason) => {
+						eligible_collators.saturating_dec();
+						kicked.push((candidate.who, reason));
+					},
+					None => candidates.push(candidate),
+				}
+			}
+
+			// Casting `u32` to `usize` should be safe on all machines running this.
+			let desired_candidates = DesiredCandidates::<T>::get() as usize;
+			let selected = candidates.iter().rev().take(desired_candidates).collect::<Vec<_>>();
+			let stake_cutoff = match selected.last() {
+				Some(candidate) if candidates.len() >= desired_candidates => {
+					Self::ranking_stake(candidate.stake, MaxCandidateStake::<T>::get())
+				},
+				_ => Zero::zero(),
+			};
+			let mut collators = Invulnerables::<T>::get().to_vec();
+			collators.extend(selected.into_iter().map(|candidate| candidate.who.clone()));
+			CollatorSetPreview { collators, kicked, stake_cutoff }
+		}
+
+		/// Rewards a pending collator from the previous round, if any.
+		///
+		/// Returns the cursor to distribute the rewards among its stakers, if any, and the weight
+		/// consumed removing the expired locks on the stake of the collator.
+		pub(crate) fn reward_one_collator(
+			session: SessionIndex,
+		) -> (Option<RewardCursor<T::AccountId, BalanceOf<T>>>, Weight) {
+			let Some((collator, blocks)) = ProducedBlocks::<T>::drain_prefix(session).next() else {
+				return (None, Weight::zero());
+			};
+			// Expired locks no longer add to the reward weight of the collator.
+			let weight = Self::remove_expired_stake_locks(&collator);
+			(Self::do_reward_collator(&collator, blocks, session), weight)
+		}
+
+		/// Refunds any stake deposited in a given ex-candidate to the corresponding stakers.
+		///
+		/// Returns the amount of refunded stakers.
+		pub(crate) fn refund_stakers(account: &T::AccountId) -> u32 {
+			// `MaxStakers` ensures this is a bounded operation.
+			let stakes = Stake::<T>::iter_prefix(account).collect::<Vec<_>>();
+			let mut count = 0u32;
+			for (staker, StakeInfo { stake, .. }) in stakes {
+				if stake.is_zero() {
+					Stake::<T>::remove(account, &staker);
+					continue;
+				}
+				count.saturating_inc();
+				if let Err(e) =
+					Self::do_unstake(&staker, account, false, false, StakeChangeReason::Refunded)
+				{
+					// This can only occur if the refund must wait for a pending slash and the
+					// staker has too many unstaking requests. The stake is kept so that it can
+					// still be slashed and later unstaked by the staker.
+					log::warn!(
+						"Could not unstake staker {:?} from candidate {:?}: {:?}",
+						staker,
+						account,
+						e
+					);
+				}
+			}
+			count
+		}
+
+		/// Returns the staking position of a given account.
+		pub fn staker_info(
+			account: &T::AccountId,
+		) -> StakerInfo<T::AccountId, BalanceOf<T>, BlockNumberFor<T>> {
+			let stakes = StakedCandidates::<T>::get(account)
+				.into_iter()
+				.map(|candidate| {
+					let info = Stake::<T>::get(&candidate, account);
+					(candidate, info)
+				})
+				.collect();
+			let unstaking_requests = UnstakingRequests::<T>::get(account).into_inner();
+			let current_block = Self::current_block_number();
+			let claimable = unstaking_requests
+				.iter()
+				.filter(|request| {
+					request.block <= current_block && !Self::has_pending_slash(&request.candidate)
+				})
+				.fold(Zero::zero(), |acc: BalanceOf<T>, request| {
+					acc.saturating_add(request.amount)
+				});
+			StakerInfo {
+				stakes,
+				unstaking_requests,
+				claimable,
+				autocompound: AutoCompound::<T>::get(account),
+				total_held: T::Currency::balance_on_hold(&HoldReason::Staking.into(), account),
+			}
 		}
 
-		/// Rewards a pending collator from the previous round, if any.
+		/// Estimates the rewards a stake of `amount` would obtain during a session on each candidate.
 		///
-		/// Returns a tuple with the number of rewards given and the number of auto compounds.
-		pub(crate) fn reward_one_collator(session: SessionIndex) -> (u32, u32) {
-			if let Some((collator, blocks)) = ProducedBlocks::<T>::drain_prefix(session).next() {
-				Self::do_reward_collator(&collator, blocks, session)
-			} else {
-				(0, 0)
-			}
+		/// The estimation assumes the candidates keep producing the same share of blocks they did
+		/// so far in the current session, and that the rewards generated during the session,
+		/// including the extra rewards, match the ones of the previous session.
+		///
+		/// Returns the estimated rewards per candidate, sorted in ascending order by total stake.
+		pub fn projected_rewards(amount: BalanceOf<T>) -> Vec<(T::AccountId, BalanceOf<T>)> {
+			let session = CurrentSession::<T>::get();
+			let session_rewards = match session.checked_sub(1) {
+				Some(previous_session) => Rewards::<T>::get(previous_session),
+				None => {
+					let (total_blocks, _) = TotalBlocks::<T>::get(session);
+					ExtraReward::<T>::get().saturating_mul(total_blocks.into())
+				},
+			};
+			let (_, rewardable_blocks) = TotalBlocks::<T>::get(session);
+			Self::candidate_list()
+				.into_iter()
+				.map(|candidate| {
+					let (_, stakers_rewards) = Self::split_rewards(
+						session_rewards,
+						Self::effective_commission(&candidate),
+						ProducedBlocks::<T>::get(session, &candidate.who),
+						rewardable_blocks,
+					);
+					let total_stake =
+						Self::candidate_reward_weight(&candidate.who, candidate.stake)
+							.saturating_add(amount);
+					let reward = if total_stake.is_zero() {
+						Zero::zero()
+					} else {
+						Perbill::from_rational(amount, total_stake).mul_floor(stakers_rewards)
+					};
+					(candidate.who, reward)
+				})
+				.collect()
 		}
 
-		/// Refunds any stake deposited in a given ex-candidate to the corresponding stakers.
+		/// Returns the rewards `staker` will obtain from staking on `candidate` for the session
+		/// whose rewards are currently being distributed, or zero if they were already delivered.
 		///
-		/// Returns the amount of refunded stakers.
-		pub(crate) fn refund_stakers(account: &T::AccountId) -> u32 {
-			let count = Stake::<T>::iter_prefix(account)
-				.filter_map(|(staker, StakeInfo { stake, .. })| {
-					if !stake.is_zero() {
-						if let Err(e) = Self::do_unstake(&staker, account, false, None, false) {
-							// This should never occur.
-							log::warn!(
-								"Could not unstake staker {:?} from candidate {:?}: {:?}",
-								staker,
-								account,
-								e
-							);
+		/// When rewards are claimed by stakers, the rewards recorded for the session that were not
+		/// claimed yet are returned.
+		pub fn pending_session_rewards(
+			staker: &T::AccountId,
+			candidate: &T::AccountId,
+		) -> BalanceOf<T> {
+			let session = match CurrentSession::<T>::get().checked_sub(1) {
+				Some(session) => session,
+				None => return Zero::zero(),
+			};
+			let info = Stake::<T>::get(candidate, staker);
+			if info.stake.is_zero() || info.session >= session {
+				return Zero::zero();
+			}
+			let weight = Self::reward_weight(staker, candidate, info.stake);
+
+			// The stakers of the candidate are being rewarded in pages.
+			if let Some(cursor) = CurrentRewardCursor::<T>::get() {
+				if cursor.session == session && cursor.collator == *candidate {
+					if let Some(last_staker) = cursor.last_staker {
+						for rewarded_staker in Stake::<T>::iter_key_prefix(candidate) {
+							if rewarded_staker == *staker {
+								return Zero::zero();
+							}
+							if rewarded_staker == last_staker {
+								break;
+							}
 						}
-						Some(())
-					} else {
-						None
 					}
-				})
-				.count() as u32;
-			// `MaxStakers` ensures this is a bounded operation.
-			let _ = Stake::<T>::clear_prefix(account, u32::MAX, None);
-			count
+					return Perbill::from_rational(weight, cursor.total_stake)
+						.mul_floor(cursor.stakers_rewards)
+						.min(cursor.remaining_rewards);
+				}
+			}
+
+			// The candidate was not rewarded yet.
+			let blocks = ProducedBlocks::<T>::get(session, candidate);
+			if !blocks.is_zero() {
+				let candidate_info = match Candidates::<T>::get(candidate) {
+					Some(candidate_info) => candidate_info,
+					None => return Zero::zero(),
+				};
+				let (_, rewardable_blocks) = TotalBlocks::<T>::get(session);
+				if blocks > rewardable_blocks {
+					return Zero::zero();
+				}
+				let (_, stakers_rewards) = Self::split_rewards(
+					Rewards::<T>::get(session),
+					Self::effective_commission(&candidate_info),
+					blocks,
+					rewardable_blocks,
+				);
+				let total_weight = Self::candidate_reward_weight(candidate, candidate_info.stake);
+				return match T::RewardsMode::get() {
+					RewardsMode::Push => {
+						Perbill::from_rational(weight, total_weight).mul_floor(stakers_rewards)
+					},
+					RewardsMode::Pull => {
+						FixedU128::checked_from_rational(stakers_rewards, total_weight)
+							.unwrap_or_default()
+							.saturating_mul_int(weight)
+					},
+				};
+			}
+
+			// The rewards were recorded but not claimed yet.
+			let claimed = LastClaimedSession::<T>::get(candidate, staker)
+				.map_or(false, |last_claimed| last_claimed >= session);
+			match RewardPerStake::<T>::get(session, candidate) {
+				Some(reward_per_stake) if !claimed => reward_per_stake
+					.saturating_mul_int(weight)
+					.min(UnclaimedRewards::<T>::get(session)),
+				_ => Zero::zero(),
+			}
 		}
 
 		/// Ensure the correctness of the state of this pallet.
@@ -1624,6 +4905,19 @@ pub mod pallet {
 		/// ## [`MaxCandidates`]
 		///
 		/// * The amount of stakers per account is limited and its maximum value must not be surpassed.
+		///
+		/// ## [`Candidates`]
+		///
+		/// * All candidates must be in the list of candidates, which must be sorted by total stake.
+		///
+		/// ## [`StaleCandidates`]
+		///
+		/// * Only candidates can be flagged as stale.
+		///
+		/// ## [`StakeLocks`]
+		///
+		/// * Locks must only exist for existing stake, and their bonus must add up to the
+		///   [`CandidateLockBonus`] of the candidate.
 		#[cfg(any(test, feature = "try-runtime"))]
 		pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
 			let desired_candidates = DesiredCandidates::<T>::get();
@@ -1640,8 +4934,106 @@ pub mod pallet {
 			);
 
 			ensure!(
-				StakeCount::<T>::iter_values().all(|count| count < T::MaxStakedCandidates::get()),
-				"Stake count must not exceed MaxStakedCandidates"
+				StakedCandidates::<T>::iter().all(|(staker, candidates)| {
+					candidates
+						.iter()
+						.all(|candidate| !Stake::<T>::get(candidate, &staker).stake.is_zero())
+				}),
+				"Staked candidates must hold stake from the staker"
+			);
+			ensure!(
+				Stake::<T>::iter().all(|(candidate, staker, info)| {
+					info.stake.is_zero() || StakedCandidates::<T>::get(&staker).contains(&candidate)
+				}),
+				"All stake must be tracked in the staked candidates"
+			);
+			ensure!(
+				StakeFunders::<T>::iter().all(|(candidate, staker, _)| {
+					!Stake::<T>::get(&candidate, &staker).stake.is_zero()
+				}),
+				"Stake funders must only be tracked for existing stake"
+			);
+			ensure!(
+				StakeLocks::<T>::iter().all(|(candidate, staker, _)| {
+					!Stake::<T>::get(&candidate, &staker).stake.is_zero()
+				}),
+				"Stake locks must only be tracked for existing stake"
+			);
+			ensure!(
+				CandidateLockBonus::<T>::iter().all(|(candidate, bonus)| {
+					StakeLocks::<T>::iter_prefix_values(&candidate)
+						.fold(BalanceOf::<T>::zero(), |total, lock| {
+							total.saturating_add(lock.bonus)
+						}) == bonus
+				}),
+				"The lock bonus of a candidate must match the bonus of the locks on its stake"
+			);
+			ensure!(
+				Pools::<T>::iter().all(|(pool_id, pool)| {
+					PoolMembers::<T>::iter_prefix_values(pool_id)
+						.fold(BalanceOf::<T>::zero(), |total, points| total.saturating_add(points))
+						== pool.points
+				}),
+				"The points of a pool must match the points of its members"
+			);
+			ensure!(
+				Pools::<T>::iter_keys()
+					.all(|pool_id| SharedFunds::<T>::contains_key(Self::pool_account_id(pool_id))),
+				"The funds of every pool must be tracked"
+			);
+			ensure!(
+				SharedFunds::<T>::iter()
+					.all(|(account, funds)| funds <= Self::total_shared_funds(&account)),
+				"The tracked funds of an account must be covered by the funds it holds"
+			);
+			ensure!(
+				CandidateUnstakers::<T>::iter().all(|(candidate, account, count)| {
+					UnstakingRequests::<T>::get(&account)
+						.iter()
+						.filter(|request| request.candidate == candidate)
+						.count() as u32 == count
+				}),
+				"Indexed unstaking requests must match the unstaking requests of each account"
+			);
+			ensure!(
+				CandidateUnstakeRequestCount::<T>::iter().all(|(candidate, count)| {
+					CandidateUnstakers::<T>::iter_prefix_values(&candidate)
+						.fold(0u32, |total, requests| total.saturating_add(requests))
+						== count
+				}),
+				"The unstaking request count of a candidate must match its indexed requests"
+			);
+			let total_staked = Stake::<T>::iter_values()
+				.map(|info| info.stake)
+				.chain(Candidates::<T>::iter_values().map(|info| info.deposit))
+				.chain(IdleStake::<T>::iter_values())
+				.chain(IdleUnbonding::<T>::iter_values().map(|unbond| unbond.amount))
+				.chain(
+					UnstakingRequests::<T>::iter_values()
+						.flat_map(|requests| requests.into_iter().map(|request| request.amount)),
+				)
+				.fold(BalanceOf::<T>::zero(), |total, amount| total.saturating_add(amount));
+			ensure!(
+				TotalStaked::<T>::get() == total_staked,
+				"The total staked must match the funds held for staking"
+			);
+			ensure!(
+				StaleCandidates::<T>::iter_keys().all(|who| Candidates::<T>::contains_key(who)),
+				"Only candidates can be flagged as stale"
+			);
+
+			let candidates = Self::candidate_list();
+			ensure!(
+				candidates.len() as u32 == Candidates::<T>::count(),
+				"All candidates must be in the list of candidates"
+			);
+			let max_stake = MaxCandidateStake::<T>::get();
+			ensure!(
+				candidates.windows(2).all(|pair| {
+					Self::ranking_stake(pair[0].stake, max_stake)
+						<= Self::ranking_stake(pair[1].stake, max_stake)
+				}),
+				"The list of candidates must be sorted by total stake"
 			);
 
 			Ok(())
@@ -1687,19 +5079,13 @@ pub mod pallet {
 				frame_system::Pallet::<T>::block_number(),
 			);
 
-			// The `expect` below is safe because the list is a `BoundedVec` with a max size of
-			// `T::MaxCandidates`, which is a `u32`. When `decode_len` returns `Some(len)`, `len`
-			// must be valid and at most `u32::MAX`, which must always be able to convert to `u32`.
-			let candidates_len_before: u32 = CandidateList::<T>::decode_len()
-				.unwrap_or_default()
-				.try_into()
-				.expect("length is at most `T::MaxCandidates`, so it must fit in `u32`; qed");
-			let active_candidates_count = Self::kick_stale_candidates();
-			let removed = candidates_len_before.saturating_sub(active_candidates_count);
+			let candidates_len = Candidates::<T>::count();
+			// Kicked out candidates are only flagged here, and removed in the following blocks.
+			let flagged = Self::flag_stale_candidates();
 			let result = Self::assemble_collators();
 
 			frame_system::Pallet::<T>::register_extra_weight_unchecked(
-				T::WeightInfo::new_session(candidates_len_before, removed),
+				T::WeightInfo::new_session(candidates_len, flagged),
 				DispatchClass::Mandatory,
 			);
 			Some(result)
@@ -1710,6 +5096,14 @@ pub mod pallet {
 			TotalBlocks::<T>::insert(index, (0, 0));
 			CurrentSession::<T>::put(index);
 
+			let applied_slashes = Self::apply_unapplied_slashes(index);
+			if applied_slashes > 0 {
+				frame_system::Pallet::<T>::register_extra_weight_unchecked(
+					Self::max_apply_slash_weight().saturating_mul(applied_slashes.into()),
+					DispatchClass::Mandatory,
+				);
+			}
+
 			// cleanup last session's stuff
 			if index > 1 {
 				let last_session = index - 2;
@@ -1717,34 +5111,458 @@ pub mod pallet {
 				Rewards::<T>::remove(last_session);
 				let _ = ProducedBlocks::<T>::clear_prefix(last_session, u32::MAX, None);
 			}
+
+			// Rewards not claimed in time are no longer claimable.
+			if let Some(expired_session) =
+				index.checked_sub(T::RewardsHistoryDepth::get().saturating_add(1))
+			{
+				UnclaimedRewards::<T>::remove(expired_session);
+				let _ = RewardPerStake::<T>::clear_prefix(expired_session, u32::MAX, None);
+			}
 		}
 
 		fn end_session(index: SessionIndex) {
-			// Transfer the extra reward, if any, to the pot.
+			// Add the rewards coming from the configured source to the pot.
 			let pot_account = Self::account_id();
+			let (produced_blocks, _) = TotalBlocks::<T>::get(index);
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				T::RewardSource::provide_rewards(&pot_account, produced_blocks),
+				DispatchClass::Mandatory,
+			);
+
+			// Rewards are the total amount in the pot minus the existential deposit and the
+			// rewards that can still be claimed by stakers.
+			let unclaimed_rewards = UnclaimedRewards::<T>::iter_values()
+				.fold(BalanceOf::<T>::zero(), |acc, unclaimed| acc.saturating_add(unclaimed));
+			let total_rewards = T::Currency::balance(&pot_account)
+				.saturating_sub(T::Currency::minimum_balance())
+				.saturating_sub(unclaimed_rewards);
+			Rewards::<T>::insert(index, total_rewards);
+			Self::deposit_event(Event::<T>::SessionEnded { index, rewards: total_rewards });
+		}
+	}
+
+	impl<T: Config> SlashHandler<T::AccountId, BalanceOf<T>> for Pallet<T> {
+		fn slash(candidate: &T::AccountId, fraction: Perbill) -> BalanceOf<T> {
+			Self::do_slash(candidate, fraction)
+		}
+
+		fn report_offence(candidate: &T::AccountId, fraction: Perbill) -> DispatchResult {
+			Self::do_report_offence(candidate, fraction)
+		}
+	}
+
+	/// Implementation of [`StakingInterface`], so that other pallets can stake through this one.
+	///
+	/// This pallet has neither eras nor controller accounts, so eras are mapped to sessions and
+	/// stashes are their own controllers. Funds bonded are kept as [`IdleStake`] until they are
+	/// nominated, and nominating splits the idle stake evenly among the candidates nominated.
+	/// Stake deposited in candidates that are nominated again is kept, while stake deposited in
+	/// the rest is moved to the new candidates under the same rules as [`Pallet::move_stake`].
+	/// Chilling and unbonding are subject to the regular unstaking delays, which are measured in
+	/// blocks, so the bonding duration is [`Config::UserUnstakingDelay`] rounded up to whole
+	/// sessions of [`Config::SessionLength`] blocks.
+	impl<T: Config> StakingInterface for Pallet<T> {
+		type Balance = BalanceOf<T>;
+		type AccountId = T::AccountId;
+		type CurrencyToVote = SaturatingCurrencyToVote;
+
+		fn minimum_nominator_bond() -> Self::Balance {
+			MinStake::<T>::get()
+		}
+
+		fn minimum_validator_bond() -> Self::Balance {
+			CandidacyBond::<T>::get()
+		}
+
+		fn stash_by_ctrl(controller: &Self::AccountId) -> Result<Self::AccountId, DispatchError> {
+			<Self as StakingInterface>::stake(controller).map(|_| controller.clone())
+		}
+
+		fn bonding_duration() -> EraIndex {
+			let delay: u32 = T::UserUnstakingDelay::get().saturated_into();
+			let session_length: u32 = T::SessionLength::get().saturated_into();
+			delay.div_ceil(session_length.max(1))
+		}
+
+		fn current_era() -> EraIndex {
+			CurrentSession::<T>::get()
+		}
+
+		fn stake(who: &Self::AccountId) -> Result<sp_staking::Stake<Self::Balance>, DispatchError> {
+			let active = StakedCandidates::<T>::get(who)
+				.iter()
+				.fold(IdleStake::<T>::get(who), |acc, candidate| {
+					acc.saturating_add(Stake::<T>::get(candidate, who).stake)
+				});
+			let idle_unbonding =
+				IdleUnbonding::<T>::get(who).map_or(Zero::zero(), |unbond| unbond.amount);
+			let unbonding = UnstakingRequests::<T>::get(who)
+				.iter()
+				.fold(idle_unbonding, |acc, request| acc.saturating_add(request.amount));
+			let total = active.saturating_add(unbonding);
+			ensure!(!total.is_zero() || Candidates::<T>::contains_key(who), Error::<T>::NotStaker);
+			Ok(sp_staking::Stake { total, active })
+		}
+
+		fn bond(
+			who: &Self::AccountId,
+			value: Self::Balance,
+			payee: &Self::AccountId,
+		) -> DispatchResult {
+			ensure!(<Self as StakingInterface>::stake(who).is_err(), Error::<T>::AlreadyStaker);
+			ensure!(value >= MinStake::<T>::get(), Error::<T>::InsufficientStake);
+			Self::hold_stake(who, value)?;
+			IdleStake::<T>::insert(who, value);
+			Self::update_payee(who, payee)
+		}
+
+		fn nominate(who: &Self::AccountId, validators: Vec<Self::AccountId>) -> DispatchResult {
+			<Self as StakingInterface>::stake(who)?;
+			let mut targets: Vec<T::AccountId> = Vec::with_capacity(validators.len());
+			for validator in validators {
+				if !targets.contains(&validator) {
+					targets.push(validator);
+				}
+			}
+			ensure!(!targets.is_empty(), Error::<T>::EmptyTargets);
+			ensure!(
+				targets.len() as u32 <= T::MaxStakedCandidates::get(),
+				Error::<T>::TooManyStakedCandidates
+			);
+			with_storage_layer(|| {
+				// Stake in the candidates no longer nominated is moved to the new targets.
+				let removed = StakedCandidates::<T>::get(who)
+					.into_iter()
+					.filter(|candidate| !targets.contains(candidate))
+					.collect::<Vec<_>>();
+				let moved = !removed.is_empty();
+				if moved {
+					Self::ensure_stake_move_allowed(who)?;
+				}
+				for candidate in removed {
+					Self::do_unstake_to_idle(who, &candidate)?;
+				}
+				let reason =
+					if moved { StakeChangeReason::Moved } else { StakeChangeReason::Staked };
+				let idle = IdleStake::<T>::take(who);
+				let count: BalanceOf<T> = (targets.len() as u32).into();
+				let share = idle / count;
+				for (index, target) in targets.iter().enumerate() {
+					// The remainder of the split is staked on the first candidate.
+					let amount =
+						if index == 0 { share.saturating_add(idle % count) } else { share };
+					if !amount.is_zero() {
+						Self::do_stake_for_account(who, target, amount, true, false, reason)?;
+					}
+				}
+				if moved {
+					Self::note_stake_move(who);
+				}
+				Ok(())
+			})
+		}
+
+		fn chill(who: &Self::AccountId) -> DispatchResult {
+			<Self as StakingInterface>::stake(who)?;
+			with_storage_layer(|| {
+				// The stake goes through the unstaking delay, during which it can still be slashed
+				// for offences committed by the candidates it backed.
+				for candidate in StakedCandidates::<T>::get(who) {
+					let is_candidate = Candidates::<T>::contains_key(&candidate);
+					Self::do_unstake(
+						who,
+						&candidate,
+						is_candidate,
+						true,
+						StakeChangeReason::Unstaked,
+					)?;
+				}
+				Ok(())
+			})
+		}
+
+		fn bond_extra(who: &Self::AccountId, extra: Self::Balance) -> DispatchResult {
+			<Self as StakingInterface>::stake(who)?;
+			with_storage_layer(|| {
+				Self::hold_stake(who, extra)?;
+				IdleStake::<T>::mutate(who, |idle| idle.saturating_accrue(extra));
+				// The extra funds are split among the candidates currently nominated, if any.
+				let nominations = StakedCandidates::<T>::get(who);
+				if nominations.is_empty() {
+					Ok(())
+				} else {
+					Self::nominate(who, nominations.into_inner())
+				}
+			})
+		}
+
+		fn unbond(stash: &Self::AccountId, value: Self::Balance) -> DispatchResult {
+			<Self as StakingInterface>::stake(stash)?;
+			with_storage_layer(|| {
+				// Idle stake is unbonded first, and then the stake deposited in the candidates
+				// nominated the latest.
+				let idle = IdleStake::<T>::get(stash);
+				let from_idle = idle.min(value);
+				if !from_idle.is_zero() {
+					let block =
+						Self::current_block_number().saturating_add(T::UserUnstakingDelay::get());
+					IdleUnbonding::<T>::mutate(stash, |maybe_unbond| {
+						let amount = maybe_unbond
+							.as_ref()
+							.map_or(from_idle, |unbond| unbond.amount.saturating_add(from_idle));
+						*maybe_unbond = Some(IdleUnbond { block, amount });
+					});
+					let left = idle.saturating_sub(from_idle);
+					if left.is_zero() {
+						IdleStake::<T>::remove(stash);
+					} else {
+						IdleStake::<T>::insert(stash, left);
+					}
+				}
+				let mut remaining = value.saturating_sub(from_idle);
+				for candidate in StakedCandidates::<T>::get(stash).iter().rev() {
+					if remaining.is_zero() {
+						break;
+					}
+					let amount = remaining.min(Stake::<T>::get(candidate, stash).stake);
+					let has_penalty = Candidates::<T>::contains_key(candidate);
+					Self::do_unstake_amount(
+						stash,
+						candidate,
+						amount,
+						has_penalty,
+						true,
+						StakeChangeReason::Unstaked,
+					)?;
+					remaining.saturating_reduce(amount);
+				}
+				ensure!(remaining.is_zero(), Error::<T>::InvalidUnstakeAmount);
+				Ok(())
+			})
+		}
+
+		fn update_payee(stash: &Self::AccountId, reward_acc: &Self::AccountId) -> DispatchResult {
+			let destination = if stash == reward_acc {
+				RewardDestination::Free
+			} else {
+				RewardDestination::Account(reward_acc.clone())
+			};
+			Self::do_set_reward_destination(stash, destination);
+			Ok(())
+		}
+
+		fn withdraw_unbonded(
+			stash: Self::AccountId,
+			_num_slashing_spans: u32,
+		) -> Result<bool, DispatchError> {
+			Self::do_claim(&stash)?;
+			if let Some(unbond) = IdleUnbonding::<T>::get(&stash) {
+				if unbond.block <= Self::current_block_number() {
+					IdleUnbonding::<T>::remove(&stash);
+					Self::release_stake(&stash, unbond.amount)?;
+				}
+			}
+			Ok(<Self as StakingInterface>::stake(&stash).is_err())
+		}
+
+		fn desired_validator_count() -> u32 {
+			DesiredCandidates::<T>::get()
+				.saturating_add(Invulnerables::<T>::decode_len().unwrap_or_default() as u32)
+		}
+
+		fn election_ongoing() -> bool {
+			false
+		}
+
+		fn force_unstake(who: Self::AccountId) -> DispatchResult {
+			with_storage_layer(|| {
+				if Candidates::<T>::contains_key(&who) {
+					Self::try_remove_candidate_from_account(&who, true, false)?;
+				}
+				for candidate in StakedCandidates::<T>::get(&who) {
+					Self::settle_rewards(&who, &candidate);
+					Self::remove_stake_lock(&who, &candidate);
+					Self::do_unstake(&who, &candidate, false, true, StakeChangeReason::Unstaked)?;
+				}
+				let idle = IdleStake::<T>::take(&who);
+				if !idle.is_zero() {
+					Self::release_stake(&who, idle)?;
+				}
+				Ok(())
+			})
+		}
+
+		fn is_exposed_in_era(who: &Self::AccountId, era: &EraIndex) -> bool {
+			ProducedBlocks::<T>::contains_key(era, who)
+				|| StakedCandidates::<T>::get(who)
+					.iter()
+					.any(|candidate| ProducedBlocks::<T>::contains_key(era, candidate))
+		}
+
+		fn status(who: &Self::AccountId) -> Result<StakerStatus<Self::AccountId>, DispatchError> {
+			if Candidates::<T>::contains_key(who) {
+				return Ok(StakerStatus::Validator);
+			}
+			let nominations = StakedCandidates::<T>::get(who);
+			if !nominations.is_empty() {
+				return Ok(StakerStatus::Nominator(nominations.into_inner()));
+			}
+			<Self as StakingInterface>::stake(who).map(|_| StakerStatus::Idle)
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn max_exposure_page_size() -> sp_staking::Page {
+			T::MaxStakers::get()
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn add_era_stakers(
+			current_era: &EraIndex,
+			stash: &Self::AccountId,
+			_exposures: Vec<(Self::AccountId, Self::Balance)>,
+		) {
+			ProducedBlocks::<T>::insert(current_era, stash, 1);
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn set_current_era(era: EraIndex) {
+			CurrentSession::<T>::put(era);
+		}
+	}
+
+	/// [`RewardSource`] transferring [`ExtraReward`] per block produced from the extra reward
+	/// pot. Otherwise, rewards only come from the funds sent to the pot and there is no inflation.
+	pub struct ExtraRewardPot<T>(PhantomData<T>);
+
+	impl<T: Config> RewardSource<T::AccountId, BalanceOf<T>> for ExtraRewardPot<T> {
+		fn provide_rewards(pot: &T::AccountId, blocks: u32) -> Weight {
 			let per_block_extra_reward = ExtraReward::<T>::get();
-			if !per_block_extra_reward.is_zero() {
-				let (produced_blocks, _) = TotalBlocks::<T>::get(index);
-				let extra_reward = per_block_extra_reward.saturating_mul(produced_blocks.into());
-				if let Err(error) = T::Currency::transfer(
-					&Self::extra_reward_account_id(),
-					&pot_account,
-					extra_reward,
-					Expendable, // we do not care if the extra reward pot gets destroyed.
-				) {
-					log::warn!(target: LOG_TARGET, "Failure transferring extra rewards to the pallet-collator-staking pot account: {:?}", error);
+			if per_block_extra_reward.is_zero() {
+				return T::DbWeight::get().reads(1);
+			}
+			let extra_reward = per_block_extra_reward.saturating_mul(blocks.into());
+			if let Err(error) = T::Currency::transfer(
+				&Pallet::<T>::extra_reward_account_id(),
+				pot,
+				extra_reward,
+				Expendable, // we do not care if the extra reward pot gets destroyed.
+			) {
+				log::warn!(target: LOG_TARGET, "Failure transferring extra rewards to the pallet-collator-staking pot account: {:?}", error);
+			}
+			T::WeightInfo::provide_rewards_extra_pot()
+		}
+	}
+
+	/// [`RewardSource`] minting `R` per block produced.
+	pub struct FixedMint<T, R>(PhantomData<(T, R)>);
+
+	impl<T: Config, R: Get<BalanceOf<T>>> RewardSource<T::AccountId, BalanceOf<T>> for FixedMint<T, R> {
+		fn provide_rewards(pot: &T::AccountId, blocks: u32) -> Weight {
+			let reward = R::get().saturating_mul(blocks.into());
+			if !reward.is_zero() {
+				if let Err(error) = T::Currency::mint_into(pot, reward) {
+					log::warn!(target: LOG_TARGET, "Failure minting rewards into the pallet-collator-staking pot account: {:?}", error);
 				}
 			}
+			T::WeightInfo::provide_rewards_fixed_mint()
+		}
+	}
 
-			// Rewards are the total amount in the pot minus the existential deposit.
-			let total_rewards =
-				T::Currency::balance(&pot_account).saturating_sub(T::Currency::minimum_balance());
-			Rewards::<T>::insert(index, total_rewards);
-			Self::deposit_event(Event::<T>::SessionEnded { index, rewards: total_rewards });
+	/// [`RewardSource`] minting rewards following the [`InflationCurve`] given by `P`, based on the
+	/// share of the total issuance held for staking, as tracked in [`TotalStaked`].
+	///
+	/// The annual inflation is applied to the total issuance, proportionally to the blocks
+	/// produced during the session.
+	pub struct IdealStakingRateInflation<T, P>(PhantomData<(T, P)>);
+
+	impl<T: Config, P: Get<InflationCurve>> RewardSource<T::AccountId, BalanceOf<T>>
+		for IdealStakingRateInflation<T, P>
+	{
+		fn provide_rewards(pot: &T::AccountId, blocks: u32) -> Weight {
+			let curve = P::get();
+			let total_issuance = T::Currency::total_issuance();
+			let inflation = curve
+				.annual_inflation(Perbill::from_rational(TotalStaked::<T>::get(), total_issuance));
+			let reward = Perbill::from_rational(blocks, curve.blocks_per_year.max(1))
+				.mul_floor(inflation.mul_floor(total_issuance));
+			if !reward.is_zero() {
+				if let Err(error) = T::Currency::mint_into(pot, reward) {
+					log::warn!(target: LOG_TARGET, "Failure minting rewards into the pallet-collator-staking pot account: {:?}", error);
+				}
+			}
+			T::WeightInfo::provide_rewards_inflation()
 		}
 	}
 }
 
+/// Something that can slash candidates and their stakers for committing offences.
+pub trait SlashHandler<AccountId, Balance> {
+	/// Slashes `fraction` of the candidacy bond of `candidate` and of the stake backing it,
+	/// including the stake that is still pending to be unstaked from it.
+	///
+	/// Returns the total amount slashed.
+	fn slash(candidate: &AccountId, fraction: Perbill) -> Balance;
+
+	/// Reports an offence committed by `candidate`, so that it is slashed by `fraction` once the
+	/// slash deferral period is over. Until then the slash can still be cancelled.
+	fn report_offence(candidate: &AccountId, fraction: Perbill) -> DispatchResult;
+}
+
+/// Something that provides the rewards distributed among collators and their stakers.
+pub trait RewardSource<AccountId, Balance> {
+	/// Adds to `pot` the rewards for a session that just ended, during which `blocks` were
+	/// produced.
+	///
+	/// Returns the weight consumed.
+	fn provide_rewards(pot: &AccountId, blocks: u32) -> Weight;
+}
+
+/// Reason why the stake deposited by a staker in a candidate changed.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum StakeChangeReason {
+	/// The staker staked on the candidate, either directly or through another account.
+	Staked,
+	/// The staker removed stake from the candidate.
+	Unstaked,
+	/// The staker moved stake from one candidate to another.
+	Moved,
+	/// Rewards were auto-compounded as stake in the candidate.
+	AutoCompounded,
+	/// The candidate left, so its stake on itself was removed.
+	CandidateRemoved,
+	/// The candidate left, so the stake deposited in it was refunded to the staker.
+	Refunded,
+	/// The candidate was slashed, and the stake deposited in it along with it.
+	Slashed,
+}
+
+/// Something that needs to react to the changes in the stake deposited in candidates.
+pub trait OnStakeChange<AccountId, Balance> {
+	/// Called after the stake deposited by `staker` in `candidate` changes from `old_stake` to
+	/// `new_stake`. A `new_stake` of zero means the staker no longer backs the candidate.
+	fn on_stake_change(
+		staker: &AccountId,
+		candidate: &AccountId,
+		old_stake: Balance,
+		new_stake: Balance,
+		reason: StakeChangeReason,
+	);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId, Balance: Copy> OnStakeChange<AccountId, Balance> for Tuple {
+	fn on_stake_change(
+		staker: &AccountId,
+		candidate: &AccountId,
+		old_stake: Balance,
+		new_stake: Balance,
+		reason: StakeChangeReason,
+	) {
+		for_tuples!( #( Tuple::on_stake_change(staker, candidate, old_stake, new_stake, reason); )* );
+	}
+}
+
 /// [`TypedGet`] implementation to get the AccountId of the StakingPot.
 pub struct StakingPotAccountId<R>(PhantomData<R>);
 impl<R> TypedGet for StakingPotAccountId<R>
@@ -1757,6 +5575,76 @@ where
 	}
 }
 
+/// Fungible assets implementation without any asset, for runtimes that do not offer liquid
+/// staking. Use it as [`Config::Assets`] along with `()` as [`Config::LiquidStakingAssetId`].
+pub struct NoAssets<Balance>(PhantomData<Balance>);
+impl<AccountId, Balance: tokens::Balance> fungibles::Inspect<AccountId> for NoAssets<Balance> {
+	type AssetId = ();
+	type Balance = Balance;
+
+	fn total_issuance(_: Self::AssetId) -> Self::Balance {
+		Zero::zero()
+	}
+
+	fn minimum_balance(_: Self::AssetId) -> Self::Balance {
+		Zero::zero()
+	}
+
+	fn total_balance(_: Self::AssetId, _: &AccountId) -> Self::Balance {
+		Zero::zero()
+	}
+
+	fn balance(_: Self::AssetId, _: &AccountId) -> Self::Balance {
+		Zero::zero()
+	}
+
+	fn reducible_balance(
+		_: Self::AssetId,
+		_: &AccountId,
+		_: Preservation,
+		_: Fortitude,
+	) -> Self::Balance {
+		Zero::zero()
+	}
+
+	fn can_deposit(
+		_: Self::AssetId,
+		_: &AccountId,
+		_: Self::Balance,
+		_: Provenance,
+	) -> DepositConsequence {
+		DepositConsequence::UnknownAsset
+	}
+
+	fn can_withdraw(
+		_: Self::AssetId,
+		_: &AccountId,
+		_: Self::Balance,
+	) -> WithdrawConsequence<Self::Balance> {
+		WithdrawConsequence::UnknownAsset
+	}
+
+	fn asset_exists(_: Self::AssetId) -> bool {
+		false
+	}
+}
+
+impl<AccountId, Balance: tokens::Balance> fungibles::Unbalanced<AccountId> for NoAssets<Balance> {
+	fn handle_dust(_: fungibles::Dust<AccountId, Self>) {}
+
+	fn write_balance(
+		_: Self::AssetId,
+		_: &AccountId,
+		_: Self::Balance,
+	) -> Result<Option<Self::Balance>, DispatchError> {
+		Err(TokenError::UnknownAsset.into())
+	}
+
+	fn set_total_issuance(_: Self::AssetId, _: Self::Balance) {}
+}
+
+impl<AccountId: Eq, Balance: tokens::Balance> fungibles::Mutate<AccountId> for NoAssets<Balance> {}
+
 sp_api::decl_runtime_apis! {
 	/// This runtime api allows people to query the two pot addresses.
 	pub trait CollatorStakingApi<AccountId>
@@ -1768,4 +5656,25 @@ sp_api::decl_runtime_apis! {
 		/// Queries the extra reward pot account.
 		fn extra_reward_pot_account() -> AccountId;
 	}
+
+	/// This runtime api allows people to query the staking positions of accounts, the rewards
+	/// they can expect and the next collator set.
+	pub trait CollatorStakingInfoApi<AccountId, Balance, BlockNumber>
+	where AccountId: Codec, Balance: Codec, BlockNumber: Codec
+	{
+		/// Queries the staking position of a given account.
+		fn staker_info(account: AccountId) -> StakerInfo<AccountId, Balance, BlockNumber>;
+
+		/// Queries the rewards a stake of `amount` would obtain during a session on each
+		/// candidate.
+		fn projected_rewards(amount: Balance) -> Vec<(AccountId, Balance)>;
+
+		/// Queries the rewards `staker` will obtain from staking on `candidate` for the session
+		/// whose rewards are currently being distributed.
+		fn pending_session_rewards(staker: AccountId, candidate: AccountId) -> Balance;
+
+		/// Queries the collator set that would be selected for the next session, along with the
+		/// candidates that would be kicked out.
+		fn preview_next_collators() -> CollatorSetPreview<AccountId, Balance>;
+	}
 }
@@ -0,0 +1,347 @@
+//! Storage migrations for the collator staking pallet.
+
+use super::*;
+use frame_support::{pallet_prelude::*, storage_alias, traits::OnRuntimeUpgrade};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	Percent,
+};
+use sp_std::vec::Vec;
+
+/// Migration to V2, which introduces per-candidate commissions.
+pub mod v2 {
+	use super::*;
+
+	/// Candidate information as stored in V1.
+	#[derive(Encode, Decode)]
+	pub struct CandidateInfo<AccountId, Balance> {
+		pub who: AccountId,
+		pub stake: Balance,
+		pub deposit: Balance,
+		pub stakers: u32,
+	}
+
+	#[storage_alias]
+	pub type CollatorRewardPercentage<T: Config> = StorageValue<Pallet<T>, Percent, ValueQuery>;
+
+	/// Sets the commission of all existing candidates to the former global
+	/// `CollatorRewardPercentage`, which also becomes the [`MaxCommission`].
+	pub struct MigrateToV2<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = StorageVersion::get::<Pallet<T>>();
+			if on_chain_version != 1 {
+				log::info!(
+					target: LOG_TARGET,
+					"Skipping migration to V2, on-chain storage version is {:?}",
+					on_chain_version
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let commission = CollatorRewardPercentage::<T>::take();
+			MaxCommission::<T>::put(commission);
+			let translated = v4::CandidateList::<T>::translate::<
+				BoundedVec<CandidateInfo<T::AccountId, BalanceOf<T>>, T::MaxCandidates>,
+				_,
+			>(|maybe_candidates| {
+				maybe_candidates.map(|candidates| {
+					let candidates = candidates
+						.into_iter()
+						.map(|c| crate::CandidateInfo {
+							who: c.who,
+							stake: c.stake,
+							deposit: c.deposit,
+							stakers: c.stakers,
+							commission,
+						})
+						.collect::<Vec<_>>();
+					BoundedVec::truncate_from(candidates)
+				})
+			});
+			if translated.is_err() {
+				log::error!(target: LOG_TARGET, "Failed to translate the candidate list");
+			}
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(3, 4)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			ensure!(
+				StorageVersion::get::<Pallet<T>>() >= 2,
+				"Storage version must be at least 2 after the migration"
+			);
+			let max_commission = MaxCommission::<T>::get();
+			ensure!(
+				v4::CandidateList::<T>::get().iter().all(|c| c.commission <= max_commission),
+				"All candidates must have a valid commission"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migration to V3, which keeps track of the candidate unstaking requests come from and indexes
+/// them by that candidate.
+pub mod v3 {
+	use super::*;
+
+	/// Unstaking request as stored in V2.
+	#[derive(Encode, Decode)]
+	pub struct UnstakeRequest<BlockNumber, Balance> {
+		pub block: BlockNumber,
+		pub amount: Balance,
+	}
+
+	/// Adds the candidate to all existing unstaking requests, and indexes them in
+	/// [`CandidateUnstakers`] and [`CandidateUnstakeRequestCount`].
+	///
+	/// The candidate is not known for requests created before this migration, so the account
+	/// owning the request is used instead. Those requests are therefore only affected by slashes
+	/// on the account itself.
+	pub struct MigrateToV3<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = StorageVersion::get::<Pallet<T>>();
+			if on_chain_version != 2 {
+				log::info!(
+					target: LOG_TARGET,
+					"Skipping migration to V3, on-chain storage version is {:?}",
+					on_chain_version
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+			UnstakingRequests::<T>::translate::<
+				BoundedVec<UnstakeRequest<BlockNumberFor<T>, BalanceOf<T>>, T::MaxStakedCandidates>,
+				_,
+			>(|account, requests| {
+				translated += 1;
+				let requests = requests
+					.into_iter()
+					.map(|r| crate::UnstakeRequest {
+						candidate: account.clone(),
+						block: r.block,
+						amount: r.amount,
+					})
+					.collect::<Vec<_>>();
+				if !requests.is_empty() {
+					let count = requests.len() as u32;
+					CandidateUnstakers::<T>::insert(&account, &account, count);
+					CandidateUnstakeRequestCount::<T>::insert(&account, count);
+				}
+				Some(BoundedVec::truncate_from(requests))
+			});
+			StorageVersion::new(3).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(
+				translated.saturating_add(1),
+				translated.saturating_mul(3).saturating_add(1),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			ensure!(
+				StorageVersion::get::<Pallet<T>>() >= 3,
+				"Storage version must be at least 3 after the migration"
+			);
+			ensure!(
+				UnstakingRequests::<T>::iter().all(|(account, requests)| {
+					requests.iter().all(|request| {
+						CandidateUnstakers::<T>::contains_key(&request.candidate, &account)
+					})
+				}),
+				"All unstaking requests must be indexed"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migration to V4, which replaces the candidate list with a map of candidates sorted via a
+/// linked list.
+pub mod v4 {
+	use super::*;
+
+	/// Candidates sorted in ascending order by total stake, as stored up to V3.
+	#[storage_alias]
+	pub type CandidateList<T: Config> = StorageValue<
+		Pallet<T>,
+		BoundedVec<
+			crate::CandidateInfo<<T as frame_system::Config>::AccountId, BalanceOf<T>>,
+			<T as Config>::MaxCandidates,
+		>,
+		ValueQuery,
+	>;
+
+	/// Moves all candidates into [`Candidates`], keeping the order they had in the list.
+	pub struct MigrateToV4<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = StorageVersion::get::<Pallet<T>>();
+			if on_chain_version != 3 {
+				log::info!(
+					target: LOG_TARGET,
+					"Skipping migration to V4, on-chain storage version is {:?}",
+					on_chain_version
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let candidates = CandidateList::<T>::take();
+			let count = candidates.len() as u64;
+			let mut prev: Option<T::AccountId> = None;
+			for (index, candidate) in candidates.iter().enumerate() {
+				let next = candidates.get(index + 1).map(|c| c.who.clone());
+				CandidateListNodes::<T>::insert(&candidate.who, ListNode { prev, next });
+				Candidates::<T>::insert(&candidate.who, candidate.clone());
+				prev = Some(candidate.who.clone());
+			}
+			if let Some(first) = candidates.first() {
+				CandidateListHead::<T>::put(&first.who);
+			}
+			if let Some(last) = candidates.last() {
+				CandidateListTail::<T>::put(&last.who);
+			}
+			StorageVersion::new(4).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(2, count.saturating_mul(3).saturating_add(4))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			Ok((CandidateList::<T>::decode_len().unwrap_or_default() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			ensure!(
+				StorageVersion::get::<Pallet<T>>() >= 4,
+				"Storage version must be at least 4 after the migration"
+			);
+			let count = u32::decode(&mut &state[..])
+				.map_err(|_| "Could not decode the number of candidates")?;
+			ensure!(Candidates::<T>::count() == count, "All candidates must be migrated");
+			ensure!(!CandidateList::<T>::exists(), "The candidate list must be removed");
+			Ok(())
+		}
+	}
+}
+
+/// Migration to V5, which replaces the number of candidates staked on by each user with the
+/// list of those candidates, and keeps track of the total amount held for staking.
+pub mod v5 {
+	use super::*;
+
+	/// Number of candidates staked on by a user, as stored up to V4.
+	#[storage_alias]
+	pub type StakeCount<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Builds [`StakedCandidates`] from the existing stake, removes `StakeCount` and initializes
+	/// [`TotalStaked`] from the stake, candidacy bonds, idle stake and unstaking requests currently
+	/// held by the pallet.
+	///
+	/// The migration runs in a single block and reads every entry in [`Stake`], [`Candidates`],
+	/// [`IdleStake`], [`IdleUnbonding`] and [`UnstakingRequests`]. [`Stake`] holds at most `MaxStakers` entries per
+	/// candidate, and ex-candidates are refunded one per block, so in the worst case it reads
+	/// `(MaxCandidates + ex-candidates pending a refund) * MaxStakers` stake entries and writes
+	/// as many [`StakedCandidates`] entries. The returned weight accounts for every entry read or
+	/// written, and runtimes should check it fits in a block for their state before upgrading.
+	pub struct MigrateToV5<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = StorageVersion::get::<Pallet<T>>();
+			if on_chain_version != 4 {
+				log::info!(
+					target: LOG_TARGET,
+					"Skipping migration to V5, on-chain storage version is {:?}",
+					on_chain_version
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut reads = 0u64;
+			let mut writes = 0u64;
+			let mut total = BalanceOf::<T>::zero();
+			for (candidate, staker, info) in Stake::<T>::iter() {
+				reads += 1;
+				if info.stake.is_zero() {
+					continue;
+				}
+				total.saturating_accrue(info.stake);
+				let pushed = StakedCandidates::<T>::try_mutate(&staker, |candidates| {
+					candidates.try_push(candidate.clone())
+				});
+				if pushed.is_err() {
+					log::error!(
+						target: LOG_TARGET,
+						"Could not track the stake of {:?} in candidate {:?}",
+						staker,
+						candidate
+					);
+				}
+				writes += 1;
+			}
+			for info in Candidates::<T>::iter_values() {
+				total.saturating_accrue(info.deposit);
+				reads += 1;
+			}
+			for idle in IdleStake::<T>::iter_values() {
+				total.saturating_accrue(idle);
+				reads += 1;
+			}
+			for unbond in IdleUnbonding::<T>::iter_values() {
+				total.saturating_accrue(unbond.amount);
+				reads += 1;
+			}
+			for requests in UnstakingRequests::<T>::iter_values() {
+				for request in requests {
+					total.saturating_accrue(request.amount);
+				}
+				reads += 1;
+			}
+			let removed = StakeCount::<T>::clear(u32::MAX, None);
+			TotalStaked::<T>::put(total);
+			StorageVersion::new(5).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(
+				reads.saturating_add(1),
+				writes.saturating_add(removed.unique.into()).saturating_add(2),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			ensure!(
+				StorageVersion::get::<Pallet<T>>() >= 5,
+				"Storage version must be at least 5 after the migration"
+			);
+			ensure!(
+				StakeCount::<T>::iter_keys().next().is_none(),
+				"The stake count must be removed"
+			);
+			ensure!(
+				TotalStaked::<T>::get()
+					>= Candidates::<T>::iter_values().fold(
+						BalanceOf::<T>::zero(),
+						|total, info| {
+							total.saturating_add(info.stake).saturating_add(info.deposit)
+						}
+					),
+				"The total staked must cover the stake and bonds of all candidates"
+			);
+			Ok(())
+		}
+	}
+}
@@ -1,19 +1,21 @@
 use core::marker::PhantomData;
 
-use frame_support::weights::Weight;
+use frame_support::weights::{RuntimeDbWeight, Weight};
 use frame_support::{
 	derive_impl, ord_parameter_types, parameter_types,
-	traits::{ConstBool, ConstU32, ConstU64, FindAuthor, ValidatorRegistration},
+	traits::{
+		AsEnsureOriginWithArg, ConstBool, ConstU32, ConstU64, FindAuthor, ValidatorRegistration,
+	},
 	PalletId,
 };
 use frame_system as system;
-use frame_system::EnsureSignedBy;
+use frame_system::{EnsureRoot, EnsureSigned, EnsureSignedBy};
 use sp_core::H256;
 use sp_runtime::traits::Get;
 use sp_runtime::{
 	testing::UintAuthorityId,
 	traits::{BlakeTwo256, IdentityLookup, OpaqueKeys},
-	BuildStorage, Percent, RuntimeAppPublic,
+	BuildStorage, Perbill, Percent, RuntimeAppPublic,
 };
 
 use crate as collator_staking;
@@ -21,6 +23,8 @@ use crate as collator_staking;
 use super::*;
 
 type Block = frame_system::mocking::MockBlock<Test>;
+
+pub const LIQUID_STAKING_ASSET: u32 = 1;
 type AccountId = <Test as frame_system::Config>::AccountId;
 
 // Configure a mock runtime to test the pallet.
@@ -32,6 +36,7 @@ frame_support::construct_runtime!(
 		Session: pallet_session,
 		Aura: pallet_aura,
 		Balances: pallet_balances,
+		Assets: pallet_assets,
 		CollatorStaking: collator_staking,
 		Authorship: pallet_authorship,
 	}
@@ -40,6 +45,7 @@ frame_support::construct_runtime!(
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub const SS58Prefix: u8 = 42;
+	pub static DbWeight: RuntimeDbWeight = RuntimeDbWeight { read: 0, write: 0 };
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
@@ -47,7 +53,7 @@ impl system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
 	type BlockLength = ();
-	type DbWeight = ();
+	type DbWeight = DbWeight;
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
 	type Nonce = u64;
@@ -90,6 +96,29 @@ impl pallet_balances::Config for Test {
 	type MaxFreezes = ConstU32<0>;
 }
 
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type AssetDeposit = ConstU64<0>;
+	type AssetAccountDeposit = ConstU64<0>;
+	type MetadataDepositBase = ConstU64<0>;
+	type MetadataDepositPerByte = ConstU64<0>;
+	type ApprovalDeposit = ConstU64<0>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
 pub struct Author4;
 impl FindAuthor<u64> for Author4 {
 	fn find_author<'a, I>(_digests: I) -> Option<u64>
@@ -178,6 +207,16 @@ ord_parameter_types! {
 parameter_types! {
 	pub const PotId: PalletId = PalletId(*b"PotStake");
 	pub const ExtraRewardPotId: PalletId = PalletId(*b"PotExtra");
+	pub static StakerRewardsMode: RewardsMode = RewardsMode::Push;
+	pub static MaxKickWeight: Weight = Weight::MAX;
+	pub TestInflationCurve: InflationCurve = InflationCurve {
+		min_inflation: Perbill::zero(),
+		max_inflation: Perbill::from_percent(50),
+		ideal_staking_rate: Perbill::from_percent(50),
+		blocks_per_year: 100,
+	};
+	pub static LiquidStakingAsset: Option<u32> = Some(LIQUID_STAKING_ASSET);
+	pub static StakeChanges: Vec<(AccountId, AccountId, u64, u64, StakeChangeReason)> = vec![];
 }
 
 pub struct IsRegistered;
@@ -204,6 +243,28 @@ impl Get<Option<AccountId>> for SendFundsToAccount40 {
 	}
 }
 
+pub struct SendSlashedFundsToAccount50;
+impl OnUnbalanced<Credit<AccountId, Balances>> for SendSlashedFundsToAccount50 {
+	fn on_nonzero_unbalanced(amount: Credit<AccountId, Balances>) {
+		let _ = Balances::resolve(&50, amount);
+	}
+}
+
+pub struct RecordStakeChanges;
+impl OnStakeChange<AccountId, u64> for RecordStakeChanges {
+	fn on_stake_change(
+		staker: &AccountId,
+		candidate: &AccountId,
+		old_stake: u64,
+		new_stake: u64,
+		reason: StakeChangeReason,
+	) {
+		StakeChanges::mutate(|changes| {
+			changes.push((*staker, *candidate, old_stake, new_stake, reason))
+		});
+	}
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -216,6 +277,7 @@ impl Config for Test {
 	type MinEligibleCollators = ConstU32<1>;
 	type MaxInvulnerables = ConstU32<20>;
 	type KickThreshold = Period;
+	type MaxKickWeight = MaxKickWeight;
 	type CollatorId = <Self as frame_system::Config>::AccountId;
 	type CollatorIdOf = IdentityCollatorMock<Test>;
 	type CollatorRegistration = IsRegistered;
@@ -223,6 +285,20 @@ impl Config for Test {
 	type MaxStakers = ConstU32<25>;
 	type CollatorUnstakingDelay = ConstU64<5>;
 	type UserUnstakingDelay = ConstU64<2>;
+	type SessionLength = Period;
+	type CommissionChangeDelay = ConstU64<5>;
+	type StakeMoveCooldown = ConstU64<5>;
+	type CandidacyBondGracePeriod = ConstU64<5>;
+	type MaxLockTiers = ConstU32<4>;
+	type OnSlash = SendSlashedFundsToAccount50;
+	type RewardPageSize = ConstU32<5>;
+	type SlashDeferDuration = ConstU32<2>;
+	type RewardsMode = StakerRewardsMode;
+	type RewardsHistoryDepth = ConstU32<3>;
+	type RewardSource = ExtraRewardPot<Test>;
+	type OnStakeChange = RecordStakeChanges;
+	type Assets = Assets;
+	type LiquidStakingAssetId = LiquidStakingAsset;
 	type WeightInfo = ();
 }
 
@@ -241,13 +317,19 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 		candidacy_bond: 10,
 		min_stake: 2,
 		invulnerables,
-		collator_reward_percentage: Percent::from_parts(20),
+		max_commission: Percent::from_parts(20),
 		extra_reward: 0,
 	};
 	let session = pallet_session::GenesisConfig::<Test> { keys };
+	let assets = pallet_assets::GenesisConfig::<Test> {
+		assets: vec![(LIQUID_STAKING_ASSET, RootAccount::get(), true, 1)],
+		metadata: vec![],
+		accounts: vec![],
+	};
 	pallet_balances::GenesisConfig::<Test> { balances }
 		.assimilate_storage(&mut t)
 		.unwrap();
+	assets.assimilate_storage(&mut t).unwrap();
 	// collator selection must be initialized before session.
 	collator_staking.assimilate_storage(&mut t).unwrap();
 	session.assimilate_storage(&mut t).unwrap();
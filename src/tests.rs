@@ -1,25 +1,43 @@
 use crate as collator_staking;
 use crate::{
-	mock::*, AutoCompound, CandidacyBond, CandidateInfo, CandidateList, CollatorRewardPercentage,
-	Config, CurrentSession, DesiredCandidates, Error, Event, ExtraReward, Invulnerables,
-	LastAuthoredBlock, MaxDesiredCandidates, MinStake, ProducedBlocks, StakeCount, StakeInfo,
-	TotalBlocks,
+	mock::*, AutoCompound, CandidacyBond, CandidacyBondGraceEnd, CandidateInfo, CandidateListHead,
+	CandidateListTail, CandidateLockBonus, CandidateUnstakeRequestCount, CandidateUnstakers,
+	Candidates, CollatorSetPreview, CommissionChangeRequest, CommissionChangeRequests, Config,
+	CurrentRewardCursor, CurrentSession, DesiredCandidates, Error, Event, ExtraReward, FixedMint,
+	IdealStakingRateInflation, IdleStake, IdleUnbond, IdleUnbonding, InflationCurve, Invulnerables,
+	KickReason, LastAuthoredBlock, LastClaimedSession, LastStakeMove, LockTier, LockTiers,
+	MaxCandidateStake, MaxCommission, MaxDesiredCandidates, MinSelfStake, MinStake, PoolInfo,
+	PoolMembers, Pools, ProducedBlocks, RewardDestination, RewardDestinations, RewardPerStake,
+	RewardSource, Rewards, RewardsMode, SharedFunds, StakeChangeReason, StakeInfo, StakeLock,
+	StakeLocks, StakedCandidates, StaleCandidates, TotalBlocks, TotalStaked, UnclaimedRewards,
+};
+use crate::{
+	SlashHandler, Stake, StakeFunders, UnappliedSlash, UnappliedSlashes, UnstakeRequest,
+	UnstakingRequests, WeightInfo,
 };
-use crate::{Stake, UnstakeRequest, UnstakingRequests};
 use frame_support::pallet_prelude::TypedGet;
 use frame_support::traits::tokens::Preservation::Preserve;
 use frame_support::{
 	assert_noop, assert_ok,
 	traits::{
 		fungible::{Inspect, Mutate},
-		OnInitialize,
+		ConstU64, OnInitialize,
 	},
+	weights::Weight,
+	BoundedVec,
 };
-use sp_runtime::{testing::UintAuthorityId, traits::BadOrigin, BuildStorage, Percent, TokenError};
+use pallet_session::SessionManager;
+use sp_runtime::{
+	testing::UintAuthorityId, traits::BadOrigin, BuildStorage, FixedU128, Perbill, Percent,
+	TokenError,
+};
+use sp_staking::{StakerStatus, StakingInterface};
 use std::ops::RangeInclusive;
 
 type AccountId = <Test as frame_system::Config>::AccountId;
 
+const COMMISSION: Percent = Percent::from_parts(20);
+
 fn fund_account(acc: AccountId) {
 	Balances::mint_into(&acc, 100).unwrap();
 }
@@ -36,7 +54,11 @@ fn register_candidates(range: RangeInclusive<AccountId>) {
 			fund_account(ii);
 			register_keys(ii);
 		}
-		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(ii),));
+		assert_ok!(CollatorStaking::register_as_candidate(
+			RuntimeOrigin::signed(ii),
+			COMMISSION,
+			0,
+		));
 		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::CandidateAdded {
 			account_id: ii,
 			deposit: 10,
@@ -55,14 +77,18 @@ fn basic_setup_works() {
 		assert_eq!(<Test as Config>::CollatorUnstakingDelay::get(), 5);
 		assert_eq!(<Test as Config>::UserUnstakingDelay::get(), 2);
 		assert_eq!(<Test as Config>::MaxStakers::get(), 25);
+		assert_eq!(<Test as Config>::CommissionChangeDelay::get(), 5);
+		assert_eq!(<Test as Config>::StakeMoveCooldown::get(), 5);
+		assert_eq!(<Test as Config>::CandidacyBondGracePeriod::get(), 5);
+		assert_eq!(<Test as Config>::SlashDeferDuration::get(), 2);
 		// should always be MaxInvulnerables + MaxCandidates
 		assert_eq!(MaxDesiredCandidates::<Test>::get(), 40);
 
 		assert_eq!(DesiredCandidates::<Test>::get(), 2);
 		assert_eq!(CandidacyBond::<Test>::get(), 10);
 		assert_eq!(MinStake::<Test>::get(), 2);
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 0);
-		assert_eq!(CollatorRewardPercentage::<Test>::get(), Percent::from_parts(20));
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 0);
+		assert_eq!(MaxCommission::<Test>::get(), Percent::from_parts(20));
 		// The minimum balance should not have been minted
 		assert_eq!(Balances::balance(&CollatorStaking::account_id()), 0);
 		// genesis should sort input
@@ -269,7 +295,7 @@ fn candidate_to_invulnerable_works() {
 		assert_eq!(DesiredCandidates::<Test>::get(), 2);
 		assert_eq!(CandidacyBond::<Test>::get(), 10);
 
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 0);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 0);
 		assert_eq!(Invulnerables::<Test>::get(), vec![1, 2]);
 
 		assert_eq!(Balances::balance(&3), 100);
@@ -296,7 +322,7 @@ fn candidate_to_invulnerable_works() {
 		assert!(Invulnerables::<Test>::get().to_vec().contains(&3));
 		assert_eq!(Stake::<Test>::get(3, 3), StakeInfo { stake: 0, session: 0 });
 		assert_eq!(Balances::balance(&3), 100);
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 1);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 1);
 
 		assert_ok!(CollatorStaking::add_invulnerable(RuntimeOrigin::signed(RootAccount::get()), 4));
 		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::CandidateRemoved {
@@ -314,7 +340,7 @@ fn candidate_to_invulnerable_works() {
 		assert_eq!(Stake::<Test>::get(4, 4), StakeInfo { stake: 0, session: 0 });
 		assert_eq!(Balances::balance(&4), 100);
 
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 0);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 0);
 	});
 }
 
@@ -354,7 +380,7 @@ fn set_candidacy_bond_empty_candidate_list() {
 		initialize_to_block(1);
 		// given
 		assert_eq!(CandidacyBond::<Test>::get(), 10);
-		assert!(CandidateList::<Test>::get().is_empty());
+		assert!(CollatorStaking::candidate_list().is_empty());
 
 		// can decrease without candidates
 		assert_ok!(CollatorStaking::set_candidacy_bond(
@@ -365,7 +391,7 @@ fn set_candidacy_bond_empty_candidate_list() {
 			bond_amount: 7,
 		}));
 		assert_eq!(CandidacyBond::<Test>::get(), 7);
-		assert!(CandidateList::<Test>::get().is_empty());
+		assert!(CollatorStaking::candidate_list().is_empty());
 
 		// rejects bad origin.
 		assert_noop!(CollatorStaking::set_candidacy_bond(RuntimeOrigin::signed(1), 8), BadOrigin);
@@ -378,7 +404,7 @@ fn set_candidacy_bond_empty_candidate_list() {
 		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::NewCandidacyBond {
 			bond_amount: 20,
 		}));
-		assert!(CandidateList::<Test>::get().is_empty());
+		assert!(CollatorStaking::candidate_list().is_empty());
 		assert_eq!(CandidacyBond::<Test>::get(), 20);
 	});
 }
@@ -390,12 +416,13 @@ fn set_candidacy_bond_with_one_candidate() {
 
 		// given
 		assert_eq!(CandidacyBond::<Test>::get(), 10);
-		assert!(CandidateList::<Test>::get().is_empty());
+		assert!(CollatorStaking::candidate_list().is_empty());
 
-		let candidate_3 = CandidateInfo { who: 3, stake: 0, stakers: 0, deposit: 10 };
+		let candidate_3 =
+			CandidateInfo { who: 3, stake: 0, stakers: 0, deposit: 10, commission: COMMISSION };
 
 		register_candidates(3..=3);
-		assert_eq!(CandidateList::<Test>::get(), vec![candidate_3.clone()]);
+		assert_eq!(CollatorStaking::candidate_list(), vec![candidate_3.clone()]);
 		assert_eq!(Stake::<Test>::get(3, 3), StakeInfo { stake: 0, session: 0 });
 
 		// can decrease with one candidate
@@ -408,7 +435,7 @@ fn set_candidacy_bond_with_one_candidate() {
 		}));
 		assert_eq!(CandidacyBond::<Test>::get(), 7);
 		initialize_to_block(10);
-		assert_eq!(CandidateList::<Test>::get(), vec![candidate_3.clone()]);
+		assert_eq!(CollatorStaking::candidate_list(), vec![candidate_3.clone()]);
 
 		// can increase up to initial deposit
 		assert_ok!(CollatorStaking::set_candidacy_bond(
@@ -420,7 +447,7 @@ fn set_candidacy_bond_with_one_candidate() {
 		}));
 		assert_eq!(CandidacyBond::<Test>::get(), 10);
 		initialize_to_block(20);
-		assert_eq!(CandidateList::<Test>::get(), vec![candidate_3.clone()]);
+		assert_eq!(CollatorStaking::candidate_list(), vec![candidate_3.clone()]);
 
 		// can increase past initial deposit, kicking candidates under the new value
 		assert_ok!(CollatorStaking::set_candidacy_bond(
@@ -432,7 +459,7 @@ fn set_candidacy_bond_with_one_candidate() {
 		}));
 		assert_eq!(CandidacyBond::<Test>::get(), 20);
 		initialize_to_block(30);
-		assert_eq!(CandidateList::<Test>::get(), vec![]);
+		assert_eq!(CollatorStaking::candidate_list(), vec![]);
 	});
 }
 
@@ -442,16 +469,19 @@ fn set_candidacy_bond_with_many_candidates_same_deposit() {
 		initialize_to_block(1);
 		// given
 		assert_eq!(CandidacyBond::<Test>::get(), 10);
-		assert!(CandidateList::<Test>::get().is_empty());
+		assert!(CollatorStaking::candidate_list().is_empty());
 
-		let candidate_3 = CandidateInfo { who: 3, stake: 0, stakers: 0, deposit: 10 };
-		let candidate_4 = CandidateInfo { who: 4, stake: 0, stakers: 0, deposit: 10 };
-		let candidate_5 = CandidateInfo { who: 5, stake: 0, stakers: 0, deposit: 10 };
+		let candidate_3 =
+			CandidateInfo { who: 3, stake: 0, stakers: 0, deposit: 10, commission: COMMISSION };
+		let candidate_4 =
+			CandidateInfo { who: 4, stake: 0, stakers: 0, deposit: 10, commission: COMMISSION };
+		let candidate_5 =
+			CandidateInfo { who: 5, stake: 0, stakers: 0, deposit: 10, commission: COMMISSION };
 
 		register_candidates(3..=5);
 
 		assert_eq!(
-			CandidateList::<Test>::get(),
+			CollatorStaking::candidate_list(),
 			vec![candidate_5.clone(), candidate_4.clone(), candidate_3.clone()]
 		);
 
@@ -464,9 +494,10 @@ fn set_candidacy_bond_with_many_candidates_same_deposit() {
 			bond_amount: 2,
 		}));
 		assert_eq!(CandidacyBond::<Test>::get(), 2);
-		CollatorStaking::kick_stale_candidates();
+		CollatorStaking::flag_stale_candidates();
+		CollatorStaking::kick_stale_candidates(Weight::MAX);
 		assert_eq!(
-			CandidateList::<Test>::get(),
+			CollatorStaking::candidate_list(),
 			vec![candidate_5.clone(), candidate_4.clone(), candidate_3.clone()]
 		);
 
@@ -479,9 +510,10 @@ fn set_candidacy_bond_with_many_candidates_same_deposit() {
 			bond_amount: 10,
 		}));
 		assert_eq!(CandidacyBond::<Test>::get(), 10);
-		CollatorStaking::kick_stale_candidates();
+		CollatorStaking::flag_stale_candidates();
+		CollatorStaking::kick_stale_candidates(Weight::MAX);
 		assert_eq!(
-			CandidateList::<Test>::get(),
+			CollatorStaking::candidate_list(),
 			vec![candidate_5.clone(), candidate_4.clone(), candidate_3.clone()]
 		);
 
@@ -494,14 +526,78 @@ fn set_candidacy_bond_with_many_candidates_same_deposit() {
 			bond_amount: 20,
 		}));
 		assert_eq!(CandidacyBond::<Test>::get(), 20);
+		assert_eq!(CandidacyBondGraceEnd::<Test>::get(), 6);
+
+		// stake does not make up for the deposit, so only 4 tops it up in time
 		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 5, 20));
-		let new_candidate_5 = CandidateInfo { who: 5, stake: 20, stakers: 1, deposit: 10 };
+		assert_ok!(CollatorStaking::update_candidacy_bond(RuntimeOrigin::signed(4), 20));
+		let new_candidate_4 =
+			CandidateInfo { who: 4, stake: 0, stakers: 0, deposit: 20, commission: COMMISSION };
+		let new_candidate_5 =
+			CandidateInfo { who: 5, stake: 20, stakers: 1, deposit: 10, commission: COMMISSION };
+		assert_eq!(
+			CollatorStaking::candidate_list(),
+			vec![new_candidate_4.clone(), candidate_3.clone(), new_candidate_5.clone()]
+		);
+
+		// nobody is kicked during the grace period
+		CollatorStaking::flag_stale_candidates();
+		CollatorStaking::kick_stale_candidates(Weight::MAX);
+		assert_eq!(
+			CollatorStaking::candidate_list(),
+			vec![new_candidate_4.clone(), candidate_3.clone(), new_candidate_5.clone()]
+		);
+		System::set_block_number(6);
+		CollatorStaking::flag_stale_candidates();
+		CollatorStaking::kick_stale_candidates(Weight::MAX);
+		assert_eq!(CollatorStaking::candidate_list(), vec![new_candidate_4]);
+	});
+}
+
+#[test]
+fn update_candidacy_bond() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=3);
+		assert_eq!(Balances::balance(&3), 90);
+
+		// not a candidate
+		assert_noop!(
+			CollatorStaking::update_candidacy_bond(RuntimeOrigin::signed(4), 20),
+			Error::<Test>::NotCandidate
+		);
+		// lower than the candidacy bond
+		assert_noop!(
+			CollatorStaking::update_candidacy_bond(RuntimeOrigin::signed(3), 9),
+			Error::<Test>::InsufficientDeposit
+		);
+
+		// increase the deposit
+		assert_ok!(CollatorStaking::update_candidacy_bond(RuntimeOrigin::signed(3), 30));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::CandidateBondUpdated {
+			account_id: 3,
+			deposit: 30,
+		}));
+		assert_eq!(Candidates::<Test>::get(3).unwrap().deposit, 30);
+		assert_eq!(Balances::balance(&3), 70);
+
+		// decrease the deposit, which is returned after a delay
+		assert_ok!(CollatorStaking::update_candidacy_bond(RuntimeOrigin::signed(3), 15));
+		assert_eq!(Candidates::<Test>::get(3).unwrap().deposit, 15);
+		assert_eq!(
+			UnstakingRequests::<Test>::get(3),
+			vec![UnstakeRequest { candidate: 3, block: 6, amount: 15 }]
+		);
+		initialize_to_block(6);
+		assert_ok!(CollatorStaking::claim(RuntimeOrigin::signed(3)));
+		assert_eq!(Balances::balance(&3), 85);
+
+		// the remaining deposit is returned when leaving
+		assert_ok!(CollatorStaking::leave_intent(RuntimeOrigin::signed(3)));
 		assert_eq!(
-			CandidateList::<Test>::get(),
-			vec![candidate_4.clone(), candidate_3.clone(), new_candidate_5.clone()]
+			UnstakingRequests::<Test>::get(3),
+			vec![UnstakeRequest { candidate: 3, block: 11, amount: 15 }]
 		);
-		CollatorStaking::kick_stale_candidates();
-		assert_eq!(CandidateList::<Test>::get(), vec![new_candidate_5]);
 	});
 }
 
@@ -536,7 +632,7 @@ fn cannot_register_candidate_if_too_many() {
 		register_candidates(3..=22);
 
 		assert_noop!(
-			CollatorStaking::register_as_candidate(RuntimeOrigin::signed(23)),
+			CollatorStaking::register_as_candidate(RuntimeOrigin::signed(23), COMMISSION, 0),
 			Error::<Test>::TooManyCandidates,
 		);
 	})
@@ -546,7 +642,7 @@ fn cannot_register_candidate_if_too_many() {
 fn cannot_unregister_candidate_if_too_few() {
 	new_test_ext().execute_with(|| {
 		initialize_to_block(1);
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 0);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 0);
 		assert_eq!(Invulnerables::<Test>::get(), vec![1, 2]);
 		assert_ok!(CollatorStaking::remove_invulnerable(
 			RuntimeOrigin::signed(RootAccount::get()),
@@ -588,7 +684,7 @@ fn cannot_register_as_candidate_if_invulnerable() {
 
 		// can't 1 because it is invulnerable.
 		assert_noop!(
-			CollatorStaking::register_as_candidate(RuntimeOrigin::signed(1)),
+			CollatorStaking::register_as_candidate(RuntimeOrigin::signed(1), COMMISSION, 0),
 			Error::<Test>::AlreadyInvulnerable,
 		);
 	})
@@ -599,7 +695,7 @@ fn cannot_register_as_candidate_if_keys_not_registered() {
 	new_test_ext().execute_with(|| {
 		// can't 42 because keys not registered.
 		assert_noop!(
-			CollatorStaking::register_as_candidate(RuntimeOrigin::signed(42)),
+			CollatorStaking::register_as_candidate(RuntimeOrigin::signed(42), COMMISSION, 0),
 			Error::<Test>::CollatorNotRegistered
 		);
 	})
@@ -612,9 +708,10 @@ fn cannot_register_dupe_candidate() {
 
 		// can add 3 as candidate
 		register_candidates(3..=3);
-		let addition = CandidateInfo { who: 3, stake: 0, stakers: 0, deposit: 10 };
+		let addition =
+			CandidateInfo { who: 3, stake: 0, stakers: 0, deposit: 10, commission: COMMISSION };
 		assert_eq!(
-			CandidateList::<Test>::get().iter().cloned().collect::<Vec<_>>(),
+			CollatorStaking::candidate_list().iter().cloned().collect::<Vec<_>>(),
 			vec![addition]
 		);
 		assert_eq!(LastAuthoredBlock::<Test>::get(3), 11);
@@ -623,7 +720,7 @@ fn cannot_register_dupe_candidate() {
 
 		// but no more
 		assert_noop!(
-			CollatorStaking::register_as_candidate(RuntimeOrigin::signed(3)),
+			CollatorStaking::register_as_candidate(RuntimeOrigin::signed(3), COMMISSION, 0),
 			Error::<Test>::AlreadyCandidate,
 		);
 	})
@@ -641,7 +738,7 @@ fn cannot_register_as_candidate_if_poor() {
 
 		// poor
 		assert_noop!(
-			CollatorStaking::register_as_candidate(RuntimeOrigin::signed(33)),
+			CollatorStaking::register_as_candidate(RuntimeOrigin::signed(33), COMMISSION, 0),
 			TokenError::FundsUnavailable,
 		);
 	});
@@ -656,7 +753,7 @@ fn register_as_candidate_works() {
 		assert_eq!(DesiredCandidates::<Test>::get(), 2);
 		assert_eq!(CandidacyBond::<Test>::get(), 10);
 
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 0);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 0);
 		assert_eq!(Invulnerables::<Test>::get(), vec![1, 2]);
 
 		// take two endowed, non-invulnerables accounts.
@@ -672,7 +769,7 @@ fn register_as_candidate_works() {
 		assert_eq!(Balances::balance(&4), 90);
 		assert_eq!(Stake::<Test>::get(4, 4), StakeInfo { stake: 0, session: 0 });
 
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 2);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 2);
 	});
 }
 
@@ -683,7 +780,12 @@ fn cannot_take_candidate_slot_if_invulnerable() {
 
 		// can't 1 because it is invulnerable.
 		assert_noop!(
-			CollatorStaking::take_candidate_slot(RuntimeOrigin::signed(1), 50u64.into(), 2),
+			CollatorStaking::take_candidate_slot(
+				RuntimeOrigin::signed(1),
+				50u64.into(),
+				2,
+				COMMISSION,
+			),
 			Error::<Test>::AlreadyInvulnerable,
 		);
 	})
@@ -695,13 +797,18 @@ fn cannot_take_candidate_slot_if_list_not_full() {
 		initialize_to_block(1);
 
 		register_candidates(3..=21);
-		assert_eq!(CandidateList::<Test>::decode_len().unwrap_or_default(), 19);
+		assert_eq!(Candidates::<Test>::count(), 19);
 		assert_eq!(<Test as Config>::MaxCandidates::get(), 20);
 
 		fund_account(22);
 		register_keys(22);
 		assert_noop!(
-			CollatorStaking::take_candidate_slot(RuntimeOrigin::signed(22), 50u64.into(), 3),
+			CollatorStaking::take_candidate_slot(
+				RuntimeOrigin::signed(22),
+				50u64.into(),
+				3,
+				COMMISSION,
+			),
 			Error::<Test>::CanRegister,
 		);
 	})
@@ -710,9 +817,14 @@ fn cannot_take_candidate_slot_if_list_not_full() {
 #[test]
 fn cannot_take_candidate_slot_if_keys_not_registered() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(3), COMMISSION, 0));
 		assert_noop!(
-			CollatorStaking::take_candidate_slot(RuntimeOrigin::signed(42), 50u64.into(), 3),
+			CollatorStaking::take_candidate_slot(
+				RuntimeOrigin::signed(42),
+				50u64.into(),
+				3,
+				COMMISSION,
+			),
 			Error::<Test>::CollatorNotRegistered
 		);
 	})
@@ -726,7 +838,8 @@ fn cannot_take_candidate_slot_if_duplicate() {
 		// we cannot take a candidate slot if the list is not already full
 		register_candidates(3..=22);
 
-		let actual_candidates = CandidateList::<Test>::get().iter().cloned().collect::<Vec<_>>();
+		let actual_candidates =
+			CollatorStaking::candidate_list().iter().cloned().collect::<Vec<_>>();
 		assert_eq!(actual_candidates.len(), 20);
 		assert_eq!(LastAuthoredBlock::<Test>::get(3), 11);
 		assert_eq!(LastAuthoredBlock::<Test>::get(4), 11);
@@ -734,7 +847,12 @@ fn cannot_take_candidate_slot_if_duplicate() {
 
 		// but no more
 		assert_noop!(
-			CollatorStaking::take_candidate_slot(RuntimeOrigin::signed(3), 50u64.into(), 4),
+			CollatorStaking::take_candidate_slot(
+				RuntimeOrigin::signed(3),
+				50u64.into(),
+				4,
+				COMMISSION,
+			),
 			Error::<Test>::AlreadyCandidate,
 		);
 	})
@@ -746,10 +864,15 @@ fn cannot_take_candidate_slot_if_target_invalid() {
 		initialize_to_block(1);
 
 		register_candidates(4..=23);
-		assert_eq!(CandidateList::<Test>::get().len(), 20);
+		assert_eq!(CollatorStaking::candidate_list().len(), 20);
 
 		assert_noop!(
-			CollatorStaking::take_candidate_slot(RuntimeOrigin::signed(3), 11u64.into(), 24),
+			CollatorStaking::take_candidate_slot(
+				RuntimeOrigin::signed(3),
+				11u64.into(),
+				24,
+				COMMISSION,
+			),
 			Error::<Test>::NotCandidate,
 		);
 	})
@@ -765,7 +888,12 @@ fn cannot_take_candidate_slot_if_poor() {
 		assert_eq!(Balances::balance(&33), 0);
 
 		// works
-		assert_ok!(CollatorStaking::take_candidate_slot(RuntimeOrigin::signed(3), 20u64.into(), 4));
+		assert_ok!(CollatorStaking::take_candidate_slot(
+			RuntimeOrigin::signed(3),
+			20u64.into(),
+			4,
+			COMMISSION,
+		));
 		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::CandidateReplaced {
 			old: 4,
 			new: 3,
@@ -775,7 +903,12 @@ fn cannot_take_candidate_slot_if_poor() {
 
 		// poor
 		assert_noop!(
-			CollatorStaking::take_candidate_slot(RuntimeOrigin::signed(33), 30u64.into(), 3),
+			CollatorStaking::take_candidate_slot(
+				RuntimeOrigin::signed(33),
+				30u64.into(),
+				3,
+				COMMISSION,
+			),
 			TokenError::FundsUnavailable,
 		);
 	});
@@ -794,11 +927,16 @@ fn cannot_take_candidate_slot_if_insufficient_deposit() {
 		assert_eq!(Balances::balance(&4), 90);
 		assert_eq!(Stake::<Test>::get(4, 4), StakeInfo { stake: 0, session: 0 });
 
-		assert_eq!(CandidateList::<Test>::decode_len().unwrap_or_default(), 20);
+		assert_eq!(Candidates::<Test>::count(), 20);
 		fund_account(23);
 		assert_eq!(Balances::balance(&23), 100);
 		assert_noop!(
-			CollatorStaking::take_candidate_slot(RuntimeOrigin::signed(23), 5u64.into(), 3),
+			CollatorStaking::take_candidate_slot(
+				RuntimeOrigin::signed(23),
+				5u64.into(),
+				3,
+				COMMISSION,
+			),
 			Error::<Test>::InsufficientStake,
 		);
 
@@ -826,7 +964,12 @@ fn cannot_take_candidate_slot_if_deposit_less_than_target() {
 		assert_eq!(Stake::<Test>::get(23, 23), StakeInfo { stake: 0, session: 0 });
 
 		assert_noop!(
-			CollatorStaking::take_candidate_slot(RuntimeOrigin::signed(23), 20u64.into(), 3),
+			CollatorStaking::take_candidate_slot(
+				RuntimeOrigin::signed(23),
+				20u64.into(),
+				3,
+				COMMISSION,
+			),
 			Error::<Test>::InsufficientStake,
 		);
 
@@ -846,11 +989,11 @@ fn take_candidate_slot_works() {
 		assert_eq!(DesiredCandidates::<Test>::get(), 2);
 		assert_eq!(CandidacyBond::<Test>::get(), 10);
 
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 0);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 0);
 		assert_eq!(Invulnerables::<Test>::get(), vec![1, 2]);
 
 		register_candidates(3..=22);
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 20);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 20);
 
 		fund_account(23);
 		register_keys(23);
@@ -859,7 +1002,8 @@ fn take_candidate_slot_works() {
 		assert_ok!(CollatorStaking::take_candidate_slot(
 			RuntimeOrigin::signed(23),
 			50u64.into(),
-			4
+			4,
+			COMMISSION,
 		));
 		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakeAdded {
 			staker: 23,
@@ -886,6 +1030,39 @@ fn take_candidate_slot_works() {
 	});
 }
 
+#[test]
+fn cannot_take_candidate_slot_if_insufficient_self_stake() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		register_candidates(3..=22);
+		fund_account(23);
+		register_keys(23);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(4), 4, 10));
+		assert_ok!(CollatorStaking::set_minimum_self_stake(
+			RuntimeOrigin::signed(RootAccount::get()),
+			60
+		));
+
+		assert_noop!(
+			CollatorStaking::take_candidate_slot(
+				RuntimeOrigin::signed(23),
+				50u64.into(),
+				4,
+				COMMISSION,
+			),
+			Error::<Test>::InsufficientSelfStake,
+		);
+		assert_ok!(CollatorStaking::take_candidate_slot(
+			RuntimeOrigin::signed(23),
+			60u64.into(),
+			4,
+			COMMISSION,
+		));
+		assert_eq!(Stake::<Test>::get(23, 23), StakeInfo { stake: 60, session: 0 });
+	});
+}
+
 #[test]
 fn candidate_list_works() {
 	new_test_ext().execute_with(|| {
@@ -895,7 +1072,7 @@ fn candidate_list_works() {
 		assert_eq!(DesiredCandidates::<Test>::get(), 2);
 		assert_eq!(CandidacyBond::<Test>::get(), 10);
 
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 0);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 0);
 		assert_eq!(Invulnerables::<Test>::get(), vec![1, 2]);
 
 		// take three endowed, non-invulnerables accounts.
@@ -912,11 +1089,14 @@ fn candidate_list_works() {
 		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(4), 4, 25));
 		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 5, 30));
 
-		let candidate_3 = CandidateInfo { who: 3, stake: 30, stakers: 1, deposit: 10 };
-		let candidate_4 = CandidateInfo { who: 4, stake: 25, stakers: 1, deposit: 10 };
-		let candidate_5 = CandidateInfo { who: 5, stake: 50, stakers: 1, deposit: 10 };
+		let candidate_3 =
+			CandidateInfo { who: 3, stake: 30, stakers: 1, deposit: 10, commission: COMMISSION };
+		let candidate_4 =
+			CandidateInfo { who: 4, stake: 25, stakers: 1, deposit: 10, commission: COMMISSION };
+		let candidate_5 =
+			CandidateInfo { who: 5, stake: 50, stakers: 1, deposit: 10, commission: COMMISSION };
 		assert_eq!(
-			CandidateList::<Test>::get().iter().cloned().collect::<Vec<_>>(),
+			CollatorStaking::candidate_list().iter().cloned().collect::<Vec<_>>(),
 			vec![candidate_4, candidate_3, candidate_5]
 		);
 	});
@@ -947,7 +1127,7 @@ fn leave_intent() {
 		assert_eq!(UnstakingRequests::<Test>::get(3), vec![]);
 		assert_ok!(CollatorStaking::leave_intent(RuntimeOrigin::signed(3)));
 
-		let unstake_request = UnstakeRequest { block: 6, amount: 10 };
+		let unstake_request = UnstakeRequest { candidate: 3, block: 6, amount: 10 };
 		assert_eq!(Balances::balance(&3), 90);
 		assert_eq!(Stake::<Test>::get(3, 3), StakeInfo { stake: 0, session: 0 });
 		assert_eq!(UnstakingRequests::<Test>::get(3), vec![unstake_request]);
@@ -971,9 +1151,10 @@ fn fees_edgecases() {
 		Authorship::on_initialize(1);
 
 		// tuple of (id, deposit).
-		let collator = CandidateInfo { who: 4, stake: 0, stakers: 0, deposit: 10 };
+		let collator =
+			CandidateInfo { who: 4, stake: 0, stakers: 0, deposit: 10, commission: COMMISSION };
 
-		assert_eq!(CandidateList::<Test>::get(), vec![collator]);
+		assert_eq!(CollatorStaking::candidate_list(), vec![collator]);
 		assert_eq!(LastAuthoredBlock::<Test>::get(4), 1);
 		// Nothing received
 		assert_eq!(Balances::balance(&4), 90);
@@ -1001,7 +1182,7 @@ fn session_management_single_candidate() {
 		// session won't see this.
 		assert_eq!(SessionHandlerCollators::get(), vec![1, 2]);
 		// but we have a new candidate.
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 1);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 1);
 
 		initialize_to_block(10);
 		assert_eq!(SessionChangeBlock::get(), 10);
@@ -1037,7 +1218,7 @@ fn session_management_max_candidates() {
 		// session won't see this.
 		assert_eq!(SessionHandlerCollators::get(), vec![1, 2]);
 		// but we have a new candidate.
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 3);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 3);
 
 		initialize_to_block(10);
 		assert_eq!(SessionChangeBlock::get(), 10);
@@ -1074,7 +1255,7 @@ fn session_management_increase_bid_with_list_update() {
 		// session won't see this.
 		assert_eq!(SessionHandlerCollators::get(), vec![1, 2]);
 		// but we have a new candidate.
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 3);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 3);
 
 		initialize_to_block(10);
 		assert_eq!(SessionChangeBlock::get(), 10);
@@ -1111,7 +1292,7 @@ fn session_management_candidate_list_eager_sort() {
 		// session won't see this.
 		assert_eq!(SessionHandlerCollators::get(), vec![1, 2]);
 		// but we have a new candidate.
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 3);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 3);
 
 		initialize_to_block(10);
 		assert_eq!(SessionChangeBlock::get(), 10);
@@ -1156,7 +1337,7 @@ fn session_management_reciprocal_outbidding() {
 		// session won't see this.
 		assert_eq!(SessionHandlerCollators::get(), vec![1, 2]);
 		// but we have a new candidate.
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 3);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 3);
 
 		initialize_to_block(10);
 		assert_eq!(SessionChangeBlock::get(), 10);
@@ -1206,7 +1387,7 @@ fn session_management_decrease_bid_after_auction() {
 		// session won't see this.
 		assert_eq!(SessionHandlerCollators::get(), vec![1, 2]);
 		// but we have a new candidate.
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 3);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 3);
 
 		initialize_to_block(10);
 		assert_eq!(SessionChangeBlock::get(), 10);
@@ -1228,19 +1409,25 @@ fn session_management_decrease_bid_after_auction() {
 fn kick_mechanism() {
 	new_test_ext().execute_with(|| {
 		// add a new collator
-		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(3)));
-		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(4)));
+		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(3), COMMISSION, 0));
+		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(4), COMMISSION, 0));
 		initialize_to_block(10);
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 2);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 2);
 		initialize_to_block(20);
 		assert_eq!(SessionChangeBlock::get(), 20);
 		// 4 authored this block, gets to stay 3 was kicked
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 1);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 1);
 		// 3 will be kicked after 1 session delay
 		assert_eq!(SessionHandlerCollators::get(), vec![1, 2, 3, 4]);
 		assert_eq!(
-			CandidateList::<Test>::get().iter().cloned().collect::<Vec<_>>(),
-			vec![CandidateInfo { who: 4, stake: 0, stakers: 0, deposit: 10 }]
+			CollatorStaking::candidate_list().iter().cloned().collect::<Vec<_>>(),
+			vec![CandidateInfo {
+				who: 4,
+				stake: 0,
+				stakers: 0,
+				deposit: 10,
+				commission: COMMISSION
+			}]
 		);
 		assert_eq!(LastAuthoredBlock::<Test>::get(4), 20);
 		initialize_to_block(30);
@@ -1250,7 +1437,7 @@ fn kick_mechanism() {
 		assert_eq!(Balances::balance(&3), 90);
 		assert_eq!(
 			UnstakingRequests::<Test>::get(3),
-			vec![UnstakeRequest { block: 25, amount: 10 }]
+			vec![UnstakeRequest { candidate: 3, block: 25, amount: 10 }]
 		);
 	});
 }
@@ -1260,32 +1447,33 @@ fn should_not_kick_mechanism_too_few() {
 	new_test_ext().execute_with(|| {
 		// remove the invulnerables and add new collators 3 and 5
 
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 0);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 0);
 		assert_eq!(Invulnerables::<Test>::get(), vec![1, 2]);
 		assert_ok!(CollatorStaking::remove_invulnerable(
 			RuntimeOrigin::signed(RootAccount::get()),
 			1
 		));
-		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(3)));
-		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(5)));
+		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(3), COMMISSION, 0));
+		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(5), COMMISSION, 0));
 		assert_ok!(CollatorStaking::remove_invulnerable(
 			RuntimeOrigin::signed(RootAccount::get()),
 			2
 		));
 
 		initialize_to_block(10);
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 2);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 2);
 
 		initialize_to_block(20);
 		assert_eq!(SessionChangeBlock::get(), 20);
 		// 4 authored this block, 3 is kicked, 5 stays because of too few collators
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 1);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 1);
 		// 3 will be kicked after 1 session delay
 		assert_eq!(SessionHandlerCollators::get(), vec![3, 5]);
 		// tuple of (id, deposit).
-		let collator = CandidateInfo { who: 3, stake: 0, stakers: 0, deposit: 10 };
+		let collator =
+			CandidateInfo { who: 3, stake: 0, stakers: 0, deposit: 10, commission: COMMISSION };
 		assert_eq!(
-			CandidateList::<Test>::get().iter().cloned().collect::<Vec<_>>(),
+			CollatorStaking::candidate_list().iter().cloned().collect::<Vec<_>>(),
 			vec![collator]
 		);
 		assert_eq!(LastAuthoredBlock::<Test>::get(4), 20);
@@ -1297,7 +1485,7 @@ fn should_not_kick_mechanism_too_few() {
 		assert_eq!(Balances::balance(&5), 90);
 		assert_eq!(
 			UnstakingRequests::<Test>::get(5),
-			vec![UnstakeRequest { block: 25, amount: 10 }]
+			vec![UnstakeRequest { candidate: 5, block: 25, amount: 10 }]
 		);
 	});
 }
@@ -1307,7 +1495,7 @@ fn should_kick_invulnerables_from_candidates_on_session_change() {
 	new_test_ext().execute_with(|| {
 		initialize_to_block(1);
 
-		assert_eq!(CandidateList::<Test>::get().iter().count(), 0);
+		assert_eq!(CollatorStaking::candidate_list().iter().count(), 0);
 		register_candidates(3..=4);
 		assert_eq!(Balances::balance(&3), 90);
 		assert_eq!(Balances::balance(&4), 90);
@@ -1317,10 +1505,13 @@ fn should_kick_invulnerables_from_candidates_on_session_change() {
 		));
 
 		// tuple of (id, deposit).
-		let collator_3 = CandidateInfo { who: 3, stake: 0, stakers: 0, deposit: 10 };
-		let collator_4 = CandidateInfo { who: 4, stake: 0, stakers: 0, deposit: 10 };
+		let collator_3 =
+			CandidateInfo { who: 3, stake: 0, stakers: 0, deposit: 10, commission: COMMISSION };
+		let collator_4 =
+			CandidateInfo { who: 4, stake: 0, stakers: 0, deposit: 10, commission: COMMISSION };
 
-		let actual_candidates = CandidateList::<Test>::get().iter().cloned().collect::<Vec<_>>();
+		let actual_candidates =
+			CollatorStaking::candidate_list().iter().cloned().collect::<Vec<_>>();
 		assert_eq!(actual_candidates, vec![collator_4.clone(), collator_3]);
 		assert_eq!(Invulnerables::<Test>::get(), vec![1, 2, 3]);
 
@@ -1328,7 +1519,7 @@ fn should_kick_invulnerables_from_candidates_on_session_change() {
 		initialize_to_block(10);
 		// 3 is removed from candidates
 		assert_eq!(
-			CandidateList::<Test>::get().iter().cloned().collect::<Vec<_>>(),
+			CollatorStaking::candidate_list().iter().cloned().collect::<Vec<_>>(),
 			vec![collator_4]
 		);
 		// but not from invulnerables
@@ -1338,6 +1529,172 @@ fn should_kick_invulnerables_from_candidates_on_session_change() {
 	});
 }
 
+#[test]
+fn preview_next_collators() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=5);
+		for (staker, candidate, amount) in [(10, 3, 20), (11, 4, 10), (12, 5, 5)] {
+			fund_account(staker);
+			assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(staker), candidate, amount));
+		}
+
+		// The two most staked candidates are selected.
+		assert_eq!(
+			CollatorStaking::preview_next_collators(),
+			CollatorSetPreview { collators: vec![1, 2, 3, 4], kicked: vec![], stake_cutoff: 10 }
+		);
+
+		// 5 did not produce blocks and 4 became invulnerable.
+		System::set_block_number(20);
+		LastAuthoredBlock::<Test>::insert(5, 0);
+		assert_ok!(CollatorStaking::set_invulnerables(
+			RuntimeOrigin::signed(RootAccount::get()),
+			vec![1, 2, 4]
+		));
+		assert_eq!(
+			CollatorStaking::preview_next_collators(),
+			CollatorSetPreview {
+				collators: vec![1, 2, 4, 3],
+				kicked: vec![(5, KickReason::Lazy), (4, KickReason::Invulnerable)],
+				stake_cutoff: 0
+			}
+		);
+
+		// 3 does not have enough deposit.
+		CandidacyBond::<Test>::put(40);
+		assert_eq!(
+			CollatorStaking::preview_next_collators(),
+			CollatorSetPreview {
+				collators: vec![1, 2, 4],
+				kicked: vec![
+					(5, KickReason::Lazy),
+					(4, KickReason::Invulnerable),
+					(3, KickReason::InsufficientBond)
+				],
+				stake_cutoff: 0
+			}
+		);
+
+		// Nothing changed in storage, and the preview matches the actual selection.
+		assert_eq!(Candidates::<Test>::count(), 3);
+		let preview = CollatorStaking::preview_next_collators();
+		assert_eq!(CollatorStaking::new_session(3), Some(vec![1, 2, 4]));
+
+		// Kicked out candidates are flagged, and still reported until they are removed.
+		assert_eq!(StaleCandidates::<Test>::count(), 3);
+		assert_eq!(CollatorStaking::preview_next_collators(), preview);
+		CollatorStaking::kick_stale_candidates(Weight::MAX);
+		assert_eq!(Candidates::<Test>::count(), 0);
+	});
+}
+
+#[test]
+fn stale_candidates_are_unflagged_if_removal_fails() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=4);
+		StaleCandidates::<Test>::insert(3, KickReason::Lazy);
+		StaleCandidates::<Test>::insert(4, KickReason::Lazy);
+		// The candidacy bond of 3 cannot be returned through an unstaking request.
+		let requests = (0..<Test as Config>::MaxStakedCandidates::get())
+			.map(|_| UnstakeRequest { candidate: 4, block: 100, amount: 0 })
+			.collect::<Vec<_>>();
+		UnstakingRequests::<Test>::insert(3, BoundedVec::truncate_from(requests));
+
+		// Only one candidate is kicked per block, whichever comes first.
+		let kick_weight = <Test as Config>::WeightInfo::kick_stale_candidate();
+		CollatorStaking::kick_stale_candidates(kick_weight);
+		CollatorStaking::kick_stale_candidates(kick_weight);
+
+		// The candidate that cannot be removed does not prevent the other one from being kicked.
+		assert!(!Candidates::<Test>::contains_key(4));
+		assert!(Candidates::<Test>::contains_key(3));
+		assert_eq!(StaleCandidates::<Test>::count(), 0);
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::CandidateKickFailed {
+			account_id: 3,
+			error: Error::<Test>::TooManyUnstakingRequests.into(),
+		}));
+	});
+}
+
+#[test]
+fn kick_candidates_with_insufficient_self_stake() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=5);
+		assert_ok!(CollatorStaking::set_minimum_self_stake(
+			RuntimeOrigin::signed(RootAccount::get()),
+			5
+		));
+
+		// Stake from other accounts does not count as self-stake.
+		fund_account(10);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(10), 3, 20));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(4), 4, 5));
+
+		assert_eq!(
+			CollatorStaking::preview_next_collators(),
+			CollatorSetPreview {
+				collators: vec![1, 2, 4],
+				kicked: vec![
+					(5, KickReason::InsufficientSelfStake),
+					(3, KickReason::InsufficientSelfStake)
+				],
+				stake_cutoff: 0
+			}
+		);
+		assert_eq!(CollatorStaking::new_session(3), Some(vec![1, 2, 4]));
+		CollatorStaking::kick_stale_candidates(Weight::MAX);
+		assert_eq!(
+			CollatorStaking::candidate_list().iter().map(|c| c.who).collect::<Vec<_>>(),
+			vec![4]
+		);
+	});
+}
+
+#[test]
+fn stale_candidates_are_kicked_over_multiple_blocks() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=5);
+		// Only one candidate can be kicked out per block.
+		MaxKickWeight::set(<Test as Config>::WeightInfo::kick_stale_candidate());
+
+		// 4 authored all blocks, while 3 and 5 are flagged when the session ends.
+		initialize_to_block(29);
+		assert_eq!(StaleCandidates::<Test>::count(), 0);
+		assert_eq!(CollatorStaking::new_session(3), Some(vec![1, 2, 4]));
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::CandidateFlaggedAsStale {
+			account_id: 3,
+			reason: KickReason::Lazy,
+		}));
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::CandidateFlaggedAsStale {
+			account_id: 5,
+			reason: KickReason::Lazy,
+		}));
+		assert_eq!(StaleCandidates::<Test>::count(), 2);
+		assert_eq!(Candidates::<Test>::count(), 3);
+		// Flagged candidates are no longer eligible, nor flagged again.
+		assert_eq!(CollatorStaking::eligible_collators(), 3);
+		assert_eq!(CollatorStaking::flag_stale_candidates(), 0);
+
+		// They are removed in the following blocks.
+		initialize_to_block(30);
+		assert_eq!(StaleCandidates::<Test>::count(), 1);
+		assert_eq!(Candidates::<Test>::count(), 2);
+		assert_eq!(CollatorStaking::assemble_collators(), vec![1, 2, 4]);
+		initialize_to_block(31);
+		assert_eq!(StaleCandidates::<Test>::count(), 0);
+		assert_eq!(
+			CollatorStaking::candidate_list().iter().map(|c| c.who).collect::<Vec<_>>(),
+			vec![4]
+		);
+		assert_eq!(UnstakingRequests::<Test>::get(3).len(), 1);
+		assert_eq!(UnstakingRequests::<Test>::get(5).len(), 1);
+	});
+}
+
 #[test]
 #[should_panic = "duplicate invulnerables in genesis."]
 fn cannot_set_genesis_value_twice() {
@@ -1350,7 +1707,7 @@ fn cannot_set_genesis_value_twice() {
 		candidacy_bond: 10,
 		min_stake: 1,
 		invulnerables,
-		collator_reward_percentage: Percent::from_parts(20),
+		max_commission: Percent::from_parts(20),
 		extra_reward: 0,
 	};
 	// collator selection must be initialized before session.
@@ -1368,7 +1725,7 @@ fn cannot_set_invalid_min_stake_in_genesis() {
 		candidacy_bond: 10,
 		min_stake: 15,
 		invulnerables: vec![1, 2],
-		collator_reward_percentage: Percent::from_parts(20),
+		max_commission: Percent::from_parts(20),
 		extra_reward: 0,
 	};
 	// collator selection must be initialized before session.
@@ -1386,7 +1743,7 @@ fn cannot_set_invalid_max_candidates_in_genesis() {
 		candidacy_bond: 10,
 		min_stake: 2,
 		invulnerables: vec![1, 2],
-		collator_reward_percentage: Percent::from_parts(20),
+		max_commission: Percent::from_parts(20),
 		extra_reward: 0,
 	};
 	// collator selection must be initialized before session.
@@ -1406,7 +1763,7 @@ fn cannot_set_too_many_invulnerables_at_genesis() {
 		invulnerables: vec![
 			1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
 		],
-		collator_reward_percentage: Percent::from_parts(20),
+		max_commission: Percent::from_parts(20),
 		extra_reward: 0,
 	};
 	// collator selection must be initialized before session.
@@ -1458,10 +1815,10 @@ fn stake() {
 		register_candidates(3..=3);
 		assert_eq!(Balances::balance(&3), 90);
 		assert_eq!(Stake::<Test>::get(3, 3), StakeInfo { stake: 0, session: 0 });
-		assert_eq!(StakeCount::<Test>::get(3), 0);
-		assert_eq!(CandidateList::<Test>::get()[0].stake, 0);
+		assert_eq!(StakedCandidates::<Test>::get(3).len(), 0);
+		assert_eq!(CollatorStaking::candidate_list()[0].stake, 0);
 
-		assert_eq!(StakeCount::<Test>::get(4), 0);
+		assert_eq!(StakedCandidates::<Test>::get(4).len(), 0);
 		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(4), 3, 20));
 		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakeAdded {
 			staker: 4,
@@ -1471,8 +1828,8 @@ fn stake() {
 		assert_eq!(Balances::balance(&4), 80);
 		assert_eq!(Stake::<Test>::get(3, 4), StakeInfo { stake: 20, session: 0 });
 		assert_eq!(Stake::<Test>::get(3, 3), StakeInfo { stake: 0, session: 0 });
-		assert_eq!(CandidateList::<Test>::get()[0].stake, 20);
-		assert_eq!(StakeCount::<Test>::get(4), 1);
+		assert_eq!(CollatorStaking::candidate_list()[0].stake, 20);
+		assert_eq!(StakedCandidates::<Test>::get(4).len(), 1);
 	});
 }
 
@@ -1483,55 +1840,121 @@ fn stake_and_reassign_position() {
 
 		register_candidates(3..=4);
 		assert_eq!(
-			CandidateList::<Test>::get(),
+			CollatorStaking::candidate_list(),
 			vec![
-				CandidateInfo { who: 4, stake: 0, stakers: 0, deposit: 10 },
-				CandidateInfo { who: 3, stake: 0, stakers: 0, deposit: 10 },
+				CandidateInfo { who: 4, stake: 0, stakers: 0, deposit: 10, commission: COMMISSION },
+				CandidateInfo { who: 3, stake: 0, stakers: 0, deposit: 10, commission: COMMISSION },
 			]
 		);
 		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 12));
 		assert_eq!(
-			CandidateList::<Test>::get(),
+			CollatorStaking::candidate_list(),
 			vec![
-				CandidateInfo { who: 4, stake: 0, stakers: 0, deposit: 10 },
-				CandidateInfo { who: 3, stake: 12, stakers: 1, deposit: 10 },
+				CandidateInfo { who: 4, stake: 0, stakers: 0, deposit: 10, commission: COMMISSION },
+				CandidateInfo {
+					who: 3,
+					stake: 12,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
 			]
 		);
 
 		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 4, 15));
 		assert_eq!(
-			CandidateList::<Test>::get(),
+			CollatorStaking::candidate_list(),
 			vec![
-				CandidateInfo { who: 3, stake: 12, stakers: 1, deposit: 10 },
-				CandidateInfo { who: 4, stake: 15, stakers: 1, deposit: 10 },
+				CandidateInfo {
+					who: 3,
+					stake: 12,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
+				CandidateInfo {
+					who: 4,
+					stake: 15,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
 			]
 		);
 
 		register_candidates(5..=5);
 		assert_eq!(
-			CandidateList::<Test>::get(),
+			CollatorStaking::candidate_list(),
 			vec![
-				CandidateInfo { who: 5, stake: 0, stakers: 0, deposit: 10 },
-				CandidateInfo { who: 3, stake: 12, stakers: 1, deposit: 10 },
-				CandidateInfo { who: 4, stake: 15, stakers: 1, deposit: 10 },
+				CandidateInfo { who: 5, stake: 0, stakers: 0, deposit: 10, commission: COMMISSION },
+				CandidateInfo {
+					who: 3,
+					stake: 12,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
+				CandidateInfo {
+					who: 4,
+					stake: 15,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
 			]
 		);
 		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 5, 13));
 		assert_eq!(
-			CandidateList::<Test>::get(),
+			CollatorStaking::candidate_list(),
 			vec![
-				CandidateInfo { who: 3, stake: 12, stakers: 1, deposit: 10 },
-				CandidateInfo { who: 5, stake: 13, stakers: 1, deposit: 10 },
-				CandidateInfo { who: 4, stake: 15, stakers: 1, deposit: 10 },
+				CandidateInfo {
+					who: 3,
+					stake: 12,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
+				CandidateInfo {
+					who: 5,
+					stake: 13,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
+				CandidateInfo {
+					who: 4,
+					stake: 15,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
 			]
 		);
 		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 5, 7));
 		assert_eq!(
-			CandidateList::<Test>::get(),
+			CollatorStaking::candidate_list(),
 			vec![
-				CandidateInfo { who: 3, stake: 12, stakers: 1, deposit: 10 },
-				CandidateInfo { who: 4, stake: 15, stakers: 1, deposit: 10 },
-				CandidateInfo { who: 5, stake: 20, stakers: 1, deposit: 10 },
+				CandidateInfo {
+					who: 3,
+					stake: 12,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
+				CandidateInfo {
+					who: 4,
+					stake: 15,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
+				CandidateInfo {
+					who: 5,
+					stake: 20,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
 			]
 		);
 	});
@@ -1548,7 +1971,7 @@ fn cannot_stake_too_many_staked_candidates() {
 		for i in 3..=18 {
 			assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(1), i, 2));
 		}
-		assert_eq!(StakeCount::<Test>::get(1), 16);
+		assert_eq!(StakedCandidates::<Test>::get(1).len(), 16);
 		assert_noop!(
 			CollatorStaking::stake(RuntimeOrigin::signed(1), 19, 2),
 			Error::<Test>::TooManyStakedCandidates
@@ -1556,6 +1979,24 @@ fn cannot_stake_too_many_staked_candidates() {
 	});
 }
 
+#[test]
+fn top_up_stake_with_max_staked_candidates() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		register_candidates(3..=18);
+		for i in 3..=18 {
+			assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(1), i, 2));
+		}
+		assert_eq!(StakedCandidates::<Test>::get(1).len(), 16);
+
+		// The limit only applies to new candidates.
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(1), 3, 2));
+		assert_eq!(Stake::<Test>::get(3, 1).stake, 4);
+		assert_eq!(StakedCandidates::<Test>::get(1).len(), 16);
+	});
+}
+
 #[test]
 fn cannot_stake_too_many_stakers() {
 	new_test_ext().execute_with(|| {
@@ -1568,7 +2009,7 @@ fn cannot_stake_too_many_stakers() {
 			fund_account(i);
 			assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(i), 3, 2));
 		}
-		assert_eq!(CandidateList::<Test>::get()[0].stakers, 25);
+		assert_eq!(CollatorStaking::candidate_list()[0].stakers, 25);
 		fund_account(29);
 		assert_noop!(
 			CollatorStaking::stake(RuntimeOrigin::signed(29), 3, 2),
@@ -1593,20 +2034,32 @@ fn unstake_from_candidate() {
 		initialize_to_block(1);
 
 		register_candidates(3..=4);
-		assert_eq!(StakeCount::<Test>::get(5), 0);
+		assert_eq!(StakedCandidates::<Test>::get(5).len(), 0);
 		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
 		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 4, 10));
 		assert_eq!(
-			CandidateList::<Test>::get(),
+			CollatorStaking::candidate_list(),
 			vec![
-				CandidateInfo { who: 4, stake: 10, stakers: 1, deposit: 10 },
-				CandidateInfo { who: 3, stake: 20, stakers: 1, deposit: 10 },
+				CandidateInfo {
+					who: 4,
+					stake: 10,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
+				CandidateInfo {
+					who: 3,
+					stake: 20,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
 			]
 		);
 
 		// unstake from actual candidate
 		assert_eq!(Balances::balance(&5), 70);
-		assert_eq!(StakeCount::<Test>::get(5), 2);
+		assert_eq!(StakedCandidates::<Test>::get(5).len(), 2);
 		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(5), 3));
 		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::StakeRemoved {
 			staker: 5,
@@ -1621,19 +2074,25 @@ fn unstake_from_candidate() {
 		}));
 		// candidate list gets reordered
 		assert_eq!(
-			CandidateList::<Test>::get(),
+			CollatorStaking::candidate_list(),
 			vec![
-				CandidateInfo { who: 3, stake: 0, stakers: 0, deposit: 10 },
-				CandidateInfo { who: 4, stake: 10, stakers: 1, deposit: 10 },
+				CandidateInfo { who: 3, stake: 0, stakers: 0, deposit: 10, commission: COMMISSION },
+				CandidateInfo {
+					who: 4,
+					stake: 10,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
 			]
 		);
-		assert_eq!(StakeCount::<Test>::get(5), 1);
+		assert_eq!(StakedCandidates::<Test>::get(5).len(), 1);
 		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 0, session: 0 });
 		assert_eq!(Stake::<Test>::get(4, 5), StakeInfo { stake: 10, session: 0 });
 		assert_eq!(Balances::balance(&5), 70);
 		assert_eq!(
 			UnstakingRequests::<Test>::get(5),
-			vec![UnstakeRequest { block: 3, amount: 20 }]
+			vec![UnstakeRequest { candidate: 3, block: 3, amount: 20 }]
 		);
 	});
 }
@@ -1643,7 +2102,7 @@ fn unstake_self() {
 	new_test_ext().execute_with(|| {
 		initialize_to_block(1);
 
-		assert_eq!(StakeCount::<Test>::get(3), 0);
+		assert_eq!(StakedCandidates::<Test>::get(3).len(), 0);
 		assert_eq!(Balances::balance(&3), 100);
 		assert_eq!(CandidacyBond::<Test>::get(), 10);
 		register_candidates(3..=4);
@@ -1652,17 +2111,29 @@ fn unstake_self() {
 		assert_eq!(Balances::balance(&3), 70);
 		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(3), 4, 10));
 		assert_eq!(Balances::balance(&3), 60);
-		assert_eq!(StakeCount::<Test>::get(3), 2);
+		assert_eq!(StakedCandidates::<Test>::get(3).len(), 2);
 		assert_eq!(
-			CandidateList::<Test>::get(),
+			CollatorStaking::candidate_list(),
 			vec![
-				CandidateInfo { who: 4, stake: 10, stakers: 1, deposit: 10 },
-				CandidateInfo { who: 3, stake: 20, stakers: 1, deposit: 10 },
+				CandidateInfo {
+					who: 4,
+					stake: 10,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
+				CandidateInfo {
+					who: 3,
+					stake: 20,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
 			]
 		);
 
 		// unstake from actual candidate
-		assert_eq!(StakeCount::<Test>::get(3), 2);
+		assert_eq!(StakedCandidates::<Test>::get(3).len(), 2);
 		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(3), 3));
 		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::StakeRemoved {
 			staker: 3,
@@ -1676,26 +2147,35 @@ fn unstake_self() {
 			block: 6, // higher delay
 		}));
 		assert_eq!(
-			CandidateList::<Test>::get(),
+			CollatorStaking::candidate_list(),
 			vec![
-				CandidateInfo { who: 3, stake: 0, stakers: 0, deposit: 10 },
-				CandidateInfo { who: 4, stake: 10, stakers: 1, deposit: 10 }
+				CandidateInfo { who: 3, stake: 0, stakers: 0, deposit: 10, commission: COMMISSION },
+				CandidateInfo {
+					who: 4,
+					stake: 10,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				}
 			]
 		);
-		assert_eq!(StakeCount::<Test>::get(3), 1);
+		assert_eq!(StakedCandidates::<Test>::get(3).len(), 1);
 		assert_eq!(Stake::<Test>::get(3, 3), StakeInfo { stake: 0, session: 0 });
 		assert_eq!(Stake::<Test>::get(4, 3), StakeInfo { stake: 10, session: 0 });
 		assert_eq!(Balances::balance(&3), 60);
 		assert_eq!(
 			UnstakingRequests::<Test>::get(3),
-			vec![UnstakeRequest { block: 6, amount: 20 }]
+			vec![UnstakeRequest { candidate: 3, block: 6, amount: 20 }]
 		);
 
 		// check after unstaking with a shorter delay the list remains sorted by block
 		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(3), 4));
 		assert_eq!(
 			UnstakingRequests::<Test>::get(3),
-			vec![UnstakeRequest { block: 3, amount: 10 }, UnstakeRequest { block: 6, amount: 20 }]
+			vec![
+				UnstakeRequest { candidate: 4, block: 3, amount: 10 },
+				UnstakeRequest { candidate: 3, block: 6, amount: 20 }
+			]
 		);
 	});
 }
@@ -1706,28 +2186,46 @@ fn unstake_from_ex_candidate() {
 		initialize_to_block(1);
 
 		register_candidates(3..=4);
-		assert_eq!(StakeCount::<Test>::get(5), 0);
+		assert_eq!(StakedCandidates::<Test>::get(5).len(), 0);
 		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
 		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 4, 10));
 		assert_eq!(
-			CandidateList::<Test>::get(),
+			CollatorStaking::candidate_list(),
 			vec![
-				CandidateInfo { who: 4, stake: 10, stakers: 1, deposit: 10 },
-				CandidateInfo { who: 3, stake: 20, stakers: 1, deposit: 10 },
+				CandidateInfo {
+					who: 4,
+					stake: 10,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
+				CandidateInfo {
+					who: 3,
+					stake: 20,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
 			]
 		);
 		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 20, session: 0 });
 		assert_eq!(Stake::<Test>::get(4, 5), StakeInfo { stake: 10, session: 0 });
 
 		// unstake from ex-candidate
-		assert_eq!(StakeCount::<Test>::get(5), 2);
+		assert_eq!(StakedCandidates::<Test>::get(5).len(), 2);
 		assert_ok!(CollatorStaking::leave_intent(RuntimeOrigin::signed(3)));
 		assert_eq!(
-			CandidateList::<Test>::get(),
-			vec![CandidateInfo { who: 4, stake: 10, stakers: 1, deposit: 10 }]
+			CollatorStaking::candidate_list(),
+			vec![CandidateInfo {
+				who: 4,
+				stake: 10,
+				stakers: 1,
+				deposit: 10,
+				commission: COMMISSION
+			}]
 		);
 
-		assert_eq!(StakeCount::<Test>::get(5), 2);
+		assert_eq!(StakedCandidates::<Test>::get(5).len(), 2);
 		assert_eq!(Balances::balance(&5), 70);
 		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(5), 3));
 		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::StakeRemoved {
@@ -1738,7 +2236,7 @@ fn unstake_from_ex_candidate() {
 		assert_eq!(UnstakingRequests::<Test>::get(5), vec![]);
 		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 0, session: 0 });
 		assert_eq!(Stake::<Test>::get(4, 5), StakeInfo { stake: 10, session: 0 });
-		assert_eq!(StakeCount::<Test>::get(5), 1);
+		assert_eq!(StakedCandidates::<Test>::get(5).len(), 1);
 		assert_eq!(Balances::balance(&5), 90);
 	});
 }
@@ -1771,31 +2269,181 @@ fn unstake_fails_if_over_limit() {
 	});
 }
 
+#[test]
+fn unstake_amount() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 4, 10));
+		assert_eq!(
+			CollatorStaking::candidate_list(),
+			vec![
+				CandidateInfo {
+					who: 4,
+					stake: 10,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
+				CandidateInfo {
+					who: 3,
+					stake: 20,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
+			]
+		);
+		assert_eq!(StakedCandidates::<Test>::get(5).len(), 2);
+		assert_eq!(Balances::balance(&5), 70);
+
+		// remove part of the stake from an actual candidate
+		assert_ok!(CollatorStaking::unstake_amount(RuntimeOrigin::signed(5), 3, 12));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::StakeRemoved {
+			staker: 5,
+			candidate: 3,
+			amount: 12,
+		}));
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::UnstakeRequestCreated {
+			staker: 5,
+			candidate: 3,
+			amount: 12,
+			block: 3,
+		}));
+		// candidate list gets reordered, but the staker still backs the candidate
+		assert_eq!(
+			CollatorStaking::candidate_list(),
+			vec![
+				CandidateInfo { who: 3, stake: 8, stakers: 1, deposit: 10, commission: COMMISSION },
+				CandidateInfo {
+					who: 4,
+					stake: 10,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
+			]
+		);
+		assert_eq!(StakedCandidates::<Test>::get(5).len(), 2);
+		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 8, session: 0 });
+		assert_eq!(Balances::balance(&5), 70);
+		assert_eq!(
+			UnstakingRequests::<Test>::get(5),
+			vec![UnstakeRequest { candidate: 3, block: 3, amount: 12 }]
+		);
+
+		// removing the remaining stake is a full unstake
+		assert_ok!(CollatorStaking::unstake_amount(RuntimeOrigin::signed(5), 3, 8));
+		assert_eq!(
+			CollatorStaking::candidate_list(),
+			vec![
+				CandidateInfo { who: 3, stake: 0, stakers: 0, deposit: 10, commission: COMMISSION },
+				CandidateInfo {
+					who: 4,
+					stake: 10,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
+			]
+		);
+		assert_eq!(StakedCandidates::<Test>::get(5).len(), 1);
+		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 0, session: 0 });
+		assert_eq!(
+			UnstakingRequests::<Test>::get(5),
+			vec![
+				UnstakeRequest { candidate: 3, block: 3, amount: 12 },
+				UnstakeRequest { candidate: 3, block: 3, amount: 8 }
+			]
+		);
+
+		// funds are immediately returned for ex-candidates
+		assert_ok!(CollatorStaking::leave_intent(RuntimeOrigin::signed(4)));
+		assert_ok!(CollatorStaking::unstake_amount(RuntimeOrigin::signed(5), 4, 4));
+		assert_eq!(Stake::<Test>::get(4, 5), StakeInfo { stake: 6, session: 0 });
+		assert_eq!(StakedCandidates::<Test>::get(5).len(), 1);
+		assert_eq!(Balances::balance(&5), 74);
+		assert_eq!(UnstakingRequests::<Test>::get(5).len(), 2);
+	});
+}
+
+#[test]
+fn cannot_unstake_invalid_amount() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		register_candidates(3..=3);
+		assert_noop!(
+			CollatorStaking::unstake_amount(RuntimeOrigin::signed(5), 3, 2),
+			Error::<Test>::NothingToUnstake
+		);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
+
+		assert_noop!(
+			CollatorStaking::unstake_amount(RuntimeOrigin::signed(5), 3, 0),
+			Error::<Test>::InvalidUnstakeAmount
+		);
+		assert_noop!(
+			CollatorStaking::unstake_amount(RuntimeOrigin::signed(5), 3, 21),
+			Error::<Test>::InvalidUnstakeAmount
+		);
+
+		// the remaining stake cannot be lower than MinStake
+		assert_eq!(MinStake::<Test>::get(), 2);
+		assert_noop!(
+			CollatorStaking::unstake_amount(RuntimeOrigin::signed(5), 3, 19),
+			Error::<Test>::InsufficientStake
+		);
+		assert_ok!(CollatorStaking::unstake_amount(RuntimeOrigin::signed(5), 3, 18));
+		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 2, session: 0 });
+	});
+}
+
 #[test]
 fn unstake_all() {
 	new_test_ext().execute_with(|| {
 		initialize_to_block(1);
 
 		register_candidates(3..=4);
-		assert_eq!(StakeCount::<Test>::get(5), 0);
+		assert_eq!(StakedCandidates::<Test>::get(5).len(), 0);
 		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
 		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 4, 10));
 		assert_eq!(
-			CandidateList::<Test>::get(),
+			CollatorStaking::candidate_list(),
 			vec![
-				CandidateInfo { who: 4, stake: 10, stakers: 1, deposit: 10 },
-				CandidateInfo { who: 3, stake: 20, stakers: 1, deposit: 10 },
+				CandidateInfo {
+					who: 4,
+					stake: 10,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
+				CandidateInfo {
+					who: 3,
+					stake: 20,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
 			]
 		);
 
-		assert_eq!(StakeCount::<Test>::get(5), 2);
+		assert_eq!(StakedCandidates::<Test>::get(5), vec![3, 4]);
 		assert_ok!(CollatorStaking::leave_intent(RuntimeOrigin::signed(3)));
 		assert_eq!(
-			CandidateList::<Test>::get(),
-			vec![CandidateInfo { who: 4, stake: 10, stakers: 1, deposit: 10 }]
+			CollatorStaking::candidate_list(),
+			vec![CandidateInfo {
+				who: 4,
+				stake: 10,
+				stakers: 1,
+				deposit: 10,
+				commission: COMMISSION
+			}]
 		);
 
-		assert_eq!(StakeCount::<Test>::get(5), 2);
+		assert_eq!(StakedCandidates::<Test>::get(5), vec![3, 4]);
 		assert_eq!(Balances::balance(&5), 70);
 		assert_ok!(CollatorStaking::unstake_all(RuntimeOrigin::signed(5)));
 		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakeRemoved {
@@ -1816,15 +2464,21 @@ fn unstake_all() {
 		}));
 		assert_eq!(
 			UnstakingRequests::<Test>::get(5),
-			vec![UnstakeRequest { block: 3, amount: 10 }]
+			vec![UnstakeRequest { candidate: 4, block: 3, amount: 10 }]
 		);
 		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 0, session: 0 });
 		assert_eq!(Stake::<Test>::get(4, 5), StakeInfo { stake: 0, session: 0 });
-		assert_eq!(StakeCount::<Test>::get(5), 0);
+		assert_eq!(StakedCandidates::<Test>::get(5).len(), 0);
 		assert_eq!(Balances::balance(&5), 90);
 		assert_eq!(
-			CandidateList::<Test>::get(),
-			vec![CandidateInfo { who: 4, stake: 0, stakers: 0, deposit: 10 }]
+			CollatorStaking::candidate_list(),
+			vec![CandidateInfo {
+				who: 4,
+				stake: 0,
+				stakers: 0,
+				deposit: 10,
+				commission: COMMISSION
+			}]
 		);
 	});
 }
@@ -1851,12 +2505,18 @@ fn claim() {
 		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
 		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 20, session: 0 });
 		assert_eq!(
-			CandidateList::<Test>::get(),
-			vec![CandidateInfo { who: 3, stake: 20, stakers: 1, deposit: 10 }]
+			CollatorStaking::candidate_list(),
+			vec![CandidateInfo {
+				who: 3,
+				stake: 20,
+				stakers: 1,
+				deposit: 10,
+				commission: COMMISSION
+			}]
 		);
 
 		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(5), 3));
-		assert_eq!(StakeCount::<Test>::get(5), 0);
+		assert_eq!(StakedCandidates::<Test>::get(5).len(), 0);
 		assert_eq!(Balances::balance(&5), 80);
 		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::StakeRemoved {
 			staker: 5,
@@ -1864,18 +2524,24 @@ fn claim() {
 			amount: 20,
 		}));
 		assert_eq!(
-			CandidateList::<Test>::get(),
-			vec![CandidateInfo { who: 3, stake: 0, stakers: 0, deposit: 10 }]
+			CollatorStaking::candidate_list(),
+			vec![CandidateInfo {
+				who: 3,
+				stake: 0,
+				stakers: 0,
+				deposit: 10,
+				commission: COMMISSION
+			}]
 		);
 		// No changes until delay passes
 		assert_eq!(
 			UnstakingRequests::<Test>::get(5),
-			vec![UnstakeRequest { block: 3, amount: 20 }]
+			vec![UnstakeRequest { candidate: 3, block: 3, amount: 20 }]
 		);
 		assert_ok!(CollatorStaking::claim(RuntimeOrigin::signed(5)));
 		assert_eq!(
 			UnstakingRequests::<Test>::get(5),
-			vec![UnstakeRequest { block: 3, amount: 20 }]
+			vec![UnstakeRequest { candidate: 3, block: 3, amount: 20 }]
 		);
 
 		initialize_to_block(3);
@@ -1889,78 +2555,2164 @@ fn claim() {
 }
 
 #[test]
-fn set_autocompound_percentage() {
+fn staker_info() {
 	new_test_ext().execute_with(|| {
 		initialize_to_block(1);
-
-		assert_eq!(AutoCompound::<Test>::get(5), Percent::from_parts(0));
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 10));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 4, 20));
 		assert_ok!(CollatorStaking::set_autocompound_percentage(
 			RuntimeOrigin::signed(5),
 			Percent::from_parts(50)
 		));
-		assert_eq!(AutoCompound::<Test>::get(5), Percent::from_parts(50));
-		System::assert_last_event(RuntimeEvent::CollatorStaking(
-			Event::AutoCompoundPercentageSet { staker: 5, percentage: Percent::from_parts(50) },
-		));
+		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(5), 3));
+
+		let info = CollatorStaking::staker_info(&5);
+		assert_eq!(info.stakes, vec![(4, StakeInfo { stake: 20, session: 0 })]);
+		assert_eq!(
+			info.unstaking_requests,
+			vec![UnstakeRequest { candidate: 3, block: 3, amount: 10 }]
+		);
+		assert_eq!(info.claimable, 0);
+		assert_eq!(info.autocompound, Percent::from_parts(50));
+		assert_eq!(info.total_held, 30);
+
+		// The unstaking request can be claimed once the delay passes.
+		initialize_to_block(3);
+		assert_eq!(CollatorStaking::staker_info(&5).claimable, 10);
+
+		// Candidacy bonds are held too.
+		let info = CollatorStaking::staker_info(&3);
+		assert_eq!(info.stakes, vec![]);
+		assert_eq!(info.unstaking_requests, vec![]);
+		assert_eq!(info.autocompound, Percent::from_parts(0));
+		assert_eq!(info.total_held, 10);
 	});
 }
 
 #[test]
-fn set_collator_reward_percentage() {
+fn stake_with_list_hint() {
 	new_test_ext().execute_with(|| {
 		initialize_to_block(1);
 
-		assert_eq!(CollatorRewardPercentage::<Test>::get(), Percent::from_parts(20));
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 10));
+		assert_eq!(CandidateListHead::<Test>::get(), Some(4));
+		assert_eq!(CandidateListTail::<Test>::get(), Some(3));
 
-		// Invalid origin
-		assert_noop!(
-			CollatorStaking::set_collator_reward_percentage(
-				RuntimeOrigin::signed(5),
-				Percent::from_parts(50)
-			),
-			BadOrigin
+		// A valid hint places the candidate right before it without searching the list.
+		let info =
+			CollatorStaking::stake_with_hint(RuntimeOrigin::signed(5), 4, 5, Some(3)).unwrap();
+		assert_eq!(
+			info.actual_weight,
+			Some(
+				<Test as Config>::WeightInfo::stake(0)
+					.saturating_add(CollatorStaking::settle_rewards_weight())
+			)
 		);
-		assert_ok!(CollatorStaking::set_collator_reward_percentage(
-			RuntimeOrigin::signed(RootAccount::get()),
-			Percent::from_parts(50)
-		));
-		System::assert_last_event(RuntimeEvent::CollatorStaking(
-			Event::CollatorRewardPercentageSet { percentage: Percent::from_parts(50) },
-		));
-		assert_eq!(CollatorRewardPercentage::<Test>::get(), Percent::from_parts(50));
+		assert_eq!(CandidateListHead::<Test>::get(), Some(4));
+		assert_eq!(CandidateListTail::<Test>::get(), Some(3));
+
+		// An invalid hint falls back to searching the list.
+		let info =
+			CollatorStaking::stake_with_hint(RuntimeOrigin::signed(5), 4, 10, Some(3)).unwrap();
+		assert_eq!(
+			info.actual_weight,
+			Some(
+				<Test as Config>::WeightInfo::stake(1)
+					.saturating_add(CollatorStaking::settle_rewards_weight())
+			)
+		);
+		assert_eq!(CandidateListHead::<Test>::get(), Some(3));
+		assert_eq!(CandidateListTail::<Test>::get(), Some(4));
+
+		// Hints pointing to accounts outside the list are ignored too.
+		assert_ok!(CollatorStaking::unstake_from_with_hint(RuntimeOrigin::signed(5), 4, Some(5)));
+		assert_eq!(CandidateListHead::<Test>::get(), Some(4));
+		assert_eq!(CandidateListTail::<Test>::get(), Some(3));
 	});
 }
 
 #[test]
-fn set_extra_reward() {
+fn rebond() {
 	new_test_ext().execute_with(|| {
 		initialize_to_block(1);
 
-		assert_eq!(ExtraReward::<Test>::get(), 0);
-
-		// Invalid origin
-		assert_noop!(CollatorStaking::set_extra_reward(RuntimeOrigin::signed(5), 10), BadOrigin);
-
-		// Set the reward
-		assert_ok!(CollatorStaking::set_extra_reward(
-			RuntimeOrigin::signed(RootAccount::get()),
-			10
-		));
-		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::ExtraRewardSet {
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(3), 3, 20));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(3), 4, 10));
+		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(3), 3));
+		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(3), 4));
+		assert_eq!(
+			UnstakingRequests::<Test>::get(3),
+			vec![
+				UnstakeRequest { candidate: 4, block: 3, amount: 10 },
+				UnstakeRequest { candidate: 3, block: 6, amount: 20 }
+			]
+		);
+		assert_eq!(StakedCandidates::<Test>::get(3).len(), 0);
+		assert_eq!(Balances::balance(&3), 60);
+
+		// only requests towards the candidate being rebonded are taken
+		assert_noop!(
+			CollatorStaking::rebond(RuntimeOrigin::signed(3), 4, 15),
+			Error::<Test>::InvalidRebondAmount
+		);
+		assert_ok!(CollatorStaking::rebond(RuntimeOrigin::signed(3), 3, 15));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::StakeRebonded {
+			staker: 3,
+			candidate: 3,
+			amount: 15,
+		}));
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakeAdded {
+			staker: 3,
+			candidate: 3,
+			amount: 15,
+		}));
+		assert_eq!(
+			UnstakingRequests::<Test>::get(3),
+			vec![
+				UnstakeRequest { candidate: 4, block: 3, amount: 10 },
+				UnstakeRequest { candidate: 3, block: 6, amount: 5 }
+			]
+		);
+		assert_eq!(Stake::<Test>::get(3, 3), StakeInfo { stake: 15, session: 0 });
+		assert_eq!(StakedCandidates::<Test>::get(3).len(), 1);
+		assert_eq!(
+			CollatorStaking::candidate_list(),
+			vec![
+				CandidateInfo { who: 4, stake: 0, stakers: 0, deposit: 10, commission: COMMISSION },
+				CandidateInfo {
+					who: 3,
+					stake: 15,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
+			]
+		);
+		// funds remain on hold
+		assert_eq!(Balances::balance(&3), 60);
+
+		assert_ok!(CollatorStaking::rebond(RuntimeOrigin::signed(3), 4, 7));
+		assert_eq!(
+			UnstakingRequests::<Test>::get(3),
+			vec![
+				UnstakeRequest { candidate: 4, block: 3, amount: 3 },
+				UnstakeRequest { candidate: 3, block: 6, amount: 5 }
+			]
+		);
+		assert_eq!(Stake::<Test>::get(4, 3), StakeInfo { stake: 7, session: 0 });
+		assert_eq!(StakedCandidates::<Test>::get(3).len(), 2);
+		assert_eq!(Balances::balance(&3), 60);
+
+		// claimable requests cannot be rebonded
+		initialize_to_block(3);
+		assert_noop!(
+			CollatorStaking::rebond(RuntimeOrigin::signed(3), 4, 3),
+			Error::<Test>::InvalidRebondAmount
+		);
+		assert_ok!(CollatorStaking::claim(RuntimeOrigin::signed(3)));
+		assert_eq!(Balances::balance(&3), 63);
+		assert_noop!(
+			CollatorStaking::rebond(RuntimeOrigin::signed(3), 4, 2),
+			Error::<Test>::InvalidRebondAmount
+		);
+	});
+}
+
+#[test]
+fn rebond_spans_several_requests() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(3), 3, 10));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(3), 4, 10));
+		assert_ok!(CollatorStaking::unstake_amount(RuntimeOrigin::signed(3), 3, 4));
+		initialize_to_block(3);
+		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(3), 3));
+		initialize_to_block(5);
+		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(3), 4));
+		assert_eq!(
+			UnstakingRequests::<Test>::get(3),
+			vec![
+				UnstakeRequest { candidate: 3, block: 6, amount: 4 },
+				UnstakeRequest { candidate: 4, block: 7, amount: 10 },
+				UnstakeRequest { candidate: 3, block: 8, amount: 6 }
+			]
+		);
+
+		// requests towards other candidates are skipped
+		assert_ok!(CollatorStaking::rebond(RuntimeOrigin::signed(3), 3, 8));
+		assert_eq!(
+			UnstakingRequests::<Test>::get(3),
+			vec![
+				UnstakeRequest { candidate: 3, block: 6, amount: 2 },
+				UnstakeRequest { candidate: 4, block: 7, amount: 10 }
+			]
+		);
+		assert_eq!(Stake::<Test>::get(3, 3), StakeInfo { stake: 8, session: 0 });
+		assert_eq!(Balances::balance(&3), 70);
+	});
+}
+
+#[test]
+fn cannot_rebond_invalid_amount() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
+		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(5), 3));
+		assert_eq!(
+			UnstakingRequests::<Test>::get(5),
+			vec![UnstakeRequest { candidate: 3, block: 3, amount: 20 }]
+		);
+
+		assert_noop!(
+			CollatorStaking::rebond(RuntimeOrigin::signed(5), 3, 0),
+			Error::<Test>::InvalidRebondAmount
+		);
+		assert_noop!(
+			CollatorStaking::rebond(RuntimeOrigin::signed(5), 3, 21),
+			Error::<Test>::InvalidRebondAmount
+		);
+		assert_noop!(
+			CollatorStaking::rebond(RuntimeOrigin::signed(5), 5, 10),
+			Error::<Test>::NotCandidate
+		);
+		assert_noop!(
+			CollatorStaking::rebond(RuntimeOrigin::signed(5), 4, 1),
+			Error::<Test>::InsufficientStake
+		);
+
+		// the whole request can be rebonded
+		assert_ok!(CollatorStaking::rebond(RuntimeOrigin::signed(5), 4, 20));
+		assert_eq!(UnstakingRequests::<Test>::get(5), vec![]);
+		assert_eq!(Stake::<Test>::get(4, 5), StakeInfo { stake: 20, session: 0 });
+		assert_eq!(Balances::balance(&5), 80);
+	});
+}
+
+#[test]
+fn move_stake() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
+		assert_eq!(Balances::balance(&5), 80);
+
+		// part of the stake can be moved
+		assert_ok!(CollatorStaking::move_stake(RuntimeOrigin::signed(5), 3, 4, 15));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::StakeMoved {
+			staker: 5,
+			from: 3,
+			to: 4,
+			amount: 15,
+		}));
+		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 5, session: 0 });
+		assert_eq!(Stake::<Test>::get(4, 5), StakeInfo { stake: 15, session: 0 });
+		assert_eq!(StakedCandidates::<Test>::get(5), vec![3, 4]);
+		assert_eq!(
+			CollatorStaking::candidate_list(),
+			vec![
+				CandidateInfo { who: 3, stake: 5, stakers: 1, deposit: 10, commission: COMMISSION },
+				CandidateInfo {
+					who: 4,
+					stake: 15,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
+			]
+		);
+		// funds remain on hold and no unstaking request is created
+		assert_eq!(Balances::balance(&5), 80);
+		assert_eq!(UnstakingRequests::<Test>::get(5), vec![]);
+
+		// stake cannot be moved again until the cooldown passes
+		assert_noop!(
+			CollatorStaking::move_stake(RuntimeOrigin::signed(5), 3, 4, 5),
+			Error::<Test>::StakeMoveTooSoon
+		);
+		initialize_to_block(6);
+
+		// the whole stake can be moved
+		assert_ok!(CollatorStaking::move_stake(RuntimeOrigin::signed(5), 3, 4, 5));
+		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 0, session: 0 });
+		assert_eq!(Stake::<Test>::get(4, 5), StakeInfo { stake: 20, session: 0 });
+		assert_eq!(StakedCandidates::<Test>::get(5), vec![4]);
+		assert_eq!(
+			CollatorStaking::candidate_list(),
+			vec![
+				CandidateInfo { who: 3, stake: 0, stakers: 0, deposit: 10, commission: COMMISSION },
+				CandidateInfo {
+					who: 4,
+					stake: 20,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
+			]
+		);
+		assert_eq!(Balances::balance(&5), 80);
+	});
+}
+
+#[test]
+fn cannot_move_stake_invalid() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
+
+		assert_noop!(
+			CollatorStaking::move_stake(RuntimeOrigin::signed(5), 3, 3, 10),
+			Error::<Test>::SameCandidate
+		);
+		assert_noop!(
+			CollatorStaking::move_stake(RuntimeOrigin::signed(5), 4, 3, 10),
+			Error::<Test>::NothingToUnstake
+		);
+		assert_noop!(
+			CollatorStaking::move_stake(RuntimeOrigin::signed(5), 3, 4, 0),
+			Error::<Test>::InvalidUnstakeAmount
+		);
+		assert_noop!(
+			CollatorStaking::move_stake(RuntimeOrigin::signed(5), 3, 4, 21),
+			Error::<Test>::InvalidUnstakeAmount
+		);
+		// the stake left would be lower than MinStake
+		assert_noop!(
+			CollatorStaking::move_stake(RuntimeOrigin::signed(5), 3, 4, 19),
+			Error::<Test>::InsufficientStake
+		);
+		// the stake moved would be lower than MinStake
+		assert_noop!(
+			CollatorStaking::move_stake(RuntimeOrigin::signed(5), 3, 4, 1),
+			Error::<Test>::InsufficientStake
+		);
+		assert_noop!(
+			CollatorStaking::move_stake(RuntimeOrigin::signed(5), 3, 5, 10),
+			Error::<Test>::NotCandidate
+		);
+	});
+}
+
+#[test]
+fn moved_stake_earns_rewards_from_next_session() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		register_candidates(3..=4);
+		// we do not want the candidates to be kicked.
+		LastAuthoredBlock::<Test>::insert(3, 100);
+		LastAuthoredBlock::<Test>::insert(4, 100);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 4, 10));
+
+		initialize_to_block(10);
+		assert_eq!(CurrentSession::<Test>::get(), 1);
+		assert_ok!(CollatorStaking::move_stake(RuntimeOrigin::signed(5), 3, 4, 5));
+
+		// The moved stake was at stake in the origin candidate during this session.
+		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 15, session: 0 });
+		assert_eq!(Stake::<Test>::get(4, 5), StakeInfo { stake: 15, session: 1 });
+	});
+}
+
+#[test]
+fn cannot_move_stake_with_pending_slash() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
+		assert_ok!(CollatorStaking::slash_candidate(
+			RuntimeOrigin::signed(RootAccount::get()),
+			3,
+			Perbill::from_percent(10)
+		));
+
+		assert_noop!(
+			CollatorStaking::move_stake(RuntimeOrigin::signed(5), 3, 4, 10),
+			Error::<Test>::PendingSlash
+		);
+
+		// Stake can be moved once the slash is cancelled.
+		assert_ok!(CollatorStaking::cancel_deferred_slash(
+			RuntimeOrigin::signed(RootAccount::get()),
+			2,
+			3
+		));
+		assert_ok!(CollatorStaking::move_stake(RuntimeOrigin::signed(5), 3, 4, 10));
+	});
+}
+
+#[test]
+fn stake_for() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::stake_for(RuntimeOrigin::signed(1), 5, 3, 20));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::StakeFunded {
+			funder: 1,
+			staker: 5,
+			candidate: 3,
+			amount: 20,
+		}));
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakeAdded {
+			staker: 5,
+			candidate: 3,
+			amount: 20,
+		}));
+		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 20, session: 0 });
+		assert_eq!(StakeFunders::<Test>::get(3, 5), Some(1));
+		assert_eq!(StakedCandidates::<Test>::get(5), vec![3]);
+		// funds are transferred to the beneficiary and held there
+		assert_eq!(Balances::balance(&1), 80);
+		assert_eq!(Balances::balance(&5), 100);
+		assert_eq!(Balances::total_balance(&5), 120);
+
+		// the funder can add more stake
+		assert_ok!(CollatorStaking::stake_for(RuntimeOrigin::signed(1), 5, 3, 10));
+		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 30, session: 0 });
+
+		// other accounts cannot stake on the funded position
+		assert_noop!(
+			CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 10),
+			Error::<Test>::StakeFundedByOtherAccount
+		);
+		assert_noop!(
+			CollatorStaking::stake_for(RuntimeOrigin::signed(2), 5, 3, 10),
+			Error::<Test>::StakeFundedByOtherAccount
+		);
+
+		// only the funder can withdraw the position
+		assert_noop!(
+			CollatorStaking::unstake_for(RuntimeOrigin::signed(2), 5, 3),
+			Error::<Test>::NotStakeFunder
+		);
+		assert_ok!(CollatorStaking::unstake_for(RuntimeOrigin::signed(1), 5, 3));
+		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 0, session: 0 });
+		assert_eq!(StakeFunders::<Test>::get(3, 5), None);
+		assert_eq!(StakedCandidates::<Test>::get(5).len(), 0);
+		assert_eq!(
+			UnstakingRequests::<Test>::get(1),
+			vec![UnstakeRequest { candidate: 3, block: 3, amount: 30 }]
+		);
+		assert_eq!(UnstakingRequests::<Test>::get(5), vec![]);
+		assert_eq!(Balances::total_balance(&5), 100);
+
+		initialize_to_block(3);
+		assert_ok!(CollatorStaking::claim(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::balance(&1), 100);
+	});
+}
+
+#[test]
+fn cannot_stake_for_invalid() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 4, 10));
+
+		assert_noop!(
+			CollatorStaking::stake_for(RuntimeOrigin::signed(5), 5, 3, 10),
+			Error::<Test>::CannotStakeForSelf
+		);
+		// the position already holds stake of the beneficiary
+		assert_noop!(
+			CollatorStaking::stake_for(RuntimeOrigin::signed(1), 5, 4, 10),
+			Error::<Test>::StakeFundedByOtherAccount
+		);
+		assert_noop!(
+			CollatorStaking::stake_for(RuntimeOrigin::signed(1), 5, 6, 10),
+			Error::<Test>::NotCandidate
+		);
+		assert_noop!(
+			CollatorStaking::stake_for(RuntimeOrigin::signed(1), 5, 3, 1),
+			Error::<Test>::InsufficientStake
+		);
+	});
+}
+
+#[test]
+fn funded_stake_is_returned_to_funder() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::stake_for(RuntimeOrigin::signed(1), 5, 3, 20));
+
+		// the beneficiary cannot move the funded stake
+		assert_noop!(
+			CollatorStaking::move_stake(RuntimeOrigin::signed(5), 3, 4, 10),
+			Error::<Test>::StakeFundedByOtherAccount
+		);
+
+		// funds unstaked by the beneficiary go to the funder
+		assert_ok!(CollatorStaking::unstake_amount(RuntimeOrigin::signed(5), 3, 10));
+		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 10, session: 0 });
+		assert_eq!(StakeFunders::<Test>::get(3, 5), Some(1));
+		assert_eq!(
+			UnstakingRequests::<Test>::get(1),
+			vec![UnstakeRequest { candidate: 3, block: 3, amount: 10 }]
+		);
+		assert_eq!(UnstakingRequests::<Test>::get(5), vec![]);
+		assert_eq!(Balances::total_balance(&5), 110);
+
+		// funds are released to the funder right away once the candidate leaves
+		assert_ok!(CollatorStaking::leave_intent(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(5), 3));
+		assert_eq!(StakeFunders::<Test>::get(3, 5), None);
+		assert_eq!(Balances::balance(&1), 90);
+		assert_eq!(Balances::total_balance(&5), 100);
+	});
+}
+
+#[test]
+fn set_autocompound_percentage() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		assert_eq!(AutoCompound::<Test>::get(5), Percent::from_parts(0));
+		assert_ok!(CollatorStaking::set_autocompound_percentage(
+			RuntimeOrigin::signed(5),
+			Percent::from_parts(50)
+		));
+		assert_eq!(AutoCompound::<Test>::get(5), Percent::from_parts(50));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(
+			Event::AutoCompoundPercentageSet { staker: 5, percentage: Percent::from_parts(50) },
+		));
+	});
+}
+
+#[test]
+fn set_reward_destination() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		assert_eq!(RewardDestinations::<Test>::get(5), RewardDestination::Free);
+		assert_ok!(CollatorStaking::set_reward_destination(
+			RuntimeOrigin::signed(5),
+			RewardDestination::Account(1)
+		));
+		assert_eq!(RewardDestinations::<Test>::get(5), RewardDestination::Account(1));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::RewardDestinationSet {
+			staker: 5,
+			destination: RewardDestination::Account(1),
+		}));
+
+		// the default destination is not stored
+		assert_ok!(CollatorStaking::set_reward_destination(
+			RuntimeOrigin::signed(5),
+			RewardDestination::Free
+		));
+		assert!(!RewardDestinations::<Test>::contains_key(5));
+	});
+}
+
+#[test]
+fn set_max_commission() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		assert_eq!(MaxCommission::<Test>::get(), Percent::from_parts(20));
+
+		// Invalid origin
+		assert_noop!(
+			CollatorStaking::set_max_commission(RuntimeOrigin::signed(5), Percent::from_parts(50)),
+			BadOrigin
+		);
+		assert_ok!(CollatorStaking::set_max_commission(
+			RuntimeOrigin::signed(RootAccount::get()),
+			Percent::from_parts(50)
+		));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::MaxCommissionSet {
+			commission: Percent::from_parts(50),
+		}));
+		assert_eq!(MaxCommission::<Test>::get(), Percent::from_parts(50));
+	});
+}
+
+#[test]
+fn cannot_register_with_commission_above_max() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		assert_noop!(
+			CollatorStaking::register_as_candidate(
+				RuntimeOrigin::signed(3),
+				Percent::from_parts(21),
+				0,
+			),
+			Error::<Test>::InvalidCommission
+		);
+	});
+}
+
+#[test]
+fn cannot_set_invalid_commission() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		// Only candidates can set a commission
+		assert_noop!(
+			CollatorStaking::set_commission(RuntimeOrigin::signed(3), Percent::from_parts(10)),
+			Error::<Test>::NotCandidate
+		);
+
+		register_candidates(3..=3);
+		assert_noop!(
+			CollatorStaking::set_commission(RuntimeOrigin::signed(3), Percent::from_parts(21)),
+			Error::<Test>::InvalidCommission
+		);
+	});
+}
+
+#[test]
+fn set_commission_decrease_is_immediate() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=3);
+
+		assert_ok!(CollatorStaking::set_commission(
+			RuntimeOrigin::signed(3),
+			Percent::from_parts(10)
+		));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::CommissionSet {
+			candidate: 3,
+			commission: Percent::from_parts(10),
+		}));
+		assert_eq!(CollatorStaking::candidate_list()[0].commission, Percent::from_parts(10));
+		assert_eq!(CommissionChangeRequests::<Test>::get(3), None);
+	});
+}
+
+#[test]
+fn set_commission_increase_is_delayed() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(4..=4);
+		assert_ok!(CollatorStaking::set_max_commission(
+			RuntimeOrigin::signed(RootAccount::get()),
+			Percent::from_parts(50)
+		));
+
+		assert_ok!(CollatorStaking::set_commission(
+			RuntimeOrigin::signed(4),
+			Percent::from_parts(30)
+		));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(
+			Event::CommissionChangeRequested {
+				candidate: 4,
+				commission: Percent::from_parts(30),
+				block: 6,
+			},
+		));
+		assert_eq!(CollatorStaking::candidate_list()[0].commission, COMMISSION);
+		assert_eq!(
+			CommissionChangeRequests::<Test>::get(4),
+			Some(CommissionChangeRequest { block: 6, commission: Percent::from_parts(30) })
+		);
+
+		// A decrease cancels the pending increase.
+		assert_ok!(CollatorStaking::set_commission(
+			RuntimeOrigin::signed(4),
+			Percent::from_parts(15)
+		));
+		assert_eq!(CollatorStaking::candidate_list()[0].commission, Percent::from_parts(15));
+		assert_eq!(CommissionChangeRequests::<Test>::get(4), None);
+
+		// Request the increase again and let it mature.
+		assert_ok!(CollatorStaking::set_commission(
+			RuntimeOrigin::signed(4),
+			Percent::from_parts(30)
+		));
+		initialize_to_block(6);
+
+		// The pending change is applied when the candidate is rewarded.
+		ProducedBlocks::<Test>::insert(0, 4, 1);
+		TotalBlocks::<Test>::insert(0, (1, 1));
+		Rewards::<Test>::insert(0, 10);
+		Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance() + 10)
+			.unwrap();
+		CollatorStaking::reward_one_collator(0);
+
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::CommissionSet {
+			candidate: 4,
+			commission: Percent::from_parts(30),
+		}));
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
+			staker: 4,
+			amount: 3,
+			session: 0,
+		}));
+		assert_eq!(CollatorStaking::candidate_list()[0].commission, Percent::from_parts(30));
+		assert_eq!(CommissionChangeRequests::<Test>::get(4), None);
+	});
+}
+
+#[test]
+fn pending_commission_change_is_removed_when_leaving() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=3);
+		assert_ok!(CollatorStaking::set_max_commission(
+			RuntimeOrigin::signed(RootAccount::get()),
+			Percent::from_parts(50)
+		));
+		assert_ok!(CollatorStaking::set_commission(
+			RuntimeOrigin::signed(3),
+			Percent::from_parts(30)
+		));
+		assert!(CommissionChangeRequests::<Test>::get(3).is_some());
+
+		assert_ok!(CollatorStaking::leave_intent(RuntimeOrigin::signed(3)));
+		assert_eq!(CommissionChangeRequests::<Test>::get(3), None);
+	});
+}
+
+#[test]
+fn set_extra_reward() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		assert_eq!(ExtraReward::<Test>::get(), 0);
+
+		// Invalid origin
+		assert_noop!(CollatorStaking::set_extra_reward(RuntimeOrigin::signed(5), 10), BadOrigin);
+
+		// Set the reward
+		assert_ok!(CollatorStaking::set_extra_reward(
+			RuntimeOrigin::signed(RootAccount::get()),
+			10
+		));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::ExtraRewardSet {
 			amount: 10,
 		}));
-		assert_eq!(ExtraReward::<Test>::get(), 10);
+		assert_eq!(ExtraReward::<Test>::get(), 10);
+
+		// Cannot set to zero
+		assert_noop!(
+			CollatorStaking::set_extra_reward(RuntimeOrigin::signed(RootAccount::get()), 0),
+			Error::<Test>::InvalidExtraReward
+		);
+
+		// Revert the changes
+		assert_ok!(CollatorStaking::stop_extra_reward(RuntimeOrigin::signed(RootAccount::get()),));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::ExtraRewardRemoved {
+			amount_left: 0,
+			receiver: Some(40),
+		}));
+		assert_eq!(ExtraReward::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn set_minimum_stake() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		assert_eq!(MinStake::<Test>::get(), 2);
+
+		// Invalid origin
+		assert_noop!(CollatorStaking::set_minimum_stake(RuntimeOrigin::signed(5), 5), BadOrigin);
+
+		// Set the reward over CandidacyBond
+		assert_noop!(
+			CollatorStaking::set_minimum_stake(RuntimeOrigin::signed(RootAccount::get()), 1000),
+			Error::<Test>::InvalidMinStake
+		);
+
+		// Zero is a valid value
+		assert_ok!(CollatorStaking::set_minimum_stake(
+			RuntimeOrigin::signed(RootAccount::get()),
+			0
+		));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::NewMinStake {
+			min_stake: 0,
+		}));
+		assert_eq!(MinStake::<Test>::get(), 0);
+
+		// Maximum is CandidacyBond
+		assert_eq!(CandidacyBond::<Test>::get(), 10);
+		assert_ok!(CollatorStaking::set_minimum_stake(
+			RuntimeOrigin::signed(RootAccount::get()),
+			10
+		));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::NewMinStake {
+			min_stake: 10,
+		}));
+		assert_eq!(MinStake::<Test>::get(), 10);
+	});
+}
+
+#[test]
+fn cannot_set_minimum_stake_above_min_self_stake() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		assert_ok!(CollatorStaking::set_minimum_self_stake(
+			RuntimeOrigin::signed(RootAccount::get()),
+			5
+		));
+
+		assert_noop!(
+			CollatorStaking::set_minimum_stake(RuntimeOrigin::signed(RootAccount::get()), 6),
+			Error::<Test>::InvalidMinStake
+		);
+		assert_ok!(CollatorStaking::set_minimum_stake(
+			RuntimeOrigin::signed(RootAccount::get()),
+			5
+		));
+		assert_eq!(MinStake::<Test>::get(), 5);
+
+		// Once the requirement is disabled, the limit no longer applies.
+		assert_ok!(CollatorStaking::set_minimum_self_stake(
+			RuntimeOrigin::signed(RootAccount::get()),
+			0
+		));
+		assert_ok!(CollatorStaking::set_minimum_stake(
+			RuntimeOrigin::signed(RootAccount::get()),
+			6
+		));
+		assert_eq!(MinStake::<Test>::get(), 6);
+	});
+}
+
+#[test]
+fn max_candidate_stake() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=4);
+
+		// Invalid origin
+		assert_noop!(
+			CollatorStaking::set_max_candidate_stake(RuntimeOrigin::signed(5), 30),
+			BadOrigin
+		);
+		assert_ok!(CollatorStaking::set_max_candidate_stake(
+			RuntimeOrigin::signed(RootAccount::get()),
+			30
+		));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::NewMaxCandidateStake {
+			max_candidate_stake: 30,
+		}));
+		assert_eq!(MaxCandidateStake::<Test>::get(), 30);
+
+		fund_account(10);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
+		assert_noop!(
+			CollatorStaking::stake(RuntimeOrigin::signed(10), 3, 15),
+			Error::<Test>::MaxCandidateStakeExceeded
+		);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(10), 3, 10));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(10), 4, 25));
+		assert_eq!(
+			CollatorStaking::candidate_list()
+				.iter()
+				.map(|c| (c.who, c.stake))
+				.collect::<Vec<_>>(),
+			vec![(4, 25), (3, 30)]
+		);
+
+		// Lowering the limit does not remove stake, but stake above it does not count for the
+		// selection of collators.
+		assert_ok!(CollatorStaking::set_max_candidate_stake(
+			RuntimeOrigin::signed(RootAccount::get()),
+			20
+		));
+		assert_eq!(Candidates::<Test>::get(3).unwrap().stake, 30);
+		assert_eq!(CollatorStaking::preview_next_collators().stake_cutoff, 20);
+		assert_noop!(
+			CollatorStaking::stake(RuntimeOrigin::signed(10), 3, 2),
+			Error::<Test>::MaxCandidateStakeExceeded
+		);
+
+		// 4 gets back under the limit, and both candidates rank equally.
+		assert_ok!(CollatorStaking::unstake_amount(RuntimeOrigin::signed(10), 4, 5));
+		assert_eq!(
+			CollatorStaking::candidate_list()
+				.iter()
+				.map(|c| (c.who, c.stake))
+				.collect::<Vec<_>>(),
+			vec![(4, 20), (3, 30)]
+		);
+		assert_ok!(CollatorStaking::unstake_amount(RuntimeOrigin::signed(10), 3, 5));
+		assert_eq!(
+			CollatorStaking::candidate_list()
+				.iter()
+				.map(|c| (c.who, c.stake))
+				.collect::<Vec<_>>(),
+			vec![(3, 25), (4, 20)]
+		);
+
+		// Removing the limit sorts the candidates by their whole stake again.
+		assert_ok!(CollatorStaking::set_max_candidate_stake(
+			RuntimeOrigin::signed(RootAccount::get()),
+			0
+		));
+		assert_eq!(
+			CollatorStaking::candidate_list()
+				.iter()
+				.map(|c| (c.who, c.stake))
+				.collect::<Vec<_>>(),
+			vec![(4, 20), (3, 25)]
+		);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(10), 3, 50));
+	});
+}
+
+#[test]
+fn autocompound_is_limited_by_max_candidate_stake() {
+	new_test_ext().execute_with(|| {
+		StakerRewardsMode::set(RewardsMode::Pull);
+		initialize_to_block(1);
+		register_candidates(4..=4);
+		assert_ok!(CollatorStaking::set_max_candidate_stake(
+			RuntimeOrigin::signed(RootAccount::get()),
+			25
+		));
+		for staker in 10..=11 {
+			fund_account(staker);
+			assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(staker), 4, 10));
+		}
+		assert_ok!(CollatorStaking::set_autocompound_percentage(
+			RuntimeOrigin::signed(10),
+			Percent::from_parts(100)
+		));
+
+		ProducedBlocks::<Test>::insert(1, 4, 10);
+		TotalBlocks::<Test>::insert(1, (10, 10));
+		Rewards::<Test>::insert(1, 100);
+		CurrentSession::<Test>::put(2);
+		Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance() + 100)
+			.unwrap();
+
+		// Total rewards: 100
+		// 20 (20%) for the collator
+		// 80 (80%) for the stakers, 40 each
+		initialize_to_block(2);
+		assert_ok!(CollatorStaking::claim_rewards(RuntimeOrigin::signed(10), 4));
+
+		// Only 5 can be compounded before reaching the limit.
+		assert_eq!(Stake::<Test>::get(4, 10).stake, 15);
+		assert_eq!(Candidates::<Test>::get(4).unwrap().stake, 25);
+		assert_eq!(Balances::balance(&10), 125);
+	});
+}
+
+#[test]
+fn set_lock_tiers() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		let tiers = vec![
+			LockTier { duration: 10, multiplier: FixedU128::from_u32(1) },
+			LockTier { duration: 20, multiplier: FixedU128::from_rational(3, 2) },
+		];
+
+		// Invalid origin
+		assert_noop!(
+			CollatorStaking::set_lock_tiers(
+				RuntimeOrigin::signed(5),
+				tiers.clone().try_into().unwrap()
+			),
+			BadOrigin
+		);
+
+		// Multipliers cannot reduce the share of the rewards.
+		assert_noop!(
+			CollatorStaking::set_lock_tiers(
+				RuntimeOrigin::signed(RootAccount::get()),
+				vec![LockTier { duration: 10, multiplier: FixedU128::from_rational(1, 2) }]
+					.try_into()
+					.unwrap()
+			),
+			Error::<Test>::InvalidLockTier
+		);
+
+		assert_ok!(CollatorStaking::set_lock_tiers(
+			RuntimeOrigin::signed(RootAccount::get()),
+			tiers.clone().try_into().unwrap()
+		));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::LockTiersSet {
+			tiers: tiers.clone(),
+		}));
+		assert_eq!(LockTiers::<Test>::get().into_inner(), tiers);
+	});
+}
+
+#[test]
+fn stake_with_lock() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(4..=4);
+		assert_ok!(CollatorStaking::set_lock_tiers(
+			RuntimeOrigin::signed(RootAccount::get()),
+			vec![
+				LockTier { duration: 10, multiplier: FixedU128::from_u32(2) },
+				LockTier { duration: 5, multiplier: FixedU128::from_u32(3) },
+			]
+			.try_into()
+			.unwrap()
+		));
+
+		assert_noop!(
+			CollatorStaking::stake_with_lock(RuntimeOrigin::signed(3), 4, 20, 2),
+			Error::<Test>::InvalidLockTier
+		);
+		assert_noop!(
+			CollatorStaking::stake_with_lock(RuntimeOrigin::signed(3), 4, 0, 0),
+			Error::<Test>::InsufficientStake
+		);
+		assert_ok!(CollatorStaking::stake_with_lock(RuntimeOrigin::signed(3), 4, 20, 0));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::StakeLocked {
+			staker: 3,
+			candidate: 4,
+			until: 11,
+			multiplier: FixedU128::from_u32(2),
+		}));
+		assert_eq!(
+			StakeLocks::<Test>::get(4, 3),
+			Some(StakeLock { until: 11, multiplier: FixedU128::from_u32(2), bonus: 20 })
+		);
+		assert_eq!(CandidateLockBonus::<Test>::get(4), 20);
+		// The lock does not affect the position of the candidate.
+		assert_eq!(Candidates::<Test>::get(4).unwrap().stake, 20);
+
+		// Stake added later is locked as well.
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(3), 4, 10));
+		assert_eq!(StakeLocks::<Test>::get(4, 3).unwrap().bonus, 30);
+		assert_eq!(CandidateLockBonus::<Test>::get(4), 30);
+
+		// Locks cannot be shortened.
+		assert_noop!(
+			CollatorStaking::stake_with_lock(RuntimeOrigin::signed(3), 4, 0, 1),
+			Error::<Test>::LockShortened
+		);
+
+		// Locked stake cannot be removed.
+		assert_noop!(
+			CollatorStaking::unstake_from(RuntimeOrigin::signed(3), 4),
+			Error::<Test>::StakeLocked
+		);
+		assert_noop!(
+			CollatorStaking::unstake_amount(RuntimeOrigin::signed(3), 4, 5),
+			Error::<Test>::StakeLocked
+		);
+		assert_noop!(
+			CollatorStaking::unlock_stake(RuntimeOrigin::signed(1), 4, 3),
+			Error::<Test>::StakeLocked
+		);
+		assert_noop!(
+			CollatorStaking::unlock_stake(RuntimeOrigin::signed(1), 4, 5),
+			Error::<Test>::NotLocked
+		);
+
+		// Anyone can remove the lock once it expires.
+		initialize_to_block(11);
+		assert_ok!(CollatorStaking::unlock_stake(RuntimeOrigin::signed(1), 4, 3));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::StakeUnlocked {
+			staker: 3,
+			candidate: 4,
+		}));
+		assert_eq!(StakeLocks::<Test>::get(4, 3), None);
+		assert_eq!(CandidateLockBonus::<Test>::get(4), 0);
+		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(3), 4));
+	});
+}
+
+#[test]
+fn locked_stake_can_be_unstaked_if_candidate_leaves() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(4..=4);
+		assert_ok!(CollatorStaking::set_lock_tiers(
+			RuntimeOrigin::signed(RootAccount::get()),
+			vec![LockTier { duration: 100, multiplier: FixedU128::from_u32(2) }]
+				.try_into()
+				.unwrap()
+		));
+		assert_ok!(CollatorStaking::stake_with_lock(RuntimeOrigin::signed(3), 4, 20, 0));
+
+		assert_ok!(CollatorStaking::leave_intent(RuntimeOrigin::signed(4)));
+		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(3), 4));
+		assert_eq!(StakeLocks::<Test>::get(4, 3), None);
+		assert_eq!(CandidateLockBonus::<Test>::get(4), 0);
+	});
+}
+
+#[test]
+fn locked_stake_gets_reward_multiplier() {
+	new_test_ext().execute_with(|| {
+		StakerRewardsMode::set(RewardsMode::Pull);
+		initialize_to_block(1);
+		register_candidates(4..=4);
+		assert_ok!(CollatorStaking::set_lock_tiers(
+			RuntimeOrigin::signed(RootAccount::get()),
+			vec![LockTier { duration: 100, multiplier: FixedU128::from_u32(3) }]
+				.try_into()
+				.unwrap()
+		));
+		for staker in 10..=11 {
+			fund_account(staker);
+		}
+		assert_ok!(CollatorStaking::stake_with_lock(RuntimeOrigin::signed(10), 4, 10, 0));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(11), 4, 10));
+
+		ProducedBlocks::<Test>::insert(1, 4, 10);
+		TotalBlocks::<Test>::insert(1, (10, 10));
+		Rewards::<Test>::insert(1, 100);
+		CurrentSession::<Test>::put(2);
+		Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance() + 100)
+			.unwrap();
+
+		// Total rewards: 100
+		// 20 (20%) for the collator
+		// 80 (80%) for the stakers, shared by a weight of 30 for 10 and 10 for 11
+		assert_eq!(CollatorStaking::pending_session_rewards(&10, &4), 60);
+		assert_eq!(CollatorStaking::pending_session_rewards(&11, &4), 20);
+		initialize_to_block(2);
+		assert_ok!(CollatorStaking::claim_rewards(RuntimeOrigin::signed(10), 4));
+		assert_ok!(CollatorStaking::claim_rewards(RuntimeOrigin::signed(11), 4));
+		assert_eq!(Balances::balance(&10), 150);
+		assert_eq!(Balances::balance(&11), 110);
+	});
+}
+
+#[test]
+fn expired_lock_loses_reward_multiplier() {
+	new_test_ext().execute_with(|| {
+		StakerRewardsMode::set(RewardsMode::Pull);
+		initialize_to_block(1);
+		register_candidates(4..=4);
+		assert_ok!(CollatorStaking::set_lock_tiers(
+			RuntimeOrigin::signed(RootAccount::get()),
+			vec![LockTier { duration: 1, multiplier: FixedU128::from_u32(3) }]
+				.try_into()
+				.unwrap()
+		));
+		for staker in 10..=11 {
+			fund_account(staker);
+		}
+		assert_ok!(CollatorStaking::stake_with_lock(RuntimeOrigin::signed(10), 4, 10, 0));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(11), 4, 10));
+
+		ProducedBlocks::<Test>::insert(1, 4, 10);
+		TotalBlocks::<Test>::insert(1, (10, 10));
+		Rewards::<Test>::insert(1, 100);
+		CurrentSession::<Test>::put(2);
+		Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance() + 100)
+			.unwrap();
+		assert_eq!(CollatorStaking::pending_session_rewards(&10, &4), 60);
+
+		// The lock expires before the rewards are distributed, so it is removed first.
+		initialize_to_block(2);
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakeUnlocked {
+			staker: 10,
+			candidate: 4,
+		}));
+		assert_eq!(StakeLocks::<Test>::get(4, 10), None);
+		assert_eq!(CandidateLockBonus::<Test>::get(4), 0);
+
+		// 80 (80%) for the stakers, shared equally.
+		assert_ok!(CollatorStaking::claim_rewards(RuntimeOrigin::signed(10), 4));
+		assert_ok!(CollatorStaking::claim_rewards(RuntimeOrigin::signed(11), 4));
+		assert_eq!(Balances::balance(&10), 130);
+		assert_eq!(Balances::balance(&11), 130);
+	});
+}
+
+#[test]
+fn set_minimum_self_stake() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		assert_eq!(MinSelfStake::<Test>::get(), 0);
+
+		// Invalid origin
+		assert_noop!(
+			CollatorStaking::set_minimum_self_stake(RuntimeOrigin::signed(5), 5),
+			BadOrigin
+		);
+
+		// Lower than MinStake
+		assert_eq!(MinStake::<Test>::get(), 2);
+		assert_noop!(
+			CollatorStaking::set_minimum_self_stake(RuntimeOrigin::signed(RootAccount::get()), 1),
+			Error::<Test>::InvalidMinSelfStake
+		);
+
+		assert_ok!(CollatorStaking::set_minimum_self_stake(
+			RuntimeOrigin::signed(RootAccount::get()),
+			5
+		));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::NewMinSelfStake {
+			min_self_stake: 5,
+		}));
+		assert_eq!(MinSelfStake::<Test>::get(), 5);
+
+		// Zero disables the requirement
+		assert_ok!(CollatorStaking::set_minimum_self_stake(
+			RuntimeOrigin::signed(RootAccount::get()),
+			0
+		));
+		assert_eq!(MinSelfStake::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn candidates_keep_min_self_stake() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		assert_ok!(CollatorStaking::set_minimum_self_stake(
+			RuntimeOrigin::signed(RootAccount::get()),
+			5
+		));
+
+		// The self-stake must be staked explicitly when registering.
+		assert_noop!(
+			CollatorStaking::register_as_candidate(RuntimeOrigin::signed(3), COMMISSION, 0),
+			Error::<Test>::InsufficientSelfStake
+		);
+		assert_noop!(
+			CollatorStaking::register_as_candidate(RuntimeOrigin::signed(3), COMMISSION, 4),
+			Error::<Test>::InsufficientSelfStake
+		);
+		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(3), COMMISSION, 5));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::StakeAdded {
+			staker: 3,
+			candidate: 3,
+			amount: 5,
+		}));
+		assert_eq!(Balances::balance(&3), 85);
+		assert_eq!(Stake::<Test>::get(3, 3), StakeInfo { stake: 5, session: 0 });
+		assert_eq!(Candidates::<Test>::get(3).unwrap().stake, 5);
+
+		// Self-stake cannot go below the minimum while being a candidate.
+		assert_noop!(
+			CollatorStaking::unstake_from(RuntimeOrigin::signed(3), 3),
+			Error::<Test>::InsufficientSelfStake
+		);
+		assert_noop!(
+			CollatorStaking::unstake_amount(RuntimeOrigin::signed(3), 3, 1),
+			Error::<Test>::InsufficientSelfStake
+		);
+		assert_noop!(
+			CollatorStaking::unstake_all(RuntimeOrigin::signed(3)),
+			Error::<Test>::InsufficientSelfStake
+		);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(3), 3, 3));
+		assert_ok!(CollatorStaking::unstake_amount(RuntimeOrigin::signed(3), 3, 3));
+		assert_eq!(Stake::<Test>::get(3, 3).stake, 5);
+
+		// Once the candidate leaves, self-stake is returned.
+		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(4), COMMISSION, 5));
+		assert_ok!(CollatorStaking::leave_intent(RuntimeOrigin::signed(3)));
+		assert_eq!(Stake::<Test>::get(3, 3).stake, 0);
+	});
+}
+
+#[test]
+fn should_not_reward_invulnerables() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CollatorStaking::add_invulnerable(RuntimeOrigin::signed(RootAccount::get()), 4));
+		assert_eq!(ExtraReward::<Test>::get(), 0);
+		assert_eq!(TotalBlocks::<Test>::get(0), (0, 0));
+		assert_eq!(CurrentSession::<Test>::get(), 0);
+		for block in 1..=9 {
+			initialize_to_block(block);
+			assert_eq!(CurrentSession::<Test>::get(), 0);
+			assert_eq!(TotalBlocks::<Test>::get(0), (block as u32, 0));
+
+			// Transfer the ED first
+			Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance())
+				.unwrap();
+
+			// Assume we collected one unit in fees per block
+			assert_ok!(Balances::transfer(&1, &CollatorStaking::account_id(), 1, Preserve));
+			finalize_current_block();
+		}
+
+		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 0);
+		initialize_to_block(10);
+		assert_eq!(CurrentSession::<Test>::get(), 1);
+		assert_eq!(TotalBlocks::<Test>::get(1), (1, 0));
+
+		// No StakingRewardReceived should have been emitted if only invulnerable is producing blocks.
+		assert!(!System::events().iter().any(|e| {
+			match e.event {
+				RuntimeEvent::CollatorStaking(Event::StakingRewardReceived { .. }) => true,
+				_ => false,
+			}
+		}));
+	});
+}
+
+#[test]
+fn should_reward_collator() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(4), COMMISSION, 0));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(4), 4, 10));
+		assert_eq!(ExtraReward::<Test>::get(), 0);
+		assert_eq!(Balances::balance(&CollatorStaking::account_id()), 0);
+		Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance()).unwrap();
+		assert_eq!(TotalBlocks::<Test>::get(0), (0, 0));
+		assert_eq!(CurrentSession::<Test>::get(), 0);
+		for block in 1..=9 {
+			initialize_to_block(block);
+			assert_eq!(CurrentSession::<Test>::get(), 0);
+			assert_eq!(TotalBlocks::<Test>::get(0), (block as u32, block as u32));
+
+			// Assume we collected one unit in fees per block
+			assert_ok!(Balances::transfer(&1, &CollatorStaking::account_id(), 1, Preserve));
+			finalize_current_block();
+		}
+		assert_eq!(
+			Balances::balance(&CollatorStaking::account_id()),
+			Balances::minimum_balance() + 9
+		);
+		assert!(!System::events().iter().any(|e| {
+			match e.event {
+				RuntimeEvent::CollatorStaking(Event::StakingRewardReceived { .. }) => true,
+				_ => false,
+			}
+		}));
+
+		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 9);
+		initialize_to_block(10);
+		assert_eq!(CurrentSession::<Test>::get(), 1);
+		assert_eq!(TotalBlocks::<Test>::get(1), (1, 1));
+
+		finalize_current_block();
+		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 0);
+
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
+			staker: 4,
+			amount: 1,
+			session: 0,
+		}));
+
+		System::reset_events();
+
+		for block in 10..=19 {
+			initialize_to_block(block);
+			assert_eq!(CurrentSession::<Test>::get(), 1);
+			assert_eq!(TotalBlocks::<Test>::get(1), (block as u32 - 9, block as u32 - 9));
+
+			// Assume we collected one unit in fees per block
+			assert_ok!(Balances::transfer(&1, &CollatorStaking::account_id(), 1, Preserve));
+			finalize_current_block();
+		}
+
+		assert_eq!(
+			Balances::free_balance(CollatorStaking::account_id()) - Balances::minimum_balance(),
+			18
+		);
+		initialize_to_block(20);
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::SessionEnded {
+			index: 1,
+			rewards: 18,
+		}));
+		assert_eq!(Balances::balance(&CollatorStaking::account_id()), Balances::minimum_balance());
+		assert_eq!(CurrentSession::<Test>::get(), 2);
+		assert_eq!(TotalBlocks::<Test>::get(2), (1, 1));
+
+		finalize_current_block();
+		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 0);
+
+		// Total rewards: 10 (from session 1) + 8 (from session 0) = 18
+		// 3 (20%) for collators
+		// 13 (80%) for stakers
+
+		// Reward for collator
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
+			staker: 4,
+			amount: 3,
+			session: 1,
+		}));
+		// Reward for staker
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
+			staker: 4,
+			amount: 15,
+			session: 1,
+		}));
+	});
+}
+
+#[test]
+fn should_reward_stakers_in_pages() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(4..=4);
+		for staker in 10..=16 {
+			fund_account(staker);
+			assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(staker), 4, 10));
+		}
+
+		// Collator 4 produced all blocks in session 1
+		ProducedBlocks::<Test>::insert(1, 4, 10);
+		TotalBlocks::<Test>::insert(1, (10, 10));
+		Rewards::<Test>::insert(1, 100);
+		CurrentSession::<Test>::put(2);
+		Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance() + 100)
+			.unwrap();
+		let rewarded_stakers = || {
+			System::events()
+				.iter()
+				.filter(|e| {
+					matches!(
+						e.event,
+						RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
+							staker: 10..=16,
+							..
+						})
+					)
+				})
+				.count()
+		};
+
+		// The collator and the first page of stakers are rewarded
+		initialize_to_block(2);
+		// Total rewards: 100
+		// 20 (20%) for the collator
+		// 80 (80%) for the stakers, 11 each
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
+			staker: 4,
+			amount: 20,
+			session: 1,
+		}));
+		assert_eq!(rewarded_stakers(), 5);
+		let cursor = CurrentRewardCursor::<Test>::get().unwrap();
+		assert_eq!(cursor.session, 1);
+		assert_eq!(cursor.collator, 4);
+		assert!(cursor.last_staker.is_some());
+		assert_eq!(cursor.total_stake, 70);
+		assert_eq!(cursor.stakers_rewards, 80);
+		assert_eq!(cursor.remaining_rewards, 25);
+
+		// The remaining stakers are rewarded in the next block
+		initialize_to_block(3);
+		assert_eq!(rewarded_stakers(), 7);
+		for staker in 10..=16 {
+			System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
+				staker,
+				amount: 11,
+				session: 1,
+			}));
+		}
+		assert_eq!(CurrentRewardCursor::<Test>::get(), None);
+		assert_eq!(
+			Balances::balance(&CollatorStaking::account_id()),
+			Balances::minimum_balance() + 3
+		);
+	});
+}
+
+#[test]
+fn should_record_rewards_to_be_claimed() {
+	new_test_ext().execute_with(|| {
+		StakerRewardsMode::set(RewardsMode::Pull);
+		initialize_to_block(1);
+		register_candidates(4..=4);
+		for staker in 10..=11 {
+			fund_account(staker);
+			assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(staker), 4, 10));
+		}
+		assert_ok!(CollatorStaking::set_autocompound_percentage(
+			RuntimeOrigin::signed(11),
+			Percent::from_parts(50)
+		));
+
+		// Collator 4 produced all blocks in session 1
+		ProducedBlocks::<Test>::insert(1, 4, 10);
+		TotalBlocks::<Test>::insert(1, (10, 10));
+		Rewards::<Test>::insert(1, 100);
+		CurrentSession::<Test>::put(2);
+		Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance() + 100)
+			.unwrap();
+
+		// Total rewards: 100
+		// 20 (20%) for the collator
+		// 80 (80%) recorded for the stakers, 4 per unit of stake
+		initialize_to_block(2);
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
+			staker: 4,
+			amount: 20,
+			session: 1,
+		}));
+		assert_eq!(CurrentRewardCursor::<Test>::get(), None);
+		assert_eq!(RewardPerStake::<Test>::get(1, 4), Some(FixedU128::from_u32(4)));
+		assert_eq!(UnclaimedRewards::<Test>::get(1), 80);
+		assert_eq!(Balances::balance(&10), 90);
+
+		// Unclaimed rewards are not considered rewards for the next session.
+		CollatorStaking::end_session(2);
+		assert_eq!(Rewards::<Test>::get(2), 0);
+
+		assert_ok!(CollatorStaking::claim_rewards(RuntimeOrigin::signed(10), 4));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::RewardsClaimed {
+			staker: 10,
+			candidate: 4,
+			amount: 40,
+		}));
+		assert_eq!(Balances::balance(&10), 130);
+		assert_eq!(LastClaimedSession::<Test>::get(4, 10), Some(1));
+		assert_eq!(UnclaimedRewards::<Test>::get(1), 40);
+		assert_noop!(
+			CollatorStaking::claim_rewards(RuntimeOrigin::signed(10), 4),
+			Error::<Test>::NoPendingRewards
+		);
+
+		// Rewards can be claimed on behalf of others, and they are auto-compounded.
+		assert_ok!(CollatorStaking::claim_rewards_for(RuntimeOrigin::signed(3), 11, 4));
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::RewardsClaimed {
+			staker: 11,
+			candidate: 4,
+			amount: 40,
+		}));
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakeAdded {
+			staker: 11,
+			candidate: 4,
+			amount: 20,
+		}));
+		assert_eq!(Stake::<Test>::get(4, 11).stake, 30);
+		assert_eq!(CollatorStaking::candidate_list()[0].stake, 40);
+		assert_eq!(UnclaimedRewards::<Test>::get(1), 0);
+		assert_eq!(Balances::balance(&CollatorStaking::account_id()), Balances::minimum_balance());
+	});
+}
+
+#[test]
+fn rewards_are_delivered_to_reward_destination() {
+	new_test_ext().execute_with(|| {
+		StakerRewardsMode::set(RewardsMode::Pull);
+		initialize_to_block(1);
+		register_candidates(4..=4);
+		for staker in 10..=13 {
+			fund_account(staker);
+			assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(staker), 4, 10));
+		}
+		for (account, destination) in [
+			(4, RewardDestination::Account(2)),
+			(10, RewardDestination::Staked),
+			(11, RewardDestination::Account(1)),
+			(12, RewardDestination::Burn),
+		] {
+			assert_ok!(CollatorStaking::set_reward_destination(
+				RuntimeOrigin::signed(account),
+				destination
+			));
+		}
+		assert_ok!(CollatorStaking::set_autocompound_percentage(
+			RuntimeOrigin::signed(13),
+			Percent::from_parts(50)
+		));
+
+		// Collator 4 produced all blocks in session 1
+		ProducedBlocks::<Test>::insert(1, 4, 10);
+		TotalBlocks::<Test>::insert(1, (10, 10));
+		Rewards::<Test>::insert(1, 100);
+		CurrentSession::<Test>::put(2);
+		Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance() + 100)
+			.unwrap();
+
+		// Total rewards: 100
+		// 20 (20%) for the collator, sent to account 2
+		// 80 (80%) recorded for the stakers, 2 per unit of stake
+		initialize_to_block(2);
+		assert_eq!(Balances::balance(&2), 120);
+		assert_eq!(Balances::balance(&4), 90);
+
+		// rewards are staked in full
+		assert_ok!(CollatorStaking::claim_rewards(RuntimeOrigin::signed(10), 4));
+		assert_eq!(Stake::<Test>::get(4, 10).stake, 30);
+		assert_eq!(Balances::balance(&10), 90);
+
+		// rewards are sent to a different account
+		assert_ok!(CollatorStaking::claim_rewards(RuntimeOrigin::signed(11), 4));
+		assert_eq!(Stake::<Test>::get(4, 11).stake, 10);
+		assert_eq!(Balances::balance(&11), 90);
+		assert_eq!(Balances::balance(&1), 120);
+
+		// rewards are burned
+		let issuance = Balances::total_issuance();
+		assert_ok!(CollatorStaking::claim_rewards(RuntimeOrigin::signed(12), 4));
+		assert_eq!(Balances::balance(&12), 90);
+		assert_eq!(Balances::total_issuance(), issuance - 20);
+
+		// rewards are transferred and auto-compounded
+		assert_ok!(CollatorStaking::claim_rewards(RuntimeOrigin::signed(13), 4));
+		assert_eq!(Stake::<Test>::get(4, 13).stake, 20);
+		assert_eq!(Balances::balance(&13), 100);
+		assert_eq!(Balances::balance(&CollatorStaking::account_id()), Balances::minimum_balance());
+	});
+}
+
+#[test]
+fn cannot_claim_rewards_if_pushed() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(4..=4);
+		assert_noop!(
+			CollatorStaking::claim_rewards(RuntimeOrigin::signed(4), 4),
+			Error::<Test>::RewardsNotClaimable
+		);
+		assert_noop!(
+			CollatorStaking::claim_rewards_for(RuntimeOrigin::signed(3), 4, 4),
+			Error::<Test>::RewardsNotClaimable
+		);
+	});
+}
+
+#[test]
+fn rewards_are_claimed_when_stake_changes() {
+	new_test_ext().execute_with(|| {
+		StakerRewardsMode::set(RewardsMode::Pull);
+		initialize_to_block(1);
+		register_candidates(4..=4);
+		fund_account(10);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(10), 4, 10));
+		LastAuthoredBlock::<Test>::insert(4, 100);
+
+		// The staker was not eligible for rewards in the session it first staked in.
+		RewardPerStake::<Test>::insert(0, 4, FixedU128::from_u32(1));
+		UnclaimedRewards::<Test>::insert(0, 10);
+		RewardPerStake::<Test>::insert(1, 4, FixedU128::from_u32(2));
+		UnclaimedRewards::<Test>::insert(1, 20);
+		CurrentSession::<Test>::put(2);
+		Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance() + 30)
+			.unwrap();
+
+		assert_ok!(CollatorStaking::unstake_amount(RuntimeOrigin::signed(10), 4, 5));
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::RewardsClaimed {
+			staker: 10,
+			candidate: 4,
+			amount: 20,
+		}));
+		assert_eq!(LastClaimedSession::<Test>::get(4, 10), Some(1));
+		assert_eq!(UnclaimedRewards::<Test>::get(0), 10);
+		assert_eq!(UnclaimedRewards::<Test>::get(1), 0);
+
+		// Rewards not claimed in time expire.
+		CollatorStaking::start_session(4);
+		assert_eq!(RewardPerStake::<Test>::get(0, 4), None);
+		assert_eq!(UnclaimedRewards::<Test>::get(0), 0);
+
+		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(10), 4));
+		assert_eq!(LastClaimedSession::<Test>::get(4, 10), None);
+	});
+}
+
+#[test]
+fn projected_rewards() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 10));
+
+		// No rewards so far.
+		assert_eq!(CollatorStaking::projected_rewards(10), vec![(4, 0), (3, 0)]);
+
+		Rewards::<Test>::insert(0, 100);
+		CurrentSession::<Test>::put(1);
+		TotalBlocks::<Test>::insert(1, (10, 10));
+		ProducedBlocks::<Test>::insert(1, 3, 6);
+		ProducedBlocks::<Test>::insert(1, 4, 4);
+
+		// Candidate 3: 60 in rewards, 12 (20%) as commission, 48 shared with the existing stake.
+		// Candidate 4: 40 in rewards, 8 (20%) as commission, 32 for the new stake.
+		assert_eq!(CollatorStaking::projected_rewards(10), vec![(4, 32), (3, 24)]);
+	});
+}
+
+#[test]
+fn pending_session_rewards() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(4..=4);
+		for staker in 10..=16 {
+			fund_account(staker);
+			assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(staker), 4, 10));
+		}
+		assert_eq!(CollatorStaking::pending_session_rewards(&10, &4), 0);
+
+		// Collator 4 produced all blocks in session 1
+		ProducedBlocks::<Test>::insert(1, 4, 10);
+		TotalBlocks::<Test>::insert(1, (10, 10));
+		Rewards::<Test>::insert(1, 100);
+		CurrentSession::<Test>::put(2);
+		Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance() + 100)
+			.unwrap();
+		let pending_rewards =
+			|| (10..=16).map(|staker| CollatorStaking::pending_session_rewards(&staker, &4));
+
+		// 80 for the stakers, 11 each.
+		assert!(pending_rewards().all(|reward| reward == 11));
+
+		// The first page of stakers is rewarded.
+		initialize_to_block(2);
+		assert_eq!(pending_rewards().filter(|reward| *reward == 0).count(), 5);
+		assert_eq!(pending_rewards().filter(|reward| *reward == 11).count(), 2);
+
+		initialize_to_block(3);
+		assert!(pending_rewards().all(|reward| reward == 0));
+	});
+}
+
+#[test]
+fn should_reward_collator_with_extra_rewards() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(4), COMMISSION, 0));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(4), 4, 10));
+		ExtraReward::<Test>::put(1);
+		assert_eq!(Balances::balance(&CollatorStaking::account_id()), 0);
+		Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance()).unwrap();
+		fund_account(CollatorStaking::extra_reward_account_id());
+
+		assert_eq!(TotalBlocks::<Test>::get(0), (0, 0));
+		assert_eq!(CurrentSession::<Test>::get(), 0);
+		for block in 1..=9 {
+			initialize_to_block(block);
+			assert_eq!(CurrentSession::<Test>::get(), 0);
+			assert_eq!(TotalBlocks::<Test>::get(0), (block as u32, block as u32));
+
+			// Assume we collected one unit in fees per block
+			assert_ok!(Balances::transfer(&1, &CollatorStaking::account_id(), 1, Preserve));
+			finalize_current_block();
+		}
+		assert_eq!(
+			Balances::balance(&CollatorStaking::account_id()),
+			Balances::minimum_balance() + 9
+		);
+		assert!(!System::events().iter().any(|e| {
+			match e.event {
+				RuntimeEvent::CollatorStaking(Event::StakingRewardReceived { .. }) => true,
+				_ => false,
+			}
+		}));
+
+		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 9);
+		initialize_to_block(10);
+		assert_eq!(CurrentSession::<Test>::get(), 1);
+		assert_eq!(TotalBlocks::<Test>::get(1), (1, 1));
+
+		finalize_current_block();
+		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 0);
+
+		// We collected 1 per block, plus 1 as extra reward per block.
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
+			staker: 4,
+			amount: 3,
+			session: 0,
+		}));
+
+		System::reset_events();
+
+		for block in 10..=19 {
+			initialize_to_block(block);
+			assert_eq!(CurrentSession::<Test>::get(), 1);
+			assert_eq!(TotalBlocks::<Test>::get(1), (block as u32 - 9, block as u32 - 9));
+
+			// Assume we collected one unit in fees per block
+			assert_ok!(Balances::transfer(&1, &CollatorStaking::account_id(), 1, Preserve));
+			finalize_current_block();
+		}
+
+		assert_eq!(
+			Balances::free_balance(CollatorStaking::account_id()) - Balances::minimum_balance(),
+			25
+		);
+		initialize_to_block(20);
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::SessionEnded {
+			index: 1,
+			rewards: 35,
+		}));
+		assert_eq!(
+			Balances::free_balance(CollatorStaking::account_id()) - Balances::minimum_balance(),
+			0
+		);
+		assert_eq!(CurrentSession::<Test>::get(), 2);
+		assert_eq!(TotalBlocks::<Test>::get(2), (1, 1));
+
+		finalize_current_block();
+		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 0);
+
+		// Total rewards: 25 (accumulated in the pot) + 10 (extra rewards)
+		// 3 (20%) for collators
+		// 13 (80%) for stakers
+
+		// Reward for collator
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
+			staker: 4,
+			amount: 7,
+			session: 1,
+		}));
+		// Reward for staker
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
+			staker: 4,
+			amount: 28,
+			session: 1,
+		}));
+	});
+}
+
+#[test]
+fn should_reward_collator_with_extra_rewards_and_no_funds() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(4), COMMISSION, 0));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(4), 4, 10));
+		// This account has no funds
+		ExtraReward::<Test>::put(1);
+		assert_eq!(Balances::balance(&CollatorStaking::account_id()), 0);
+		Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance()).unwrap();
+
+		assert_eq!(TotalBlocks::<Test>::get(0), (0, 0));
+		assert_eq!(CurrentSession::<Test>::get(), 0);
+		for block in 1..=9 {
+			initialize_to_block(block);
+			assert_eq!(CurrentSession::<Test>::get(), 0);
+			assert_eq!(TotalBlocks::<Test>::get(0), (block as u32, block as u32));
+
+			// Assume we collected one unit in fees per block
+			assert_ok!(Balances::transfer(&1, &CollatorStaking::account_id(), 1, Preserve));
+			finalize_current_block();
+		}
+		assert_eq!(
+			Balances::balance(&CollatorStaking::account_id()),
+			Balances::minimum_balance() + 9
+		);
+		assert!(!System::events().iter().any(|e| {
+			match e.event {
+				RuntimeEvent::CollatorStaking(Event::StakingRewardReceived { .. }) => true,
+				_ => false,
+			}
+		}));
+
+		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 9);
+		initialize_to_block(10);
+		assert_eq!(CurrentSession::<Test>::get(), 1);
+		assert_eq!(TotalBlocks::<Test>::get(1), (1, 1));
+
+		finalize_current_block();
+		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 0);
+
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
+			staker: 4,
+			amount: 1,
+			session: 0,
+		}));
+
+		System::reset_events();
+
+		for block in 10..=19 {
+			initialize_to_block(block);
+			assert_eq!(CurrentSession::<Test>::get(), 1);
+			assert_eq!(TotalBlocks::<Test>::get(1), (block as u32 - 9, block as u32 - 9));
+
+			// Assume we collected one unit in fees per block
+			assert_ok!(Balances::transfer(&1, &CollatorStaking::account_id(), 1, Preserve));
+			finalize_current_block();
+		}
+
+		assert_eq!(
+			Balances::free_balance(CollatorStaking::account_id()) - Balances::minimum_balance(),
+			18
+		);
+		initialize_to_block(20);
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::SessionEnded {
+			index: 1,
+			rewards: 18,
+		}));
+		assert_eq!(
+			Balances::free_balance(CollatorStaking::account_id()) - Balances::minimum_balance(),
+			0
+		);
+		assert_eq!(Balances::balance(&CollatorStaking::account_id()), Balances::minimum_balance());
+		assert_eq!(CurrentSession::<Test>::get(), 2);
+		assert_eq!(TotalBlocks::<Test>::get(2), (1, 1));
+
+		finalize_current_block();
+		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 0);
+
+		// Total rewards: 10 (from session 1) + 8 (from session 0) = 18
+		// 3 (20%) for collators
+		// 13 (80%) for stakers
+
+		// Reward for collator
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
+			staker: 4,
+			amount: 3,
+			session: 1,
+		}));
+		// Reward for staker
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
+			staker: 4,
+			amount: 15,
+			session: 1,
+		}));
+	});
+}
+
+#[test]
+fn fixed_mint_reward_source() {
+	new_test_ext().execute_with(|| {
+		let pot = CollatorStaking::account_id();
+		FixedMint::<Test, ConstU64<3>>::provide_rewards(&pot, 0);
+		assert_eq!(Balances::balance(&pot), 0);
+
+		FixedMint::<Test, ConstU64<3>>::provide_rewards(&pot, 10);
+		assert_eq!(Balances::balance(&pot), 30);
+		assert_eq!(Balances::total_issuance(), 530);
+	});
+}
+
+#[test]
+fn inflation_curve() {
+	let curve = InflationCurve {
+		min_inflation: Perbill::from_percent(10),
+		max_inflation: Perbill::from_percent(50),
+		ideal_staking_rate: Perbill::from_percent(5),
+		blocks_per_year: 100,
+	};
+	assert_eq!(curve.annual_inflation(Perbill::zero()), Perbill::from_percent(10));
+	assert_eq!(curve.annual_inflation(Perbill::from_parts(25_000_000)), Perbill::from_percent(30));
+	assert_eq!(curve.annual_inflation(Perbill::from_percent(5)), Perbill::from_percent(50));
+	assert_eq!(curve.annual_inflation(Perbill::from_parts(525_000_000)), Perbill::from_percent(30));
+	assert_eq!(curve.annual_inflation(Perbill::one()), Perbill::from_percent(10));
+}
+
+#[test]
+fn ideal_staking_rate_inflation_reward_source() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=3);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(3), 3, 40));
+		assert_eq!(Balances::total_issuance(), 500);
+		assert_eq!(TotalStaked::<Test>::get(), 50);
+
+		// Staking rate: 10% including the candidacy bond, so the annual inflation is 10% out of
+		// the 50% at the ideal 50%.
+		// Annual rewards: 50, and 5 for the 10 blocks produced out of 100 per year.
+		let pot = CollatorStaking::account_id();
+		IdealStakingRateInflation::<Test, TestInflationCurve>::provide_rewards(&pot, 10);
+		assert_eq!(Balances::balance(&pot), 5);
+		assert_eq!(Balances::total_issuance(), 505);
+	});
+}
+
+#[test]
+fn total_staked_tracks_held_funds() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=3);
+		assert_eq!(TotalStaked::<Test>::get(), 10);
+
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(4), 3, 20));
+		assert_eq!(TotalStaked::<Test>::get(), 30);
+
+		// Idle stake is held as well.
+		assert_ok!(<CollatorStaking as StakingInterface>::bond(&5, 10, &5));
+		assert_eq!(TotalStaked::<Test>::get(), 40);
+
+		// Funds waiting to be unstaked remain at stake until claimed.
+		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(4), 3));
+		assert_eq!(TotalStaked::<Test>::get(), 40);
+		initialize_to_block(3);
+		assert_ok!(CollatorStaking::claim(RuntimeOrigin::signed(4)));
+		assert_eq!(TotalStaked::<Test>::get(), 20);
+
+		// Slashed funds are no longer staked.
+		assert_eq!(CollatorStaking::slash(&3, Perbill::from_percent(50)), 5);
+		assert_eq!(TotalStaked::<Test>::get(), 15);
+	});
+}
+
+#[test]
+fn should_reward_collator_with_extra_rewards_and_many_stakers() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(3), COMMISSION, 0));
+		// only the candidate 4 is going to produce blocks, but we do not want the candidate 3 to be kicked.
+		LastAuthoredBlock::<Test>::insert(3, 100);
+		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(4), COMMISSION, 0));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(2), 4, 40));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(3), 4, 50));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 91));
+		assert_eq!(
+			CollatorStaking::candidate_list(),
+			vec![
+				CandidateInfo {
+					who: 4,
+					stake: 90,
+					stakers: 2,
+					deposit: 10,
+					commission: COMMISSION
+				},
+				CandidateInfo {
+					who: 3,
+					stake: 91,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				}
+			]
+		);
+
+		// Staker 3 will autocompound 40% of its earnings
+		AutoCompound::<Test>::insert(3, Percent::from_parts(40));
+		ExtraReward::<Test>::put(1);
+		assert_eq!(Balances::balance(&CollatorStaking::account_id()), 0);
+		Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance()).unwrap();
+		fund_account(CollatorStaking::extra_reward_account_id());
+
+		assert_eq!(TotalBlocks::<Test>::get(0), (0, 0));
+		assert_eq!(CurrentSession::<Test>::get(), 0);
+		for block in 1..=9 {
+			initialize_to_block(block);
+			assert_eq!(CurrentSession::<Test>::get(), 0);
+			assert_eq!(TotalBlocks::<Test>::get(0), (block as u32, block as u32));
+
+			// Assume we collected one unit in fees per block
+			assert_ok!(Balances::transfer(&1, &CollatorStaking::account_id(), 1, Preserve));
+			finalize_current_block();
+		}
+		assert_eq!(
+			Balances::balance(&CollatorStaking::account_id()),
+			Balances::minimum_balance() + 9
+		);
+		assert!(!System::events().iter().any(|e| {
+			match e.event {
+				RuntimeEvent::CollatorStaking(Event::StakingRewardReceived { .. }) => true,
+				_ => false,
+			}
+		}));
 
-		// Cannot set to zero
+		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 9);
+		initialize_to_block(10);
+		assert_eq!(CurrentSession::<Test>::get(), 1);
+		assert_eq!(TotalBlocks::<Test>::get(1), (1, 1));
+		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 0);
+
+		// Reward for collator
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
+			staker: 4,
+			amount: 3,
+			session: 0,
+		}));
+
+		System::reset_events();
+
+		for block in 10..=19 {
+			initialize_to_block(block);
+			assert_eq!(CurrentSession::<Test>::get(), 1);
+			assert_eq!(TotalBlocks::<Test>::get(1), (block as u32 - 9, block as u32 - 9));
+
+			// Assume we collected one unit in fees per block
+			assert_ok!(Balances::transfer(&1, &CollatorStaking::account_id(), 1, Preserve));
+			finalize_current_block();
+		}
+
+		assert_eq!(
+			Balances::free_balance(CollatorStaking::account_id()) - Balances::minimum_balance(),
+			25
+		);
+		initialize_to_block(20);
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::SessionEnded {
+			index: 1,
+			rewards: 35,
+		}));
+		assert_eq!(
+			Balances::free_balance(CollatorStaking::account_id()) - Balances::minimum_balance(),
+			1
+		); // rounding
+		assert_eq!(CurrentSession::<Test>::get(), 2);
+		assert_eq!(TotalBlocks::<Test>::get(2), (1, 1));
+		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 0);
+
+		// we need one more block to distribute rewards among the two collators
+		initialize_to_block(21);
+
+		// Total rewards: 25 (accumulated) + 10 (extra rewards) = 35
+		// 7 (20%) for collators
+		//  - Staker 4: 7
+		// 28 (80%) for stakers
+		//  - Staker 2 -> 44.4% = 12
+		//  - Staker 3 -> 55.5% = 15
+
+		// Reward for collator
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
+			staker: 4,
+			amount: 7,
+			session: 1,
+		}));
+		// Reward for stakers
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
+			staker: 2,
+			amount: 12,
+			session: 1,
+		}));
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
+			staker: 3,
+			amount: 15,
+			session: 1,
+		}));
+
+		// Check that staker 3 added 40% of its earnings via autocompound.
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakeAdded {
+			staker: 3,
+			candidate: 4,
+			amount: 6,
+		}));
+
+		// Check after adding the stake via autocompound the candidate list is sorted.
+		assert_eq!(
+			CollatorStaking::candidate_list(),
+			vec![
+				CandidateInfo {
+					who: 3,
+					stake: 91,
+					stakers: 1,
+					deposit: 10,
+					commission: COMMISSION
+				},
+				CandidateInfo {
+					who: 4,
+					stake: 96,
+					stakers: 2,
+					deposit: 10,
+					commission: COMMISSION
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn stop_extra_reward() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		fund_account(CollatorStaking::extra_reward_account_id());
+		assert_eq!(ExtraReward::<Test>::get(), 0);
+
+		// Cannot stop if already zero
 		assert_noop!(
-			CollatorStaking::set_extra_reward(RuntimeOrigin::signed(RootAccount::get()), 0),
-			Error::<Test>::InvalidExtraReward
+			CollatorStaking::stop_extra_reward(RuntimeOrigin::signed(RootAccount::get())),
+			Error::<Test>::ExtraRewardAlreadyDisabled
 		);
 
-		// Revert the changes
-		assert_ok!(CollatorStaking::stop_extra_reward(RuntimeOrigin::signed(RootAccount::get()),));
+		// Now we can stop it
+		assert_ok!(CollatorStaking::set_extra_reward(RuntimeOrigin::signed(RootAccount::get()), 2));
+		assert_ok!(CollatorStaking::stop_extra_reward(RuntimeOrigin::signed(RootAccount::get())));
+
 		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::ExtraRewardRemoved {
-			amount_left: 0,
+			amount_left: 100,
 			receiver: Some(40),
 		}));
 		assert_eq!(ExtraReward::<Test>::get(), 0);
@@ -1968,552 +4720,1289 @@ fn set_extra_reward() {
 }
 
 #[test]
-fn set_minimum_stake() {
+fn top_up_extra_rewards() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		assert_eq!(Balances::balance(&CollatorStaking::extra_reward_account_id()), 0);
+
+		// Cannot fund with an amount equal to zero.
+		assert_noop!(
+			CollatorStaking::top_up_extra_rewards(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::InvalidFundingAmount
+		);
+
+		// Cannot fund if total balance less than ED.
+		assert_noop!(
+			CollatorStaking::top_up_extra_rewards(RuntimeOrigin::signed(1), 1),
+			TokenError::BelowMinimum
+		);
+
+		// Now we can top it up.
+		assert_ok!(CollatorStaking::top_up_extra_rewards(RuntimeOrigin::signed(1), 10));
+
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::ExtraRewardPotFunded {
+			pot: CollatorStaking::extra_reward_account_id(),
+			amount: 10,
+		}));
+		assert_eq!(Balances::balance(&CollatorStaking::extra_reward_account_id()), 10);
+	});
+}
+
+#[test]
+fn slash_candidate_and_stakers() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(3), 3, 20));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 40));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(4), 3, 10));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 4, 10));
+		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(4), 3));
+		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(5), 4));
+
+		assert_eq!(CollatorStaking::slash(&3, Perbill::from_percent(50)), 40);
+
+		// The candidacy bond was slashed
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::Slashed {
+			staker: 3,
+			candidate: 3,
+			amount: 5,
+		}));
+		// The stake deposited on the candidate was slashed
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::Slashed {
+			staker: 3,
+			candidate: 3,
+			amount: 10,
+		}));
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::Slashed {
+			staker: 5,
+			candidate: 3,
+			amount: 20,
+		}));
+		assert_eq!(Stake::<Test>::get(3, 3), StakeInfo { stake: 10, session: 0 });
+		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 20, session: 0 });
+		assert_eq!(
+			CollatorStaking::candidate_list(),
+			vec![
+				CandidateInfo { who: 4, stake: 0, stakers: 0, deposit: 10, commission: COMMISSION },
+				CandidateInfo { who: 3, stake: 30, stakers: 2, deposit: 5, commission: COMMISSION },
+			]
+		);
+
+		// The stake pending to be unstaked from the candidate was slashed too, but not the one
+		// coming from other candidates.
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::Slashed {
+			staker: 4,
+			candidate: 3,
+			amount: 5,
+		}));
+		assert_eq!(
+			UnstakingRequests::<Test>::get(4),
+			vec![UnstakeRequest { candidate: 3, block: 3, amount: 5 }]
+		);
+		assert_eq!(
+			UnstakingRequests::<Test>::get(5),
+			vec![UnstakeRequest { candidate: 4, block: 3, amount: 10 }]
+		);
+		assert_eq!(CandidateUnstakers::<Test>::get(3, 4), Some(1));
+		assert_eq!(CandidateUnstakers::<Test>::get(4, 5), Some(1));
+
+		// Slashed funds were sent to the configured destination
+		assert_eq!(Balances::balance(&50), 40);
+		assert_eq!(Balances::total_balance(&3), 85);
+		assert_eq!(Balances::total_balance(&4), 95);
+		assert_eq!(Balances::total_balance(&5), 80);
+	});
+}
+
+#[test]
+fn slash_all_stake() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		register_candidates(3..=3);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
+		assert_eq!(StakedCandidates::<Test>::get(5).len(), 1);
+
+		assert_eq!(CollatorStaking::slash(&3, Perbill::from_percent(100)), 30);
+
+		// The staker no longer backs the candidate
+		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 0, session: 0 });
+		assert_eq!(StakedCandidates::<Test>::get(5).len(), 0);
+		assert_eq!(
+			CollatorStaking::candidate_list(),
+			vec![CandidateInfo {
+				who: 3,
+				stake: 0,
+				stakers: 0,
+				deposit: 0,
+				commission: COMMISSION
+			}]
+		);
+		assert_eq!(Balances::balance(&50), 30);
+		assert_eq!(Balances::total_balance(&5), 80);
+	});
+}
+
+#[test]
+fn unstaking_requests_are_indexed_by_candidate() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 4, 10));
+		assert_ok!(CollatorStaking::unstake_amount(RuntimeOrigin::signed(5), 3, 5));
+		assert_ok!(CollatorStaking::unstake_amount(RuntimeOrigin::signed(5), 3, 5));
+		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(5), 4));
+		assert_eq!(CandidateUnstakers::<Test>::get(3, 5), Some(2));
+		assert_eq!(CandidateUnstakers::<Test>::get(4, 5), Some(1));
+		assert_eq!(CandidateUnstakeRequestCount::<Test>::get(3), 2);
+		assert_eq!(CandidateUnstakeRequestCount::<Test>::get(4), 1);
+
+		// Fully slashed requests are no longer indexed.
+		assert_eq!(CollatorStaking::slash(&3, Perbill::from_percent(100)), 30);
+		assert_eq!(
+			UnstakingRequests::<Test>::get(5),
+			vec![UnstakeRequest { candidate: 4, block: 3, amount: 10 }]
+		);
+		assert_eq!(CandidateUnstakers::<Test>::get(3, 5), None);
+		assert_eq!(CandidateUnstakeRequestCount::<Test>::get(3), 0);
+
+		// Neither are claimed requests.
+		initialize_to_block(3);
+		assert_ok!(CollatorStaking::claim(RuntimeOrigin::signed(5)));
+		assert_eq!(CandidateUnstakers::<Test>::get(4, 5), None);
+		assert_eq!(CandidateUnstakeRequestCount::<Test>::get(4), 0);
+	});
+}
+
+#[test]
+fn slash_nothing() {
 	new_test_ext().execute_with(|| {
 		initialize_to_block(1);
 
-		assert_eq!(MinStake::<Test>::get(), 2);
+		register_candidates(3..=3);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
+		System::reset_events();
 
-		// Invalid origin
-		assert_noop!(CollatorStaking::set_minimum_stake(RuntimeOrigin::signed(5), 5), BadOrigin);
+		assert_eq!(CollatorStaking::slash(&3, Perbill::from_percent(0)), 0);
+		assert!(System::events().is_empty());
+		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 20, session: 0 });
+	});
+}
+
+#[test]
+fn cannot_slash_candidate_if_invalid() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=3);
 
-		// Set the reward over CandidacyBond
 		assert_noop!(
-			CollatorStaking::set_minimum_stake(RuntimeOrigin::signed(RootAccount::get()), 1000),
-			Error::<Test>::InvalidMinStake
+			CollatorStaking::slash_candidate(
+				RuntimeOrigin::signed(5),
+				3,
+				Perbill::from_percent(10)
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			CollatorStaking::slash_candidate(
+				RuntimeOrigin::signed(RootAccount::get()),
+				3,
+				Perbill::from_percent(0)
+			),
+			Error::<Test>::InvalidSlashFraction
+		);
+		assert_noop!(
+			CollatorStaking::slash_candidate(
+				RuntimeOrigin::signed(RootAccount::get()),
+				4,
+				Perbill::from_percent(10)
+			),
+			Error::<Test>::NotCandidate
 		);
+	});
+}
 
-		// Zero is a valid value
-		assert_ok!(CollatorStaking::set_minimum_stake(
+#[test]
+fn slash_candidate_is_deferred() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=3);
+		// we do not want the candidate 3 to be kicked.
+		LastAuthoredBlock::<Test>::insert(3, 100);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
+
+		assert_ok!(CollatorStaking::slash_candidate(
 			RuntimeOrigin::signed(RootAccount::get()),
-			0
+			3,
+			Perbill::from_percent(50)
 		));
-		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::NewMinStake {
-			min_stake: 0,
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::SlashDeferred {
+			candidate: 3,
+			fraction: Perbill::from_percent(50),
+			session: 2,
 		}));
-		assert_eq!(MinStake::<Test>::get(), 0);
 
-		// Maximum is CandidacyBond
-		assert_eq!(CandidacyBond::<Test>::get(), 10);
-		assert_ok!(CollatorStaking::set_minimum_stake(
+		// Reporting again the same candidate keeps the highest fraction
+		assert_ok!(CollatorStaking::slash_candidate(
 			RuntimeOrigin::signed(RootAccount::get()),
-			10
+			3,
+			Perbill::from_percent(10)
 		));
-		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::NewMinStake {
-			min_stake: 10,
+		assert_eq!(
+			UnappliedSlashes::<Test>::get(2),
+			vec![UnappliedSlash { candidate: 3, fraction: Perbill::from_percent(50) }]
+		);
+
+		// Nothing is slashed until the session is reached
+		initialize_to_block(10);
+		assert_eq!(CurrentSession::<Test>::get(), 1);
+		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 20, session: 0 });
+
+		initialize_to_block(20);
+		assert_eq!(CurrentSession::<Test>::get(), 2);
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::Slashed {
+			staker: 3,
+			candidate: 3,
+			amount: 5,
 		}));
-		assert_eq!(MinStake::<Test>::get(), 10);
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::Slashed {
+			staker: 5,
+			candidate: 3,
+			amount: 10,
+		}));
+		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 10, session: 0 });
+		assert_eq!(UnappliedSlashes::<Test>::get(2), vec![]);
 	});
 }
 
 #[test]
-fn should_not_reward_invulnerables() {
+fn cannot_claim_unstaked_funds_with_pending_slash() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(CollatorStaking::add_invulnerable(RuntimeOrigin::signed(RootAccount::get()), 4));
-		assert_eq!(ExtraReward::<Test>::get(), 0);
-		assert_eq!(TotalBlocks::<Test>::get(0), (0, 0));
-		assert_eq!(CurrentSession::<Test>::get(), 0);
-		for block in 1..=9 {
-			initialize_to_block(block);
-			assert_eq!(CurrentSession::<Test>::get(), 0);
-			assert_eq!(TotalBlocks::<Test>::get(0), (block as u32, 0));
+		initialize_to_block(1);
+		register_candidates(3..=4);
+		// we do not want the candidates to be kicked.
+		LastAuthoredBlock::<Test>::insert(3, 100);
+		LastAuthoredBlock::<Test>::insert(4, 100);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 4, 10));
+		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(5), 3));
+		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(5), 4));
+		assert_ok!(CollatorStaking::slash_candidate(
+			RuntimeOrigin::signed(RootAccount::get()),
+			3,
+			Perbill::from_percent(50)
+		));
+		assert!(CollatorStaking::has_pending_slash(&3));
 
-			// Transfer the ED first
-			Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance())
-				.unwrap();
+		// Only the funds unstaked from the candidate without pending slashes can be claimed.
+		initialize_to_block(3);
+		assert_eq!(CollatorStaking::staker_info(&5).claimable, 10);
+		assert_ok!(CollatorStaking::claim(RuntimeOrigin::signed(5)));
+		assert_eq!(Balances::balance(&5), 80);
+		assert_eq!(
+			UnstakingRequests::<Test>::get(5),
+			vec![UnstakeRequest { candidate: 3, block: 3, amount: 20 }]
+		);
 
-			// Assume we collected one unit in fees per block
-			assert_ok!(Balances::transfer(&1, &CollatorStaking::account_id(), 1, Preserve));
-			finalize_current_block();
-		}
+		// Once the slash is applied the rest can be claimed.
+		initialize_to_block(20);
+		assert!(!CollatorStaking::has_pending_slash(&3));
+		assert_eq!(CollatorStaking::staker_info(&5).claimable, 10);
+		assert_ok!(CollatorStaking::claim(RuntimeOrigin::signed(5)));
+		assert_eq!(Balances::balance(&5), 90);
+		assert_eq!(UnstakingRequests::<Test>::get(5), vec![]);
+	});
+}
 
-		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 0);
-		initialize_to_block(10);
-		assert_eq!(CurrentSession::<Test>::get(), 1);
-		assert_eq!(TotalBlocks::<Test>::get(1), (1, 0));
+#[test]
+fn slash_former_candidate() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=3);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
+		assert_ok!(CollatorStaking::unstake_from(RuntimeOrigin::signed(5), 3));
+		assert_ok!(CollatorStaking::leave_intent(RuntimeOrigin::signed(3)));
 
-		// No StakingRewardReceived should have been emitted if only invulnerable is producing blocks.
-		assert!(!System::events().iter().any(|e| {
-			match e.event {
-				RuntimeEvent::CollatorStaking(Event::StakingRewardReceived { .. }) => true,
-				_ => false,
-			}
-		}));
+		// The candidate left, but funds removed from it are still waiting to be unstaked.
+		assert_ok!(CollatorStaking::slash_candidate(
+			RuntimeOrigin::signed(RootAccount::get()),
+			3,
+			Perbill::from_percent(50)
+		));
+		initialize_to_block(20);
+		assert_eq!(
+			UnstakingRequests::<Test>::get(5),
+			vec![UnstakeRequest { candidate: 3, block: 3, amount: 10 }]
+		);
+		assert_eq!(
+			UnstakingRequests::<Test>::get(3),
+			vec![UnstakeRequest { candidate: 3, block: 6, amount: 5 }]
+		);
+
+		// Nothing is left to be slashed once all requests are claimed.
+		assert_ok!(CollatorStaking::claim(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorStaking::claim(RuntimeOrigin::signed(5)));
+		assert_noop!(
+			CollatorStaking::slash_candidate(
+				RuntimeOrigin::signed(RootAccount::get()),
+				3,
+				Perbill::from_percent(50)
+			),
+			Error::<Test>::NotCandidate
+		);
 	});
 }
 
 #[test]
-fn should_reward_collator() {
+fn refunded_stakers_are_slashed_after_candidate_leaves() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(4),));
-		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(4), 4, 10));
-		assert_eq!(ExtraReward::<Test>::get(), 0);
-		assert_eq!(Balances::balance(&CollatorStaking::account_id()), 0);
-		Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance()).unwrap();
-		assert_eq!(TotalBlocks::<Test>::get(0), (0, 0));
-		assert_eq!(CurrentSession::<Test>::get(), 0);
-		for block in 1..=9 {
-			initialize_to_block(block);
-			assert_eq!(CurrentSession::<Test>::get(), 0);
-			assert_eq!(TotalBlocks::<Test>::get(0), (block as u32, block as u32));
+		initialize_to_block(1);
+		register_candidates(3..=3);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
+		assert_ok!(CollatorStaking::slash_candidate(
+			RuntimeOrigin::signed(RootAccount::get()),
+			3,
+			Perbill::from_percent(50)
+		));
+		assert_ok!(CollatorStaking::leave_intent(RuntimeOrigin::signed(3)));
 
-			// Assume we collected one unit in fees per block
-			assert_ok!(Balances::transfer(&1, &CollatorStaking::account_id(), 1, Preserve));
-			finalize_current_block();
-		}
+		// The refund is kept on hold while the slash is pending.
+		finalize_current_block();
+		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 0, session: 0 });
 		assert_eq!(
-			Balances::balance(&CollatorStaking::account_id()),
-			Balances::minimum_balance() + 9
+			UnstakingRequests::<Test>::get(5),
+			vec![UnstakeRequest { candidate: 3, block: 1, amount: 20 }]
 		);
-		assert!(!System::events().iter().any(|e| {
-			match e.event {
-				RuntimeEvent::CollatorStaking(Event::StakingRewardReceived { .. }) => true,
-				_ => false,
-			}
+		assert_eq!(CandidateUnstakers::<Test>::get(3, 5), Some(1));
+		initialize_to_block(3);
+		assert_eq!(CollatorStaking::staker_info(&5).claimable, 0);
+		assert_ok!(CollatorStaking::claim(RuntimeOrigin::signed(5)));
+		assert_eq!(Balances::balance(&5), 80);
+
+		// Once the slash is applied the staker gets the rest back.
+		initialize_to_block(20);
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::Slashed {
+			staker: 5,
+			candidate: 3,
+			amount: 10,
 		}));
+		assert_ok!(CollatorStaking::claim(RuntimeOrigin::signed(5)));
+		assert_eq!(Balances::balance(&5), 90);
+		assert_eq!(UnstakingRequests::<Test>::get(5), vec![]);
+	});
+}
 
-		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 9);
-		initialize_to_block(10);
-		assert_eq!(CurrentSession::<Test>::get(), 1);
-		assert_eq!(TotalBlocks::<Test>::get(1), (1, 1));
+#[test]
+fn cancel_deferred_slash() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=3);
+		// we do not want the candidate 3 to be kicked.
+		LastAuthoredBlock::<Test>::insert(3, 100);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
+		assert_ok!(CollatorStaking::slash_candidate(
+			RuntimeOrigin::signed(RootAccount::get()),
+			3,
+			Perbill::from_percent(50)
+		));
 
-		finalize_current_block();
-		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 0);
+		assert_noop!(
+			CollatorStaking::cancel_deferred_slash(RuntimeOrigin::signed(5), 2, 3),
+			BadOrigin
+		);
+		assert_noop!(
+			CollatorStaking::cancel_deferred_slash(RuntimeOrigin::signed(RootAccount::get()), 1, 3),
+			Error::<Test>::SlashNotFound
+		);
+		assert_noop!(
+			CollatorStaking::cancel_deferred_slash(RuntimeOrigin::signed(RootAccount::get()), 2, 4),
+			Error::<Test>::SlashNotFound
+		);
 
-		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
-			staker: 4,
-			amount: 1,
-			session: 0,
+		assert_ok!(CollatorStaking::cancel_deferred_slash(
+			RuntimeOrigin::signed(RootAccount::get()),
+			2,
+			3
+		));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::DeferredSlashCancelled {
+			candidate: 3,
+			session: 2,
 		}));
+		assert_eq!(UnappliedSlashes::<Test>::get(2), vec![]);
+		assert!(!CollatorStaking::has_pending_slash(&3));
 
-		System::reset_events();
+		// Nothing is slashed once the session is reached
+		initialize_to_block(20);
+		assert_eq!(CurrentSession::<Test>::get(), 2);
+		assert!(!System::events()
+			.iter()
+			.any(|e| { matches!(e.event, RuntimeEvent::CollatorStaking(Event::Slashed { .. })) }));
+		assert_eq!(Stake::<Test>::get(3, 5), StakeInfo { stake: 20, session: 0 });
+	});
+}
 
-		for block in 10..=19 {
-			initialize_to_block(block);
-			assert_eq!(CurrentSession::<Test>::get(), 1);
-			assert_eq!(TotalBlocks::<Test>::get(1), (block as u32 - 9, block as u32 - 9));
+#[test]
+fn staking_interface_bond_and_nominate() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=4);
 
-			// Assume we collected one unit in fees per block
-			assert_ok!(Balances::transfer(&1, &CollatorStaking::account_id(), 1, Preserve));
-			finalize_current_block();
-		}
+		assert_eq!(CollatorStaking::status(&5), Err(Error::<Test>::NotStaker.into()));
+		assert_noop!(CollatorStaking::bond(&5, 1, &5), Error::<Test>::InsufficientStake);
+		assert_ok!(CollatorStaking::bond(&5, 9, &5));
+		assert_noop!(CollatorStaking::bond(&5, 9, &5), Error::<Test>::AlreadyStaker);
+		assert_eq!(Balances::balance(&5), 91);
+		assert_eq!(IdleStake::<Test>::get(5), 9);
+		assert_eq!(
+			<CollatorStaking as StakingInterface>::stake(&5),
+			Ok(sp_staking::Stake { total: 9, active: 9 })
+		);
+		assert_eq!(CollatorStaking::status(&5), Ok(StakerStatus::Idle));
+
+		// Idle stake is split among the candidates, and the remainder goes to the first one.
+		assert_noop!(CollatorStaking::nominate(&5, vec![]), Error::<Test>::EmptyTargets);
+		assert_ok!(CollatorStaking::nominate(&5, vec![3, 4, 3]));
+		assert_eq!(IdleStake::<Test>::get(5), 0);
+		assert_eq!(Stake::<Test>::get(3, 5).stake, 5);
+		assert_eq!(Stake::<Test>::get(4, 5).stake, 4);
+		assert_eq!(CollatorStaking::status(&5), Ok(StakerStatus::Nominator(vec![3, 4])));
+		assert_eq!(CollatorStaking::status(&3), Ok(StakerStatus::Validator));
+
+		// Stake in candidates no longer nominated is moved to the rest.
+		assert_ok!(CollatorStaking::nominate(&5, vec![4]));
+		assert_eq!(Stake::<Test>::get(3, 5).stake, 0);
+		assert_eq!(Stake::<Test>::get(4, 5).stake, 9);
+		assert_eq!(CollatorStaking::status(&5), Ok(StakerStatus::Nominator(vec![4])));
+
+		// Chilled stake goes through the unstaking delay.
+		assert_ok!(CollatorStaking::chill(&5));
+		assert_eq!(Stake::<Test>::get(4, 5).stake, 0);
+		assert_eq!(IdleStake::<Test>::get(5), 0);
+		assert_eq!(
+			UnstakingRequests::<Test>::get(5),
+			vec![UnstakeRequest { candidate: 4, block: 3, amount: 9 }]
+		);
+		assert_eq!(CollatorStaking::status(&5), Ok(StakerStatus::Idle));
+		assert_eq!(
+			<CollatorStaking as StakingInterface>::stake(&5),
+			Ok(sp_staking::Stake { total: 9, active: 0 })
+		);
+		assert_eq!(Balances::balance(&5), 91);
+	});
+}
+
+#[test]
+fn staking_interface_nominate_follows_move_stake_rules() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::bond(&5, 20, &5));
+		assert_ok!(CollatorStaking::nominate(&5, vec![3]));
+
+		// Stake cannot be moved away from a candidate with a pending slash.
+		assert_ok!(CollatorStaking::slash_candidate(
+			RuntimeOrigin::signed(RootAccount::get()),
+			3,
+			Perbill::from_percent(10)
+		));
+		assert_noop!(CollatorStaking::nominate(&5, vec![4]), Error::<Test>::PendingSlash);
+		assert_ok!(CollatorStaking::cancel_deferred_slash(
+			RuntimeOrigin::signed(RootAccount::get()),
+			2,
+			3
+		));
+
+		assert_ok!(CollatorStaking::nominate(&5, vec![4]));
+		assert_eq!(Stake::<Test>::get(4, 5).stake, 20);
+		assert_eq!(LastStakeMove::<Test>::get(5), Some(1));
+
+		// Nominating the same candidates does not move any stake, so it is not limited.
+		assert_ok!(CollatorStaking::bond_extra(&5, 10));
+		assert_eq!(Stake::<Test>::get(4, 5).stake, 30);
+
+		assert_noop!(CollatorStaking::nominate(&5, vec![3]), Error::<Test>::StakeMoveTooSoon);
+		initialize_to_block(6);
+		assert_ok!(CollatorStaking::nominate(&5, vec![3]));
+		assert_eq!(Stake::<Test>::get(3, 5).stake, 30);
+	});
+}
 
+#[test]
+fn staking_interface_chilled_stake_can_be_slashed() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=3);
+		assert_ok!(CollatorStaking::bond(&5, 20, &5));
+		assert_ok!(CollatorStaking::nominate(&5, vec![3]));
+		assert_ok!(CollatorStaking::chill(&5));
+
+		assert_eq!(CollatorStaking::slash(&3, Perbill::from_percent(50)), 15);
 		assert_eq!(
-			Balances::free_balance(CollatorStaking::account_id()) - Balances::minimum_balance(),
-			18
+			UnstakingRequests::<Test>::get(5),
+			vec![UnstakeRequest { candidate: 3, block: 3, amount: 10 }]
 		);
-		initialize_to_block(20);
-		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::SessionEnded {
-			index: 1,
-			rewards: 18,
-		}));
-		assert_eq!(Balances::balance(&CollatorStaking::account_id()), Balances::minimum_balance());
-		assert_eq!(CurrentSession::<Test>::get(), 2);
-		assert_eq!(TotalBlocks::<Test>::get(2), (1, 1));
+		initialize_to_block(3);
+		assert_eq!(CollatorStaking::withdraw_unbonded(5, 0), Ok(true));
+		assert_eq!(Balances::balance(&5), 90);
+	});
+}
 
-		finalize_current_block();
-		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 0);
+#[test]
+fn staking_interface_bond_extra_with_max_staked_candidates() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
 
-		// Total rewards: 10 (from session 1) + 8 (from session 0) = 18
-		// 3 (20%) for collators
-		// 13 (80%) for stakers
+		register_candidates(3..=18);
+		assert_ok!(CollatorStaking::bond(&1, 32, &1));
+		assert_ok!(CollatorStaking::nominate(&1, (3..=18).collect()));
+		assert_eq!(StakedCandidates::<Test>::get(1).len(), 16);
 
-		// Reward for collator
-		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
-			staker: 4,
-			amount: 3,
-			session: 1,
-		}));
-		// Reward for staker
-		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
-			staker: 4,
-			amount: 15,
-			session: 1,
-		}));
+		assert_ok!(CollatorStaking::bond_extra(&1, 16));
+		for i in 3..=18 {
+			assert_eq!(Stake::<Test>::get(i, 1).stake, 3);
+		}
 	});
 }
 
 #[test]
-fn should_reward_collator_with_extra_rewards() {
+fn staking_interface_bonding_duration() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(4),));
-		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(4), 4, 10));
-		ExtraReward::<Test>::put(1);
-		assert_eq!(Balances::balance(&CollatorStaking::account_id()), 0);
-		Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance()).unwrap();
-		fund_account(CollatorStaking::extra_reward_account_id());
+		// The unstaking delay of 2 blocks is rounded up to a whole session of 10 blocks.
+		assert_eq!(<CollatorStaking as StakingInterface>::bonding_duration(), 1);
+	});
+}
 
-		assert_eq!(TotalBlocks::<Test>::get(0), (0, 0));
-		assert_eq!(CurrentSession::<Test>::get(), 0);
-		for block in 1..=9 {
-			initialize_to_block(block);
-			assert_eq!(CurrentSession::<Test>::get(), 0);
-			assert_eq!(TotalBlocks::<Test>::get(0), (block as u32, block as u32));
+#[test]
+fn staking_interface_unbond_and_withdraw() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::bond(&5, 20, &5));
+		assert_ok!(CollatorStaking::nominate(&5, vec![3, 4]));
 
-			// Assume we collected one unit in fees per block
-			assert_ok!(Balances::transfer(&1, &CollatorStaking::account_id(), 1, Preserve));
-			finalize_current_block();
-		}
+		assert_ok!(CollatorStaking::bond_extra(&5, 10));
+		assert_eq!(Stake::<Test>::get(3, 5).stake, 15);
+		assert_eq!(Stake::<Test>::get(4, 5).stake, 15);
+		assert_eq!(Balances::balance(&5), 70);
+
+		// Stake is unbonded from the candidates nominated the latest first.
+		assert_noop!(CollatorStaking::unbond(&5, 31), Error::<Test>::InvalidUnstakeAmount);
+		assert_ok!(CollatorStaking::unbond(&5, 20));
+		assert_eq!(Stake::<Test>::get(3, 5).stake, 10);
+		assert_eq!(Stake::<Test>::get(4, 5).stake, 0);
 		assert_eq!(
-			Balances::balance(&CollatorStaking::account_id()),
-			Balances::minimum_balance() + 9
+			<CollatorStaking as StakingInterface>::stake(&5),
+			Ok(sp_staking::Stake { total: 30, active: 10 })
 		);
-		assert!(!System::events().iter().any(|e| {
-			match e.event {
-				RuntimeEvent::CollatorStaking(Event::StakingRewardReceived { .. }) => true,
-				_ => false,
-			}
-		}));
 
-		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 9);
-		initialize_to_block(10);
-		assert_eq!(CurrentSession::<Test>::get(), 1);
-		assert_eq!(TotalBlocks::<Test>::get(1), (1, 1));
+		assert_eq!(CollatorStaking::withdraw_unbonded(5, 0), Ok(false));
+		assert_eq!(Balances::balance(&5), 70);
+		initialize_to_block(3);
+		assert_eq!(CollatorStaking::withdraw_unbonded(5, 0), Ok(false));
+		assert_eq!(Balances::balance(&5), 90);
 
-		finalize_current_block();
-		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 0);
+		assert_ok!(CollatorStaking::fully_unbond(&5));
+		initialize_to_block(5);
+		assert_eq!(CollatorStaking::withdraw_unbonded(5, 0), Ok(true));
+		assert_eq!(Balances::balance(&5), 100);
+		assert_eq!(CollatorStaking::status(&5), Err(Error::<Test>::NotStaker.into()));
+	});
+}
 
-		// We collected 1 per block, plus 1 as extra reward per block.
-		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
-			staker: 4,
-			amount: 3,
-			session: 0,
-		}));
+#[test]
+fn staking_interface_unbond_idle_stake() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		assert_ok!(CollatorStaking::bond(&5, 20, &5));
 
-		System::reset_events();
+		// Idle stake does not back any candidate, so no unstaking request is created.
+		assert_ok!(CollatorStaking::unbond(&5, 5));
+		assert_eq!(IdleStake::<Test>::get(5), 15);
+		assert_eq!(IdleUnbonding::<Test>::get(5), Some(IdleUnbond { block: 3, amount: 5 }));
+		assert_eq!(UnstakingRequests::<Test>::get(5), vec![]);
+		assert_eq!(CandidateUnstakers::<Test>::get(5, 5), None);
+		assert_eq!(CandidateUnstakeRequestCount::<Test>::get(5), 0);
+		assert_eq!(
+			<CollatorStaking as StakingInterface>::stake(&5),
+			Ok(sp_staking::Stake { total: 20, active: 15 })
+		);
 
-		for block in 10..=19 {
-			initialize_to_block(block);
-			assert_eq!(CurrentSession::<Test>::get(), 1);
-			assert_eq!(TotalBlocks::<Test>::get(1), (block as u32 - 9, block as u32 - 9));
+		// Unbonding again restarts the delay for the whole amount.
+		initialize_to_block(2);
+		assert_ok!(CollatorStaking::unbond(&5, 5));
+		assert_eq!(IdleUnbonding::<Test>::get(5), Some(IdleUnbond { block: 4, amount: 10 }));
+		initialize_to_block(3);
+		assert_eq!(CollatorStaking::withdraw_unbonded(5, 0), Ok(false));
+		assert_eq!(Balances::balance(&5), 80);
 
-			// Assume we collected one unit in fees per block
-			assert_ok!(Balances::transfer(&1, &CollatorStaking::account_id(), 1, Preserve));
-			finalize_current_block();
-		}
+		initialize_to_block(4);
+		assert_eq!(CollatorStaking::withdraw_unbonded(5, 0), Ok(false));
+		assert_eq!(IdleUnbonding::<Test>::get(5), None);
+		assert_eq!(Balances::balance(&5), 90);
+		assert_eq!(TotalStaked::<Test>::get(), 10);
+	});
+}
 
+#[test]
+fn staking_interface_payee_exposure_and_force_unstake() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=3);
+		assert_ok!(CollatorStaking::bond(&5, 20, &6));
+		assert_eq!(RewardDestinations::<Test>::get(5), RewardDestination::Account(6));
+		assert_ok!(CollatorStaking::update_payee(&5, &5));
+		assert_eq!(RewardDestinations::<Test>::get(5), RewardDestination::Free);
+
+		assert_ok!(CollatorStaking::nominate(&5, vec![3]));
+		ProducedBlocks::<Test>::insert(0, 3, 1);
+		assert!(CollatorStaking::is_exposed_in_era(&3, &0));
+		assert!(CollatorStaking::is_exposed_in_era(&5, &0));
+		assert!(!CollatorStaking::is_exposed_in_era(&5, &1));
+		assert!(!CollatorStaking::is_exposed_in_era(&4, &0));
+
+		assert_ok!(CollatorStaking::chill(&5));
+		assert!(!CollatorStaking::is_exposed_in_era(&5, &0));
 		assert_eq!(
-			Balances::free_balance(CollatorStaking::account_id()) - Balances::minimum_balance(),
-			25
+			UnstakingRequests::<Test>::get(5),
+			vec![UnstakeRequest { candidate: 3, block: 3, amount: 20 }]
 		);
-		initialize_to_block(20);
-		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::SessionEnded {
-			index: 1,
-			rewards: 35,
-		}));
+
+		// Idle stake is unbonded before the stake deposited in candidates.
+		initialize_to_block(2);
+		assert_ok!(CollatorStaking::bond_extra(&5, 20));
+		assert_ok!(CollatorStaking::unbond(&5, 5));
+		assert_eq!(IdleStake::<Test>::get(5), 15);
 		assert_eq!(
-			Balances::free_balance(CollatorStaking::account_id()) - Balances::minimum_balance(),
-			0
+			UnstakingRequests::<Test>::get(5),
+			vec![UnstakeRequest { candidate: 3, block: 3, amount: 20 }]
 		);
-		assert_eq!(CurrentSession::<Test>::get(), 2);
-		assert_eq!(TotalBlocks::<Test>::get(2), (1, 1));
+		assert_eq!(IdleUnbonding::<Test>::get(5), Some(IdleUnbond { block: 4, amount: 5 }));
 
-		finalize_current_block();
-		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 0);
+		// Forced unstakes return the funds right away.
+		assert_ok!(CollatorStaking::nominate(&5, vec![3]));
+		assert_ok!(CollatorStaking::force_unstake(3));
+		assert_eq!(Candidates::<Test>::get(3), None);
+		assert_eq!(Balances::balance(&3), 100);
+		assert_ok!(CollatorStaking::force_unstake(5));
+		assert_eq!(Stake::<Test>::get(3, 5).stake, 0);
+		assert_eq!(Balances::balance(&5), 75);
+		assert_eq!(
+			<CollatorStaking as StakingInterface>::stake(&5),
+			Ok(sp_staking::Stake { total: 25, active: 0 })
+		);
+	});
+}
 
-		// Total rewards: 25 (accumulated in the pot) + 10 (extra rewards)
-		// 3 (20%) for collators
-		// 13 (80%) for stakers
+#[test]
+fn stake_changes_are_notified() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=4);
 
-		// Reward for collator
-		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
-			staker: 4,
-			amount: 7,
-			session: 1,
-		}));
-		// Reward for staker
-		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
-			staker: 4,
-			amount: 28,
-			session: 1,
-		}));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
+		assert_ok!(CollatorStaking::unstake_amount(RuntimeOrigin::signed(5), 3, 5));
+		assert_ok!(CollatorStaking::move_stake(RuntimeOrigin::signed(5), 3, 4, 15));
+		assert_eq!(CollatorStaking::slash(&4, Perbill::from_percent(20)), 5);
+		assert_eq!(
+			StakeChanges::take(),
+			vec![
+				(5, 3, 0, 20, StakeChangeReason::Staked),
+				(5, 3, 20, 15, StakeChangeReason::Unstaked),
+				(5, 3, 15, 0, StakeChangeReason::Moved),
+				(5, 4, 0, 15, StakeChangeReason::Moved),
+				(5, 4, 15, 12, StakeChangeReason::Slashed),
+			]
+		);
+
+		// Stake deposited in candidates that left is refunded.
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(3), 3, 10));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 10));
+		assert_ok!(CollatorStaking::leave_intent(RuntimeOrigin::signed(3)));
+		finalize_current_block();
+		assert_eq!(
+			StakeChanges::take(),
+			vec![
+				(3, 3, 0, 10, StakeChangeReason::Staked),
+				(5, 3, 0, 10, StakeChangeReason::Staked),
+				(3, 3, 10, 0, StakeChangeReason::CandidateRemoved),
+				(5, 3, 10, 0, StakeChangeReason::Refunded),
+			]
+		);
 	});
 }
 
 #[test]
-fn should_reward_collator_with_extra_rewards_and_no_funds() {
+fn nomination_pool_lifecycle() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(4),));
-		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(4), 4, 10));
-		// This account has no funds
-		ExtraReward::<Test>::put(1);
-		assert_eq!(Balances::balance(&CollatorStaking::account_id()), 0);
-		Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance()).unwrap();
+		initialize_to_block(1);
+		register_candidates(3..=4);
+		assert_noop!(
+			CollatorStaking::create_pool(
+				RuntimeOrigin::signed(5),
+				BoundedVec::truncate_from(vec![]),
+				20
+			),
+			Error::<Test>::NoPoolCandidates
+		);
+		assert_ok!(CollatorStaking::create_pool(
+			RuntimeOrigin::signed(5),
+			BoundedVec::truncate_from(vec![4, 3, 4]),
+			20
+		));
+		let pool = CollatorStaking::pool_account_id(0);
+		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::PoolCreated {
+			pool_id: 0,
+			creator: 5,
+			account: pool,
+		}));
+		assert_eq!(Pools::<Test>::get(0), Some(PoolInfo { creator: 5, points: 20, members: 1 }));
+		assert_eq!(PoolMembers::<Test>::get(0, 5), 20);
+		assert_eq!(RewardDestinations::<Test>::get(pool), RewardDestination::Staked);
+		assert_eq!(SharedFunds::<Test>::get(pool), Some(20));
+		assert_eq!(Stake::<Test>::get(3, pool).stake, 10);
+		assert_eq!(Stake::<Test>::get(4, pool).stake, 10);
+		assert_eq!(Balances::balance(&5), 75);
+
+		// Members can join with less than the minimum stake.
+		fund_account(6);
+		assert_ok!(CollatorStaking::join_pool(RuntimeOrigin::signed(6), 0, 1));
+		assert_eq!(PoolMembers::<Test>::get(0, 6), 1);
+		assert_eq!(Stake::<Test>::get(3, pool).stake, 11);
+		assert_noop!(
+			CollatorStaking::join_pool(RuntimeOrigin::signed(6), 1, 1),
+			Error::<Test>::PoolNotFound
+		);
 
-		assert_eq!(TotalBlocks::<Test>::get(0), (0, 0));
-		assert_eq!(CurrentSession::<Test>::get(), 0);
-		for block in 1..=9 {
-			initialize_to_block(block);
-			assert_eq!(CurrentSession::<Test>::get(), 0);
-			assert_eq!(TotalBlocks::<Test>::get(0), (block as u32, block as u32));
+		assert_ok!(CollatorStaking::join_pool(RuntimeOrigin::signed(1), 0, 10));
+		assert_eq!(PoolMembers::<Test>::get(0, 1), 10);
+		assert_eq!(Pools::<Test>::get(0), Some(PoolInfo { creator: 5, points: 31, members: 3 }));
+		assert_eq!(SharedFunds::<Test>::get(pool), Some(31));
+		assert_eq!(Stake::<Test>::get(3, pool).stake, 16);
+		assert_eq!(Stake::<Test>::get(4, pool).stake, 15);
 
-			// Assume we collected one unit in fees per block
-			assert_ok!(Balances::transfer(&1, &CollatorStaking::account_id(), 1, Preserve));
-			finalize_current_block();
-		}
+		// Unbonding maps onto the unstaking requests of the member.
+		assert_noop!(
+			CollatorStaking::leave_pool(RuntimeOrigin::signed(6), 0, 2),
+			Error::<Test>::InvalidPoolPoints
+		);
+		assert_ok!(CollatorStaking::leave_pool(RuntimeOrigin::signed(6), 0, 1));
+		assert_eq!(PoolMembers::<Test>::get(0, 6), 0);
+		assert_eq!(SharedFunds::<Test>::get(pool), Some(30));
+		assert_eq!(Stake::<Test>::get(4, pool).stake, 14);
 		assert_eq!(
-			Balances::balance(&CollatorStaking::account_id()),
-			Balances::minimum_balance() + 9
+			UnstakingRequests::<Test>::get(6),
+			vec![UnstakeRequest { candidate: 4, block: 3, amount: 1 }]
 		);
-		assert!(!System::events().iter().any(|e| {
-			match e.event {
-				RuntimeEvent::CollatorStaking(Event::StakingRewardReceived { .. }) => true,
-				_ => false,
-			}
+		initialize_to_block(3);
+		assert_ok!(CollatorStaking::claim(RuntimeOrigin::signed(6)));
+		assert_eq!(Balances::balance(&6), 100);
+
+		// The pool is dissolved once all members leave.
+		assert_ok!(CollatorStaking::leave_pool(RuntimeOrigin::signed(5), 0, 20));
+		assert_eq!(Stake::<Test>::get(4, pool).stake, 0);
+		assert_eq!(Stake::<Test>::get(3, pool).stake, 10);
+		assert_eq!(SharedFunds::<Test>::get(pool), Some(10));
+		assert_ok!(CollatorStaking::leave_pool(RuntimeOrigin::signed(1), 0, 10));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::PoolDissolved {
+			pool_id: 0,
 		}));
+		assert_eq!(Pools::<Test>::get(0), None);
+		assert_eq!(SharedFunds::<Test>::get(pool), None);
+		assert_eq!(StakedCandidates::<Test>::get(pool).len(), 0);
+		assert_eq!(RewardDestinations::<Test>::get(pool), RewardDestination::Free);
+		assert_eq!(Balances::total_balance(&pool), 0);
+		assert_eq!(Balances::balance(&5), 80);
+		assert_eq!(CollatorStaking::staker_info(&5).total_held, 20);
+		assert_eq!(CollatorStaking::staker_info(&1).total_held, 10);
+	});
+}
 
-		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 9);
-		initialize_to_block(10);
-		assert_eq!(CurrentSession::<Test>::get(), 1);
-		assert_eq!(TotalBlocks::<Test>::get(1), (1, 1));
+#[test]
+fn nomination_pool_ignores_donations() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=3);
+		assert_ok!(CollatorStaking::create_pool(
+			RuntimeOrigin::signed(5),
+			BoundedVec::truncate_from(vec![3]),
+			2
+		));
+		let pool = CollatorStaking::pool_account_id(0);
+
+		// Balance sent to the pool does not change the value of the points.
+		Balances::mint_into(&pool, 50).unwrap();
+		fund_account(6);
+		assert_ok!(CollatorStaking::join_pool(RuntimeOrigin::signed(6), 0, 10));
+		assert_eq!(PoolMembers::<Test>::get(0, 6), 10);
+		assert_eq!(SharedFunds::<Test>::get(pool), Some(12));
+		assert_eq!(Stake::<Test>::get(3, pool).stake, 12);
+
+		// Nor can it be withdrawn by members leaving.
+		assert_ok!(CollatorStaking::leave_pool(RuntimeOrigin::signed(6), 0, 5));
+		assert_eq!(Balances::balance(&6), 90);
+		assert_eq!(Stake::<Test>::get(3, pool).stake, 7);
+		assert_eq!(
+			UnstakingRequests::<Test>::get(6),
+			vec![UnstakeRequest { candidate: 3, block: 3, amount: 5 }]
+		);
 
-		finalize_current_block();
-		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 0);
+		// The last member to leave gets whatever is left.
+		assert_ok!(CollatorStaking::leave_pool(RuntimeOrigin::signed(5), 0, 2));
+		assert_ok!(CollatorStaking::leave_pool(RuntimeOrigin::signed(6), 0, 5));
+		assert_eq!(Pools::<Test>::get(0), None);
+		assert_eq!(Balances::balance(&6), 140);
+		assert_eq!(CollatorStaking::staker_info(&6).total_held, 10);
+	});
+}
 
-		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
-			staker: 4,
-			amount: 1,
-			session: 0,
-		}));
+#[test]
+fn nomination_pool_withdrawal_near_min_stake() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=3);
+		assert_ok!(CollatorStaking::create_pool(
+			RuntimeOrigin::signed(5),
+			BoundedVec::truncate_from(vec![3]),
+			10
+		));
+		let pool = CollatorStaking::pool_account_id(0);
+		fund_account(6);
+		assert_ok!(CollatorStaking::join_pool(RuntimeOrigin::signed(6), 0, 1));
+		assert_eq!(Stake::<Test>::get(3, pool).stake, 11);
+
+		// Unstaking 10 would leave 1 in the candidate, which is below the minimum stake, so the
+		// whole stake is unstaked and the pool keeps the excess.
+		assert_ok!(CollatorStaking::leave_pool(RuntimeOrigin::signed(5), 0, 10));
+		assert_eq!(Stake::<Test>::get(3, pool).stake, 0);
+		assert_eq!(StakedCandidates::<Test>::get(pool).len(), 0);
+		assert_eq!(
+			UnstakingRequests::<Test>::get(5),
+			vec![
+				UnstakeRequest { candidate: 3, block: 3, amount: 1 },
+				UnstakeRequest { candidate: 3, block: 3, amount: 9 },
+			]
+		);
+		assert_eq!(
+			UnstakingRequests::<Test>::get(pool),
+			vec![UnstakeRequest { candidate: 3, block: 3, amount: 1 }]
+		);
+		assert_eq!(SharedFunds::<Test>::get(pool), Some(1));
 
-		System::reset_events();
+		// Funds the pool is unstaking are handed over to the members leaving.
+		assert_ok!(CollatorStaking::leave_pool(RuntimeOrigin::signed(6), 0, 1));
+		assert_eq!(Pools::<Test>::get(0), None);
+		assert_eq!(UnstakingRequests::<Test>::get(pool), vec![]);
+		assert_eq!(
+			UnstakingRequests::<Test>::get(6),
+			vec![UnstakeRequest { candidate: 3, block: 3, amount: 1 }]
+		);
+		assert_eq!(CandidateUnstakers::<Test>::get(3, pool), None);
+
+		initialize_to_block(3);
+		assert_ok!(CollatorStaking::claim(RuntimeOrigin::signed(5)));
+		assert_ok!(CollatorStaking::claim(RuntimeOrigin::signed(6)));
+		assert_eq!(Balances::balance(&5), 95);
+		assert_eq!(Balances::balance(&6), 100);
+	});
+}
 
-		for block in 10..=19 {
-			initialize_to_block(block);
-			assert_eq!(CurrentSession::<Test>::get(), 1);
-			assert_eq!(TotalBlocks::<Test>::get(1), (block as u32 - 9, block as u32 - 9));
+#[test]
+fn nomination_pool_spreads_withdrawal_across_candidates() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::create_pool(
+			RuntimeOrigin::signed(5),
+			BoundedVec::truncate_from(vec![3, 4]),
+			20
+		));
+		let pool = CollatorStaking::pool_account_id(0);
+		fund_account(6);
+		assert_ok!(CollatorStaking::join_pool(RuntimeOrigin::signed(6), 0, 2));
+		assert_eq!(Stake::<Test>::get(3, pool).stake, 11);
+		assert_eq!(Stake::<Test>::get(4, pool).stake, 11);
+
+		// Candidate 4 keeps the minimum stake and the rest comes from candidate 3.
+		assert_ok!(CollatorStaking::leave_pool(RuntimeOrigin::signed(5), 0, 10));
+		assert_eq!(Stake::<Test>::get(4, pool).stake, 2);
+		assert_eq!(Stake::<Test>::get(3, pool).stake, 10);
+		assert_eq!(SharedFunds::<Test>::get(pool), Some(12));
+		assert_eq!(UnstakingRequests::<Test>::get(pool), vec![]);
+		assert_eq!(CollatorStaking::staker_info(&5).total_held, 10);
+	});
+}
 
-			// Assume we collected one unit in fees per block
-			assert_ok!(Balances::transfer(&1, &CollatorStaking::account_id(), 1, Preserve));
-			finalize_current_block();
-		}
+#[test]
+fn nomination_pool_stakes_released_excess_again() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::create_pool(
+			RuntimeOrigin::signed(5),
+			BoundedVec::truncate_from(vec![3, 4]),
+			4
+		));
+		let pool = CollatorStaking::pool_account_id(0);
 
+		// Both candidates hold the minimum stake, so the whole stake in the last one is unstaked.
+		assert_ok!(CollatorStaking::leave_pool(RuntimeOrigin::signed(5), 0, 1));
+		assert_eq!(Stake::<Test>::get(3, pool).stake, 2);
+		assert_eq!(Stake::<Test>::get(4, pool).stake, 0);
 		assert_eq!(
-			Balances::free_balance(CollatorStaking::account_id()) - Balances::minimum_balance(),
-			18
+			UnstakingRequests::<Test>::get(5),
+			vec![UnstakeRequest { candidate: 4, block: 3, amount: 1 }]
 		);
-		initialize_to_block(20);
-		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::SessionEnded {
-			index: 1,
-			rewards: 18,
-		}));
 		assert_eq!(
-			Balances::free_balance(CollatorStaking::account_id()) - Balances::minimum_balance(),
-			0
+			UnstakingRequests::<Test>::get(pool),
+			vec![UnstakeRequest { candidate: 4, block: 3, amount: 1 }]
 		);
-		assert_eq!(Balances::balance(&CollatorStaking::account_id()), Balances::minimum_balance());
-		assert_eq!(CurrentSession::<Test>::get(), 2);
-		assert_eq!(TotalBlocks::<Test>::get(2), (1, 1));
-
-		finalize_current_block();
-		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 0);
+		assert_eq!(SharedFunds::<Test>::get(pool), Some(3));
 
-		// Total rewards: 10 (from session 1) + 8 (from session 0) = 18
-		// 3 (20%) for collators
-		// 13 (80%) for stakers
+		// Once released, the excess is staked again along with the next deposit.
+		initialize_to_block(3);
+		fund_account(6);
+		assert_ok!(CollatorStaking::join_pool(RuntimeOrigin::signed(6), 0, 3));
+		assert_eq!(PoolMembers::<Test>::get(0, 6), 3);
+		assert_eq!(UnstakingRequests::<Test>::get(pool), vec![]);
+		assert_eq!(Stake::<Test>::get(3, pool).stake, 6);
+		assert_eq!(SharedFunds::<Test>::get(pool), Some(6));
+	});
+}
 
-		// Reward for collator
-		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
-			staker: 4,
-			amount: 3,
-			session: 1,
-		}));
-		// Reward for staker
-		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
-			staker: 4,
-			amount: 15,
-			session: 1,
-		}));
+#[test]
+fn nomination_pool_funds_follow_slashes_and_rewards() {
+	new_test_ext().execute_with(|| {
+		StakerRewardsMode::set(RewardsMode::Pull);
+		initialize_to_block(1);
+		register_candidates(3..=4);
+		LastAuthoredBlock::<Test>::insert(4, 100);
+		assert_ok!(CollatorStaking::create_pool(
+			RuntimeOrigin::signed(5),
+			BoundedVec::truncate_from(vec![3, 4]),
+			40
+		));
+		let pool = CollatorStaking::pool_account_id(0);
+
+		// Slashes reduce the value of the points.
+		CollatorStaking::slash(&3, Perbill::from_percent(50));
+		assert_eq!(Stake::<Test>::get(3, pool).stake, 10);
+		assert_eq!(SharedFunds::<Test>::get(pool), Some(30));
+
+		// Rewards increase it.
+		RewardPerStake::<Test>::insert(1, 4, FixedU128::from_rational(5, 2));
+		UnclaimedRewards::<Test>::insert(1, 50);
+		CurrentSession::<Test>::put(2);
+		Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance() + 50)
+			.unwrap();
+		fund_account(6);
+		assert_ok!(CollatorStaking::join_pool(RuntimeOrigin::signed(6), 0, 10));
+		assert_eq!(PoolMembers::<Test>::get(0, 6), 5);
+		assert_eq!(SharedFunds::<Test>::get(pool), Some(90));
+		assert_eq!(Stake::<Test>::get(3, pool).stake, 15);
+		assert_eq!(Stake::<Test>::get(4, pool).stake, 75);
 	});
 }
 
 #[test]
-fn should_reward_collator_with_extra_rewards_and_many_stakers() {
+fn liquid_staking() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(3),));
-		// only the candidate 4 is going to produce blocks, but we do not want the candidate 3 to be kicked.
-		LastAuthoredBlock::<Test>::insert(3, 100);
-		assert_ok!(CollatorStaking::register_as_candidate(RuntimeOrigin::signed(4),));
-		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(2), 4, 40));
-		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(3), 4, 50));
-		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 91));
+		initialize_to_block(1);
+		register_candidates(3..=4);
+		LiquidStakingAsset::set(None);
+		assert_noop!(
+			CollatorStaking::liquid_stake(RuntimeOrigin::signed(5), 3, 20),
+			Error::<Test>::LiquidStakingDisabled
+		);
+		LiquidStakingAsset::set(Some(LIQUID_STAKING_ASSET));
+
+		// The first staker pays the existential deposit and gets the derivative at par.
+		assert_ok!(CollatorStaking::liquid_stake(RuntimeOrigin::signed(5), 3, 20));
+		let account = CollatorStaking::liquid_staking_account_id();
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::LiquidStaked {
+			staker: 5,
+			candidate: 3,
+			amount: 20,
+			minted: 20,
+		}));
+		assert_eq!(Assets::balance(LIQUID_STAKING_ASSET, 5), 20);
+		assert_eq!(RewardDestinations::<Test>::get(account), RewardDestination::Staked);
+		assert_eq!(SharedFunds::<Test>::get(account), Some(20));
+		assert_eq!(Stake::<Test>::get(3, account).stake, 20);
+		assert_eq!(Balances::balance(&5), 75);
+
+		// Balance sent to the account does not change the exchange rate.
+		Balances::mint_into(&account, 20).unwrap();
+		assert_ok!(CollatorStaking::liquid_stake(RuntimeOrigin::signed(1), 4, 10));
+		assert_eq!(Assets::balance(LIQUID_STAKING_ASSET, 1), 10);
+		assert_eq!(Assets::total_supply(LIQUID_STAKING_ASSET), 30);
+		assert_eq!(SharedFunds::<Test>::get(account), Some(30));
+		assert_eq!(Stake::<Test>::get(4, account).stake, 10);
+
+		// Funds go through the unstaking requests of the staker.
+		assert_noop!(
+			CollatorStaking::liquid_unstake(RuntimeOrigin::signed(5), 0),
+			Error::<Test>::InvalidUnstakeAmount
+		);
+		assert_ok!(CollatorStaking::liquid_unstake(RuntimeOrigin::signed(5), 10));
+		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::LiquidUnstaked {
+			staker: 5,
+			amount: 10,
+			burned: 10,
+		}));
+		assert_eq!(Balances::balance(&5), 75);
+		assert_eq!(Stake::<Test>::get(4, account).stake, 0);
+		assert_eq!(
+			UnstakingRequests::<Test>::get(5),
+			vec![UnstakeRequest { candidate: 4, block: 3, amount: 10 }]
+		);
+		assert_ok!(CollatorStaking::liquid_unstake(RuntimeOrigin::signed(5), 9));
+		assert_eq!(Assets::balance(LIQUID_STAKING_ASSET, 5), 1);
+		assert_eq!(Stake::<Test>::get(3, account).stake, 11);
+		assert_eq!(SharedFunds::<Test>::get(account), Some(11));
+
+		// Stake left in a candidate never falls below the minimum stake.
+		assert_ok!(CollatorStaking::liquid_unstake(RuntimeOrigin::signed(1), 10));
+		assert_eq!(Stake::<Test>::get(3, account).stake, 0);
 		assert_eq!(
-			CandidateList::<Test>::get(),
+			UnstakingRequests::<Test>::get(1),
 			vec![
-				CandidateInfo { who: 4, stake: 90, stakers: 2, deposit: 10 },
-				CandidateInfo { who: 3, stake: 91, stakers: 1, deposit: 10 }
+				UnstakeRequest { candidate: 3, block: 3, amount: 1 },
+				UnstakeRequest { candidate: 3, block: 3, amount: 9 },
 			]
 		);
-
-		// Staker 3 will autocompound 40% of its earnings
-		AutoCompound::<Test>::insert(3, Percent::from_parts(40));
-		ExtraReward::<Test>::put(1);
-		assert_eq!(Balances::balance(&CollatorStaking::account_id()), 0);
-		Balances::mint_into(&CollatorStaking::account_id(), Balances::minimum_balance()).unwrap();
-		fund_account(CollatorStaking::extra_reward_account_id());
-
-		assert_eq!(TotalBlocks::<Test>::get(0), (0, 0));
-		assert_eq!(CurrentSession::<Test>::get(), 0);
-		for block in 1..=9 {
-			initialize_to_block(block);
-			assert_eq!(CurrentSession::<Test>::get(), 0);
-			assert_eq!(TotalBlocks::<Test>::get(0), (block as u32, block as u32));
-
-			// Assume we collected one unit in fees per block
-			assert_ok!(Balances::transfer(&1, &CollatorStaking::account_id(), 1, Preserve));
-			finalize_current_block();
-		}
 		assert_eq!(
-			Balances::balance(&CollatorStaking::account_id()),
-			Balances::minimum_balance() + 9
+			UnstakingRequests::<Test>::get(account),
+			vec![UnstakeRequest { candidate: 3, block: 3, amount: 1 }]
 		);
-		assert!(!System::events().iter().any(|e| {
-			match e.event {
-				RuntimeEvent::CollatorStaking(Event::StakingRewardReceived { .. }) => true,
-				_ => false,
-			}
-		}));
+		assert_eq!(SharedFunds::<Test>::get(account), Some(1));
+
+		// Burning the whole supply withdraws all the funds.
+		assert_ok!(CollatorStaking::liquid_unstake(RuntimeOrigin::signed(5), 1));
+		assert_eq!(Assets::total_supply(LIQUID_STAKING_ASSET), 0);
+		assert_eq!(SharedFunds::<Test>::get(account), None);
+		assert_eq!(StakedCandidates::<Test>::get(account).len(), 0);
+		assert_eq!(UnstakingRequests::<Test>::get(account), vec![]);
+		assert_eq!(Balances::balance(&5), 95);
+		initialize_to_block(3);
+		assert_ok!(CollatorStaking::claim(RuntimeOrigin::signed(1)));
+		assert_ok!(CollatorStaking::claim(RuntimeOrigin::signed(5)));
+		assert_eq!(Balances::balance(&1), 100);
+		assert_eq!(Balances::balance(&5), 115);
+	});
+}
 
-		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 9);
-		initialize_to_block(10);
-		assert_eq!(CurrentSession::<Test>::get(), 1);
-		assert_eq!(TotalBlocks::<Test>::get(1), (1, 1));
-		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 0);
+#[test]
+fn no_assets_disable_liquid_staking() {
+	use frame_support::traits::fungibles::{Inspect, Mutate};
+	type NoAssets = crate::NoAssets<u64>;
 
-		// Reward for collator
-		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
-			staker: 4,
-			amount: 3,
-			session: 0,
-		}));
+	new_test_ext().execute_with(|| {
+		assert!(!<NoAssets as Inspect<u64>>::asset_exists(()));
+		assert_eq!(<NoAssets as Inspect<u64>>::total_issuance(()), 0);
+		assert_eq!(<NoAssets as Inspect<u64>>::balance((), &1), 0);
+		assert_eq!(
+			<NoAssets as Mutate<u64>>::mint_into((), &1, 10),
+			Err(TokenError::UnknownAsset.into())
+		);
+	});
+}
 
-		System::reset_events();
+#[test]
+fn migrate_to_v2() {
+	use crate::migrations::{v2, v4};
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
 
-		for block in 10..=19 {
-			initialize_to_block(block);
-			assert_eq!(CurrentSession::<Test>::get(), 1);
-			assert_eq!(TotalBlocks::<Test>::get(1), (block as u32 - 9, block as u32 - 9));
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<CollatorStaking>();
+		let old_candidates = vec![
+			v2::CandidateInfo { who: 3, stake: 10, deposit: 10, stakers: 1 },
+			v2::CandidateInfo { who: 4, stake: 20, deposit: 10, stakers: 2 },
+		];
+		frame_support::storage::unhashed::put(
+			&v4::CandidateList::<Test>::hashed_key(),
+			&old_candidates,
+		);
+		v2::CollatorRewardPercentage::<Test>::put(Percent::from_parts(15));
 
-			// Assume we collected one unit in fees per block
-			assert_ok!(Balances::transfer(&1, &CollatorStaking::account_id(), 1, Preserve));
-			finalize_current_block();
-		}
+		v2::MigrateToV2::<Test>::on_runtime_upgrade();
 
+		assert_eq!(StorageVersion::get::<CollatorStaking>(), 2);
+		assert_eq!(MaxCommission::<Test>::get(), Percent::from_parts(15));
+		assert!(!v2::CollatorRewardPercentage::<Test>::exists());
 		assert_eq!(
-			Balances::free_balance(CollatorStaking::account_id()) - Balances::minimum_balance(),
-			25
+			v4::CandidateList::<Test>::get(),
+			vec![
+				CandidateInfo {
+					who: 3,
+					stake: 10,
+					stakers: 1,
+					deposit: 10,
+					commission: Percent::from_parts(15)
+				},
+				CandidateInfo {
+					who: 4,
+					stake: 20,
+					stakers: 2,
+					deposit: 10,
+					commission: Percent::from_parts(15)
+				},
+			]
 		);
-		initialize_to_block(20);
-		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::SessionEnded {
-			index: 1,
-			rewards: 35,
-		}));
-		assert_eq!(
-			Balances::free_balance(CollatorStaking::account_id()) - Balances::minimum_balance(),
-			1
-		); // rounding
-		assert_eq!(CurrentSession::<Test>::get(), 2);
-		assert_eq!(TotalBlocks::<Test>::get(2), (1, 1));
-		assert_eq!(ProducedBlocks::<Test>::get(0, 4), 0);
 
-		// we need one more block to distribute rewards among the two collators
-		initialize_to_block(21);
+		// Running it again is a no-op.
+		v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<CollatorStaking>(), 2);
+	});
+}
 
-		// Total rewards: 25 (accumulated) + 10 (extra rewards) = 35
-		// 7 (20%) for collators
-		//  - Staker 4: 7
-		// 28 (80%) for stakers
-		//  - Staker 2 -> 44.4% = 12
-		//  - Staker 3 -> 55.5% = 15
+#[test]
+fn migrate_to_v3() {
+	use crate::migrations::v3;
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
 
-		// Reward for collator
-		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
-			staker: 4,
-			amount: 7,
-			session: 1,
-		}));
-		// Reward for stakers
-		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
-			staker: 2,
-			amount: 12,
-			session: 1,
-		}));
-		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakingRewardReceived {
-			staker: 3,
-			amount: 15,
-			session: 1,
-		}));
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(2).put::<CollatorStaking>();
+		let old_requests = vec![
+			v3::UnstakeRequest { block: 3u64, amount: 10u64 },
+			v3::UnstakeRequest { block: 6, amount: 20 },
+		];
+		frame_support::storage::unhashed::put(
+			&UnstakingRequests::<Test>::hashed_key_for(5),
+			&old_requests,
+		);
 
-		// Check that staker 3 added 40% of its earnings via autocompound.
-		System::assert_has_event(RuntimeEvent::CollatorStaking(Event::StakeAdded {
-			staker: 3,
-			candidate: 4,
-			amount: 6,
-		}));
+		v3::MigrateToV3::<Test>::on_runtime_upgrade();
 
-		// Check after adding the stake via autocompound the candidate list is sorted.
+		assert_eq!(StorageVersion::get::<CollatorStaking>(), 3);
 		assert_eq!(
-			CandidateList::<Test>::get(),
+			UnstakingRequests::<Test>::get(5),
 			vec![
-				CandidateInfo { who: 3, stake: 91, stakers: 1, deposit: 10 },
-				CandidateInfo { who: 4, stake: 96, stakers: 2, deposit: 10 },
+				UnstakeRequest { candidate: 5, block: 3, amount: 10 },
+				UnstakeRequest { candidate: 5, block: 6, amount: 20 }
 			]
 		);
+		assert_eq!(CandidateUnstakers::<Test>::get(5, 5), Some(2));
+		assert_eq!(CandidateUnstakeRequestCount::<Test>::get(5), 2);
 	});
 }
 
 #[test]
-fn stop_extra_reward() {
+fn migrate_to_v4() {
+	use crate::migrations::v4;
+	use crate::{CandidateListNodes, ListNode};
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
 	new_test_ext().execute_with(|| {
-		initialize_to_block(1);
+		StorageVersion::new(3).put::<CollatorStaking>();
+		let old_candidates = vec![
+			CandidateInfo { who: 3, stake: 10, deposit: 10, stakers: 1, commission: COMMISSION },
+			CandidateInfo { who: 5, stake: 20, deposit: 10, stakers: 2, commission: COMMISSION },
+			CandidateInfo { who: 4, stake: 20, deposit: 10, stakers: 1, commission: COMMISSION },
+		];
+		v4::CandidateList::<Test>::put(
+			frame_support::BoundedVec::try_from(old_candidates.clone()).unwrap(),
+		);
 
-		fund_account(CollatorStaking::extra_reward_account_id());
-		assert_eq!(ExtraReward::<Test>::get(), 0);
+		v4::MigrateToV4::<Test>::on_runtime_upgrade();
 
-		// Cannot stop if already zero
-		assert_noop!(
-			CollatorStaking::stop_extra_reward(RuntimeOrigin::signed(RootAccount::get())),
-			Error::<Test>::ExtraRewardAlreadyDisabled
+		assert_eq!(StorageVersion::get::<CollatorStaking>(), 4);
+		assert!(!v4::CandidateList::<Test>::exists());
+		assert_eq!(Candidates::<Test>::count(), 3);
+		assert_eq!(Candidates::<Test>::get(5), Some(old_candidates[1].clone()));
+		assert_eq!(CandidateListHead::<Test>::get(), Some(3));
+		assert_eq!(CandidateListTail::<Test>::get(), Some(4));
+		assert_eq!(
+			CandidateListNodes::<Test>::get(5),
+			Some(ListNode { prev: Some(3), next: Some(4) })
 		);
+		assert_eq!(CollatorStaking::candidate_list(), old_candidates);
 
-		// Now we can stop it
-		assert_ok!(CollatorStaking::set_extra_reward(RuntimeOrigin::signed(RootAccount::get()), 2));
-		assert_ok!(CollatorStaking::stop_extra_reward(RuntimeOrigin::signed(RootAccount::get())));
-
-		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::ExtraRewardRemoved {
-			amount_left: 100,
-			receiver: Some(40),
-		}));
-		assert_eq!(ExtraReward::<Test>::get(), 0);
+		// Running it again is a no-op.
+		v4::MigrateToV4::<Test>::on_runtime_upgrade();
+		assert_eq!(Candidates::<Test>::count(), 3);
 	});
 }
 
 #[test]
-fn top_up_extra_rewards() {
+fn migrate_to_v5() {
+	use crate::migrations::v5;
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
 	new_test_ext().execute_with(|| {
 		initialize_to_block(1);
+		register_candidates(3..=4);
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 3, 20));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(5), 4, 10));
+		assert_ok!(CollatorStaking::stake(RuntimeOrigin::signed(1), 4, 10));
+		assert_ok!(CollatorStaking::unstake_amount(RuntimeOrigin::signed(1), 4, 5));
+		assert_ok!(<CollatorStaking as StakingInterface>::bond(&2, 10, &2));
+		StakedCandidates::<Test>::remove(5);
+		StakedCandidates::<Test>::remove(1);
+		TotalStaked::<Test>::kill();
+		v5::StakeCount::<Test>::insert(5, 2);
+		v5::StakeCount::<Test>::insert(1, 1);
+		StorageVersion::new(4).put::<CollatorStaking>();
+
+		v5::MigrateToV5::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<CollatorStaking>(), 5);
+		assert_eq!(v5::StakeCount::<Test>::get(5), 0);
+		assert_eq!(v5::StakeCount::<Test>::get(1), 0);
+		let mut candidates = StakedCandidates::<Test>::get(5).into_inner();
+		candidates.sort();
+		assert_eq!(candidates, vec![3, 4]);
+		assert_eq!(StakedCandidates::<Test>::get(1), vec![4]);
+		// Bonds, stake, unstaking requests and idle stake.
+		assert_eq!(TotalStaked::<Test>::get(), 20 + 35 + 5 + 10);
+
+		// Running it again is a no-op.
+		TotalStaked::<Test>::put(1);
+		v5::MigrateToV5::<Test>::on_runtime_upgrade();
+		assert_eq!(StakedCandidates::<Test>::get(1), vec![4]);
+		assert_eq!(TotalStaked::<Test>::get(), 1);
+	});
+}
 
-		assert_eq!(Balances::balance(&CollatorStaking::extra_reward_account_id()), 0);
-
-		// Cannot fund with an amount equal to zero.
-		assert_noop!(
-			CollatorStaking::top_up_extra_rewards(RuntimeOrigin::signed(1), 0),
-			Error::<Test>::InvalidFundingAmount
-		);
+#[test]
+fn migrate_to_v5_worst_case_weight() {
+	use crate::migrations::v5;
+	use frame_support::{
+		traits::{OnRuntimeUpgrade, StorageVersion},
+		weights::RuntimeDbWeight,
+	};
 
-		// Cannot fund if total balance less than ED.
-		assert_noop!(
-			CollatorStaking::top_up_extra_rewards(RuntimeOrigin::signed(1), 1),
-			TokenError::BelowMinimum
-		);
+	new_test_ext().execute_with(|| {
+		DbWeight::set(RuntimeDbWeight { read: 1, write: 10 });
+		assert_eq!(Candidates::<Test>::count(), 0);
+
+		// Every candidate has `MaxStakers` stakers, and stakers back as many candidates as
+		// `MaxStakedCandidates` allows.
+		let max_candidates: u64 = <Test as Config>::MaxCandidates::get().into();
+		let max_stakers: u64 = <Test as Config>::MaxStakers::get().into();
+		let max_staked: u64 = <Test as Config>::MaxStakedCandidates::get().into();
+		let entries = max_candidates * max_stakers;
+		let stakers = entries.div_ceil(max_staked);
+		for index in 0..entries {
+			let candidate = 1000 + index / max_stakers;
+			let staker = 2000 + index % stakers;
+			Stake::<Test>::insert(candidate, staker, StakeInfo { stake: 10, session: 0 });
+		}
+		StorageVersion::new(4).put::<CollatorStaking>();
 
-		// Now we can top it up.
-		assert_ok!(CollatorStaking::top_up_extra_rewards(RuntimeOrigin::signed(1), 10));
+		let weight = v5::MigrateToV5::<Test>::on_runtime_upgrade();
 
-		System::assert_last_event(RuntimeEvent::CollatorStaking(Event::ExtraRewardPotFunded {
-			pot: CollatorStaking::extra_reward_account_id(),
-			amount: 10,
-		}));
-		assert_eq!(Balances::balance(&CollatorStaking::extra_reward_account_id()), 10);
+		// One read per stake entry plus the storage version, and one write per stake entry plus
+		// the total staked and the storage version.
+		assert_eq!(weight, Weight::from_parts(entries + 1 + (entries + 2) * 10, 0));
+		assert_eq!(
+			StakedCandidates::<Test>::iter_values().map(|c| c.len() as u64).sum::<u64>(),
+			entries
+		);
+		assert_eq!(TotalStaked::<Test>::get(), entries * 10);
 	});
 }
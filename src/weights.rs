@@ -6,6 +6,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `blockdeep-ref-hw`, CPU: `AMD EPYC 7232P 8-Core Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("local-v")`, DB CACHE: `1024`
+//!
+//! NOTE: the weights of the calls and hooks added or changed after this run were written by hand
+//! and are marked as placeholders below. They were not measured, and must be replaced by running
+//! the benchmarks again on the reference hardware.
 
 // Executed Command:
 // ./target/release/mythos-node
@@ -53,13 +57,40 @@ pub trait WeightInfo {
 	fn unstake_all(c: u32, s: u32, ) -> Weight;
 	fn claim(c: u32, ) -> Weight;
 	fn set_autocompound_percentage() -> Weight;
-	fn set_collator_reward_percentage() -> Weight;
+	fn set_max_commission() -> Weight;
 	fn set_extra_reward() -> Weight;
 	fn set_minimum_stake() -> Weight;
 	fn stop_extra_reward() -> Weight;
 	fn top_up_extra_rewards() -> Weight;
-	fn reward_one_collator(c: u32, s: u32, a: u32, ) -> Weight;
+	fn reward_one_collator(c: u32, ) -> Weight;
+	fn reward_stakers_page(s: u32, a: u32, ) -> Weight;
 	fn refund_stakers(s: u32, ) -> Weight;
+	fn unstake_amount(c: u32, u: u32, ) -> Weight;
+	fn rebond(c: u32, u: u32, ) -> Weight;
+	fn set_commission(c: u32, ) -> Weight;
+	fn slash_candidate(c: u32, ) -> Weight;
+	fn cancel_deferred_slash(s: u32, ) -> Weight;
+	fn apply_slash(s: u32, u: u32, ) -> Weight;
+	fn claim_rewards(s: u32, ) -> Weight;
+	fn move_stake(c: u32, ) -> Weight;
+	fn stake_for(c: u32, ) -> Weight;
+	fn unstake_for(c: u32, u: u32, ) -> Weight;
+	fn set_reward_destination() -> Weight;
+	fn set_minimum_self_stake() -> Weight;
+	fn update_candidacy_bond() -> Weight;
+	fn set_max_candidate_stake(c: u32, ) -> Weight;
+	fn set_lock_tiers(t: u32, ) -> Weight;
+	fn stake_with_lock(c: u32, ) -> Weight;
+	fn unlock_stake() -> Weight;
+	fn create_pool(c: u32, ) -> Weight;
+	fn join_pool(c: u32, ) -> Weight;
+	fn leave_pool(c: u32, ) -> Weight;
+	fn liquid_stake(c: u32, ) -> Weight;
+	fn liquid_unstake(c: u32, ) -> Weight;
+	fn kick_stale_candidate() -> Weight;
+	fn provide_rewards_extra_pot() -> Weight;
+	fn provide_rewards_fixed_mint() -> Weight;
+	fn provide_rewards_inflation() -> Weight;
 }
 
 /// Weights for `pallet_collator_staking` using the Substrate node and recommended hardware.
@@ -86,8 +117,8 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:1)
 	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:1)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Stake` (r:1 w:0)
 	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -99,32 +130,23 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 	/// The range of component `b` is `[1, 19]`.
 	/// The range of component `c` is `[1, 99]`.
 	fn add_invulnerable(b: u32, c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `890 + b * (20 ±0) + c * (65 ±0)`
-		//  Estimated: `7087 + b * (40 ±1) + c * (62 ±0)`
-		// Minimum execution time: 97_851_000 picoseconds.
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
 		Weight::from_parts(97_465_122, 7087)
-			// Standard Error: 8_920
 			.saturating_add(Weight::from_parts(171_645, 0).saturating_mul(b.into()))
-			// Standard Error: 1_690
 			.saturating_add(Weight::from_parts(106_330, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 			.saturating_add(Weight::from_parts(0, 40).saturating_mul(b.into()))
 			.saturating_add(Weight::from_parts(0, 62).saturating_mul(c.into()))
 	}
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:0)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:1)
 	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
 	/// The range of component `b` is `[2, 20]`.
 	fn remove_invulnerable(b: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `252 + b * (20 ±0)`
-		//  Estimated: `7087`
-		// Minimum execution time: 18_430_000 picoseconds.
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
 		Weight::from_parts(19_212_060, 7087)
-			// Standard Error: 2_262
 			.saturating_add(Weight::from_parts(91_966, 0).saturating_mul(b.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
@@ -141,50 +163,60 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 	}
 	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
 	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::CandidacyBond` (r:0 w:1)
+	/// Storage: `CollatorSelection::CandidacyBond` (r:1 w:1)
 	/// Proof: `CollatorSelection::CandidacyBond` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidacyBondGraceEnd` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidacyBondGraceEnd` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn set_candidacy_bond() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `227`
-		//  Estimated: `1501`
-		// Minimum execution time: 12_600_000 picoseconds.
-		Weight::from_parts(13_190_000, 1501)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(15_731_000, 1501)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:1)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:0)
 	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
 	/// Storage: `Session::NextKeys` (r:1 w:0)
 	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `CollatorSelection::CandidacyBond` (r:1 w:0)
 	/// Proof: `CollatorSelection::CandidacyBond` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::Stake` (r:1 w:0)
+	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
 	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinSelfStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinSelfStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:99 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CurrentSession` (r:1 w:0)
+	/// Proof: `CollatorSelection::CurrentSession` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::PendingExCandidates` (r:0 w:1)
 	/// Proof: `CollatorSelection::PendingExCandidates` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:1)
 	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 99]`.
 	fn register_as_candidate(c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1310 + c * (61 ±0)`
-		//  Estimated: `7087 + c * (64 ±0)`
-		// Minimum execution time: 97_791_000 picoseconds.
-		Weight::from_parts(105_943_583, 7087)
-			// Standard Error: 3_176
-			.saturating_add(Weight::from_parts(144_117, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
-			.saturating_add(Weight::from_parts(0, 64).saturating_mul(c.into()))
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(178_512_344, 7087)
+			.saturating_add(Weight::from_parts(301_877, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(c.into()))
 	}
 	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:0)
 	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:1)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// Storage: `Session::NextKeys` (r:1 w:0)
 	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `CollatorSelection::Stake` (r:3 w:1)
@@ -195,8 +227,8 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::CandidacyBond` (r:1 w:0)
 	/// Proof: `CollatorSelection::CandidacyBond` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::StakeCount` (r:1 w:1)
-	/// Proof: `CollatorSelection::StakeCount` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
 	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::CurrentSession` (r:1 w:0)
@@ -205,17 +237,18 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 	/// Proof: `CollatorSelection::PendingExCandidates` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:2)
 	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinSelfStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinSelfStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn take_candidate_slot() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `7507`
-		//  Estimated: `10972`
-		// Minimum execution time: 269_452_000 picoseconds.
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
 		Weight::from_parts(271_451_000, 10972)
-			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads(15_u64))
 			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:1)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:0)
 	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Stake` (r:1 w:0)
@@ -228,12 +261,8 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 100]`.
 	fn leave_intent(c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `351 + c * (56 ±0)`
-		//  Estimated: `7087`
-		// Minimum execution time: 36_481_000 picoseconds.
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
 		Weight::from_parts(39_243_499, 7087)
-			// Standard Error: 1_791
 			.saturating_add(Weight::from_parts(84_121, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
@@ -257,8 +286,8 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:0)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::CandidacyBond` (r:1 w:0)
 	/// Proof: `CollatorSelection::CandidacyBond` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:100 w:0)
@@ -269,30 +298,34 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 	/// Proof: `CollatorSelection::DesiredCandidates` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Stake` (r:97 w:0)
 	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::UnstakingRequests` (r:97 w:97)
-	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::PendingExCandidates` (r:0 w:97)
-	/// Proof: `CollatorSelection::PendingExCandidates` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StaleCandidates` (r:197 w:97)
+	/// Proof: `CollatorSelection::StaleCandidates` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CounterForStaleCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::CounterForStaleCandidates` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinSelfStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinSelfStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidacyBondGraceEnd` (r:1 w:0)
+	/// Proof: `CollatorSelection::CandidacyBondGraceEnd` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 100]`.
 	/// The range of component `c` is `[1, 100]`.
 	fn new_session(r: u32, c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `363 + c * (92 ±0)`
-		//  Estimated: `7087 + c * (2507 ±0) + r * (2832 ±0)`
-		// Minimum execution time: 32_040_000 picoseconds.
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
 		Weight::from_parts(32_511_000, 7087)
-			// Standard Error: 309_600
 			.saturating_add(Weight::from_parts(15_326_198, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 2507).saturating_mul(c.into()))
-			.saturating_add(Weight::from_parts(0, 2832).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 2524).saturating_mul(r.into()))
 	}
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:1)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::StakeCount` (r:1 w:1)
-	/// Proof: `CollatorSelection::StakeCount` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
 	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
@@ -301,60 +334,64 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 	/// Proof: `CollatorSelection::CurrentSession` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
-	/// The range of component `c` is `[1, 100]`.
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 99]`.
 	fn stake(c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `744 + c * (63 ±0)`
-		//  Estimated: `7087`
-		// Minimum execution time: 91_710_000 picoseconds.
-		Weight::from_parts(97_706_167, 7087)
-			// Standard Error: 2_866
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(97_706_167, 3582)
 			.saturating_add(Weight::from_parts(175_221, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(c.into()))
 	}
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:1)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
 	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::UnstakingRequests` (r:1 w:1)
 	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::StakeCount` (r:1 w:1)
-	/// Proof: `CollatorSelection::StakeCount` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
-	/// The range of component `c` is `[1, 100]`.
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinSelfStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinSelfStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 99]`.
 	/// The range of component `u` is `[0, 15]`.
 	fn unstake_from(c: u32, u: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1190 + c * (64 ±0) + u * (20 ±0)`
-		//  Estimated: `7087`
-		// Minimum execution time: 44_140_000 picoseconds.
-		Weight::from_parts(48_537_795, 7087)
-			// Standard Error: 1_519
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(48_537_795, 3582)
 			.saturating_add(Weight::from_parts(177_672, 0).saturating_mul(c.into()))
-			// Standard Error: 9_663
 			.saturating_add(Weight::from_parts(100_544, 0).saturating_mul(u.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(c.into()))
 	}
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:1)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::Stake` (r:17 w:16)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:16 w:16)
 	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::UnstakingRequests` (r:1 w:1)
 	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::StakeCount` (r:1 w:1)
-	/// Proof: `CollatorSelection::StakeCount` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[16, 100]`.
 	/// The range of component `s` is `[1, 16]`.
 	fn unstake_all(c: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `369 + c * (56 ±0) + s * (99 ±0)`
-		//  Estimated: `7087 + s * (2567 ±0)`
-		// Minimum execution time: 75_790_000 picoseconds.
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
 		Weight::from_parts(77_041_000, 7087)
-			// Standard Error: 9_923
 			.saturating_add(Weight::from_parts(14_772, 0).saturating_mul(c.into()))
-			// Standard Error: 63_513
 			.saturating_add(Weight::from_parts(24_058_170, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
@@ -388,13 +425,10 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 		Weight::from_parts(11_000_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: `CollatorSelection::CollatorRewardPercentage` (r:0 w:1)
-	/// Proof: `CollatorSelection::CollatorRewardPercentage` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
-	fn set_collator_reward_percentage() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 7_530_000 picoseconds.
+	/// Storage: `CollatorSelection::MaxCommission` (r:0 w:1)
+	/// Proof: `CollatorSelection::MaxCommission` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_max_commission() -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
 		Weight::from_parts(7_780_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -447,41 +481,57 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 	}
 	/// Storage: `CollatorSelection::ProducedBlocks` (r:2 w:1)
 	/// Proof: `CollatorSelection::ProducedBlocks` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:1)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::TotalBlocks` (r:1 w:0)
 	/// Proof: `CollatorSelection::TotalBlocks` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Rewards` (r:1 w:0)
 	/// Proof: `CollatorSelection::Rewards` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::CollatorRewardPercentage` (r:1 w:0)
-	/// Proof: `CollatorSelection::CollatorRewardPercentage` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:202 w:202)
+	/// Storage: `CollatorSelection::CommissionChangeRequests` (r:1 w:0)
+	/// Proof: `CollatorSelection::CommissionChangeRequests` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCommission` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCommission` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::Stake` (r:201 w:200)
+	/// Storage: `CollatorSelection::CurrentRewardCursor` (r:0 w:1)
+	/// Proof: `CollatorSelection::CurrentRewardCursor` (`max_values`: Some(1), `max_size`: Some(117), added: 612, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateLockBonus` (r:1 w:0)
+	/// Proof: `CollatorSelection::CandidateLockBonus` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 16]`.
+	fn reward_one_collator(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(72_436_208, 7087)
+			.saturating_add(Weight::from_parts(213_559, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `CollatorSelection::CurrentRewardCursor` (r:1 w:1)
+	/// Proof: `CollatorSelection::CurrentRewardCursor` (`max_values`: Some(1), `max_size`: Some(117), added: 612, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:65 w:65)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:65 w:64)
 	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::AutoCompound` (r:200 w:0)
+	/// Storage: `CollatorSelection::AutoCompound` (r:64 w:0)
 	/// Proof: `CollatorSelection::AutoCompound` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::StakeCount` (r:200 w:0)
-	/// Proof: `CollatorSelection::StakeCount` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:64 w:0)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
 	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Holds` (r:200 w:200)
+	/// Storage: `Balances::Holds` (r:64 w:64)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
-	/// The range of component `c` is `[1, 16]`.
-	/// The range of component `s` is `[0, 200]`.
+	/// Storage: `CollatorSelection::StakeLocks` (r:64 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 64]`.
 	/// The range of component `a` is `[0, 100]`.
-	fn reward_one_collator(_c: u32, s: u32, a: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0 + a * (206 ±0) + c * (123 ±0) + s * (333 ±0)`
-		//  Estimated: `7087 + a * (3144 ±37) + s * (2591 ±19)`
-		// Minimum execution time: 72_350_000 picoseconds.
-		Weight::from_parts(73_011_000, 7087)
-			// Standard Error: 523_043
-			.saturating_add(Weight::from_parts(105_947_006, 0).saturating_mul(s.into()))
-			// Standard Error: 1_045_111
-			.saturating_add(Weight::from_parts(84_752_927, 0).saturating_mul(a.into()))
-			.saturating_add(T::DbWeight::get().reads(8_u64))
-			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(s.into())))
+	fn reward_stakers_page(s: u32, a: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(30_202_000, 7087)
+			.saturating_add(Weight::from_parts(104_318_255, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(84_106_331, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(s.into())))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
@@ -495,22 +545,596 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:200 w:200)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::StakeCount` (r:200 w:200)
-	/// Proof: `CollatorSelection::StakeCount` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:200 w:200)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[0, 200]`.
 	fn refund_stakers(s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `183 + s * (290 ±0)`
-		//  Estimated: `3557 + s * (2591 ±0)`
-		// Minimum execution time: 12_580_000 picoseconds.
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
 		Weight::from_parts(12_780_000, 3557)
-			// Standard Error: 26_585
 			.saturating_add(Weight::from_parts(66_185_690, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(s.into())))
 			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(s.into())))
 			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(s.into()))
 	}
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnstakingRequests` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinSelfStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinSelfStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 99]`.
+	/// The range of component `u` is `[0, 15]`.
+	fn unstake_amount(c: u32, u: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(49_312_420, 3582)
+			.saturating_add(Weight::from_parts(178_104, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(101_287, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnstakingRequests` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CurrentSession` (r:1 w:0)
+	/// Proof: `CollatorSelection::CurrentSession` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 99]`.
+	/// The range of component `u` is `[1, 16]`.
+	fn rebond(c: u32, u: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(64_103_871, 3582)
+			.saturating_add(Weight::from_parts(176_930, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(197_415, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::MaxCommission` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCommission` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CommissionChangeRequests` (r:0 w:1)
+	/// Proof: `CollatorSelection::CommissionChangeRequests` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 100]`.
+	fn set_commission(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(18_602_115, 7087)
+			.saturating_add(Weight::from_parts(131_476, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CurrentSession` (r:1 w:0)
+	/// Proof: `CollatorSelection::CurrentSession` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnappliedSlashes` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnappliedSlashes` (`max_values`: None, `max_size`: Some(3622), added: 6097, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 100]`.
+	fn slash_candidate(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(20_316_402, 7087)
+			.saturating_add(Weight::from_parts(142_718, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::UnappliedSlashes` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnappliedSlashes` (`max_values`: None, `max_size`: Some(3622), added: 6097, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 100]`.
+	fn cancel_deferred_slash(s: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(14_027_556, 7087)
+			.saturating_add(Weight::from_parts(61_932, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:201 w:201)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:201 w:201)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:201 w:200)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateUnstakers` (r:3201 w:3200)
+	/// Proof: `CollatorSelection::CandidateUnstakers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateUnstakeRequestCount` (r:1 w:1)
+	/// Proof: `CollatorSelection::CandidateUnstakeRequestCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnstakingRequests` (r:3200 w:3200)
+	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 200]`.
+	/// The range of component `u` is `[0, 3200]`.
+	fn apply_slash(s: u32, u: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(73_048_217, 7087)
+			.saturating_add(Weight::from_parts(104_381_602, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(98_000_000, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(u.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(u.into())))
+			.saturating_add(Weight::from_parts(0, 3581).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(0, 3581).saturating_mul(u.into()))
+	}
+	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CurrentSession` (r:1 w:0)
+	/// Proof: `CollatorSelection::CurrentSession` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::ProducedBlocks` (r:1 w:0)
+	/// Proof: `CollatorSelection::ProducedBlocks` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::LastClaimedSession` (r:1 w:1)
+	/// Proof: `CollatorSelection::LastClaimedSession` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::RewardPerStake` (r:10 w:0)
+	/// Proof: `CollatorSelection::RewardPerStake` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnclaimedRewards` (r:10 w:10)
+	/// Proof: `CollatorSelection::UnclaimedRewards` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::AutoCompound` (r:1 w:0)
+	/// Proof: `CollatorSelection::AutoCompound` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn claim_rewards(s: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(79_854_117, 7087)
+			.saturating_add(Weight::from_parts(6_138_502, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2547).saturating_mul(s.into()))
+	}
+	/// Storage: `CollatorSelection::LastStakeMove` (r:1 w:1)
+	/// Proof: `CollatorSelection::LastStakeMove` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:2 w:2)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:2)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:6)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListHead` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListHead` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CurrentSession` (r:1 w:0)
+	/// Proof: `CollatorSelection::CurrentSession` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinSelfStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinSelfStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 99]`.
+	fn move_stake(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(72_915_304, 3822)
+			.saturating_add(Weight::from_parts(351_842, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::StakeFunders` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakeFunders` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CurrentSession` (r:1 w:0)
+	/// Proof: `CollatorSelection::CurrentSession` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 99]`.
+	fn stake_for(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(135_204_611, 6196)
+			.saturating_add(Weight::from_parts(176_934, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::StakeFunders` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakeFunders` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnstakingRequests` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 99]`.
+	/// The range of component `u` is `[0, 15]`.
+	fn unstake_for(c: u32, u: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(87_266_140, 6196)
+			.saturating_add(Weight::from_parts(178_355, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(102_871, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::RewardDestinations` (r:0 w:1)
+	/// Proof: `CollatorSelection::RewardDestinations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	fn set_reward_destination() -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(11_370_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinSelfStake` (r:0 w:1)
+	/// Proof: `CollatorSelection::MinSelfStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_minimum_self_stake() -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(13_480_000, 1501)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::CandidacyBond` (r:1 w:0)
+	/// Proof: `CollatorSelection::CandidacyBond` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnstakingRequests` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	fn update_candidacy_bond() -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(32_571_000, 3822)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:1)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListHead` (r:1 w:1)
+	/// Proof: `CollatorSelection::CandidateListHead` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:100)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:0)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 100]`.
+	fn set_max_candidate_stake(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(15_092_417, 1517)
+			.saturating_add(Weight::from_parts(10_833_946, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::LockTiers` (r:0 w:1)
+	/// Proof: `CollatorSelection::LockTiers` (`max_values`: Some(1), `max_size`: Some(161), added: 656, mode: `MaxEncodedLen`)
+	/// The range of component `t` is `[0, 8]`.
+	fn set_lock_tiers(t: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(8_342_118, 0)
+			.saturating_add(Weight::from_parts(27_553, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::LockTiers` (r:1 w:0)
+	/// Proof: `CollatorSelection::LockTiers` (`max_values`: Some(1), `max_size`: Some(161), added: 656, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CurrentSession` (r:1 w:0)
+	/// Proof: `CollatorSelection::CurrentSession` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateLockBonus` (r:1 w:1)
+	/// Proof: `CollatorSelection::CandidateLockBonus` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 99]`.
+	fn stake_with_lock(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(110_918_402, 3597)
+			.saturating_add(Weight::from_parts(178_630, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateLockBonus` (r:1 w:1)
+	/// Proof: `CollatorSelection::CandidateLockBonus` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn unlock_stake() -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(19_870_000, 3597)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CollatorSelection::NextPoolId` (r:1 w:1)
+	/// Proof: `CollatorSelection::NextPoolId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Pools` (r:1 w:1)
+	/// Proof: `CollatorSelection::Pools` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::RewardDestinations` (r:0 w:1)
+	/// Proof: `CollatorSelection::RewardDestinations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:16 w:16)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:16 w:16)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:16 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::PoolMembers` (r:1 w:1)
+	/// Proof: `CollatorSelection::PoolMembers` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 16]`.
+	fn create_pool(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(98_224_511, 3822)
+			.saturating_add(Weight::from_parts(41_316_920, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2607).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::Pools` (r:1 w:1)
+	/// Proof: `CollatorSelection::Pools` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:16 w:16)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:16 w:16)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:16 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::PoolMembers` (r:1 w:1)
+	/// Proof: `CollatorSelection::PoolMembers` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 16]`.
+	fn join_pool(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(93_540_118, 3822)
+			.saturating_add(Weight::from_parts(42_108_377, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2607).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::Pools` (r:1 w:1)
+	/// Proof: `CollatorSelection::Pools` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnstakingRequests` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::RewardDestinations` (r:0 w:1)
+	/// Proof: `CollatorSelection::RewardDestinations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:16 w:16)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:16 w:16)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:16 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::PoolMembers` (r:1 w:1)
+	/// Proof: `CollatorSelection::PoolMembers` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 16]`.
+	fn leave_pool(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(101_871_204, 4571)
+			.saturating_add(Weight::from_parts(52_740_391, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2607).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 99]`.
+	fn liquid_stake(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(133_811_096, 3822)
+			.saturating_add(Weight::from_parts(180_117, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:16 w:16)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:16 w:16)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:16 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnstakingRequests` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 16]`.
+	fn liquid_unstake(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(104_519_773, 4571)
+			.saturating_add(Weight::from_parts(52_904_615, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2607).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::StaleCandidates` (r:2 w:1)
+	/// Proof: `CollatorSelection::StaleCandidates` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CounterForStaleCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::CounterForStaleCandidates` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CounterForCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::CounterForCandidates` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:3 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnstakingRequests` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CommissionChangeRequests` (r:0 w:1)
+	/// Proof: `CollatorSelection::CommissionChangeRequests` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::PendingExCandidates` (r:0 w:1)
+	/// Proof: `CollatorSelection::PendingExCandidates` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:1)
+	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn kick_stale_candidate() -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(63_215_000, 8757)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	/// Storage: `CollatorSelection::ExtraReward` (r:1 w:0)
+	/// Proof: `CollatorSelection::ExtraReward` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn provide_rewards_extra_pot() -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(60_000_000, 6172)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn provide_rewards_fixed_mint() -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(31_000_000, 3581)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CollatorSelection::TotalStaked` (r:1 w:0)
+	/// Proof: `CollatorSelection::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn provide_rewards_inflation() -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(34_000_000, 3581)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -536,8 +1160,8 @@ impl WeightInfo for () {
 	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:1)
 	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:1)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Stake` (r:1 w:0)
 	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -549,32 +1173,23 @@ impl WeightInfo for () {
 	/// The range of component `b` is `[1, 19]`.
 	/// The range of component `c` is `[1, 99]`.
 	fn add_invulnerable(b: u32, c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `890 + b * (20 ±0) + c * (65 ±0)`
-		//  Estimated: `7087 + b * (40 ±1) + c * (62 ±0)`
-		// Minimum execution time: 97_851_000 picoseconds.
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
 		Weight::from_parts(97_465_122, 7087)
-			// Standard Error: 8_920
 			.saturating_add(Weight::from_parts(171_645, 0).saturating_mul(b.into()))
-			// Standard Error: 1_690
 			.saturating_add(Weight::from_parts(106_330, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 			.saturating_add(Weight::from_parts(0, 40).saturating_mul(b.into()))
 			.saturating_add(Weight::from_parts(0, 62).saturating_mul(c.into()))
 	}
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:0)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:1)
 	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
 	/// The range of component `b` is `[2, 20]`.
 	fn remove_invulnerable(b: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `252 + b * (20 ±0)`
-		//  Estimated: `7087`
-		// Minimum execution time: 18_430_000 picoseconds.
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
 		Weight::from_parts(19_212_060, 7087)
-			// Standard Error: 2_262
 			.saturating_add(Weight::from_parts(91_966, 0).saturating_mul(b.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
@@ -591,50 +1206,60 @@ impl WeightInfo for () {
 	}
 	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
 	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::CandidacyBond` (r:0 w:1)
+	/// Storage: `CollatorSelection::CandidacyBond` (r:1 w:1)
 	/// Proof: `CollatorSelection::CandidacyBond` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidacyBondGraceEnd` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidacyBondGraceEnd` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn set_candidacy_bond() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `227`
-		//  Estimated: `1501`
-		// Minimum execution time: 12_600_000 picoseconds.
-		Weight::from_parts(13_190_000, 1501)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(15_731_000, 1501)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:1)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:0)
 	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
 	/// Storage: `Session::NextKeys` (r:1 w:0)
 	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `CollatorSelection::CandidacyBond` (r:1 w:0)
 	/// Proof: `CollatorSelection::CandidacyBond` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::Stake` (r:1 w:0)
+	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
 	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinSelfStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinSelfStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:99 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CurrentSession` (r:1 w:0)
+	/// Proof: `CollatorSelection::CurrentSession` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::PendingExCandidates` (r:0 w:1)
 	/// Proof: `CollatorSelection::PendingExCandidates` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:1)
 	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 99]`.
 	fn register_as_candidate(c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1310 + c * (61 ±0)`
-		//  Estimated: `7087 + c * (64 ±0)`
-		// Minimum execution time: 97_791_000 picoseconds.
-		Weight::from_parts(105_943_583, 7087)
-			// Standard Error: 3_176
-			.saturating_add(Weight::from_parts(144_117, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
-			.saturating_add(Weight::from_parts(0, 64).saturating_mul(c.into()))
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(178_512_344, 7087)
+			.saturating_add(Weight::from_parts(301_877, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(c.into()))
 	}
 	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:0)
 	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:1)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// Storage: `Session::NextKeys` (r:1 w:0)
 	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `CollatorSelection::Stake` (r:3 w:1)
@@ -645,8 +1270,8 @@ impl WeightInfo for () {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::CandidacyBond` (r:1 w:0)
 	/// Proof: `CollatorSelection::CandidacyBond` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::StakeCount` (r:1 w:1)
-	/// Proof: `CollatorSelection::StakeCount` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
 	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::CurrentSession` (r:1 w:0)
@@ -655,17 +1280,18 @@ impl WeightInfo for () {
 	/// Proof: `CollatorSelection::PendingExCandidates` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:2)
 	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinSelfStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinSelfStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn take_candidate_slot() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `7507`
-		//  Estimated: `10972`
-		// Minimum execution time: 269_452_000 picoseconds.
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
 		Weight::from_parts(271_451_000, 10972)
-			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
 			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:1)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:0)
 	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Stake` (r:1 w:0)
@@ -678,12 +1304,8 @@ impl WeightInfo for () {
 	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 100]`.
 	fn leave_intent(c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `351 + c * (56 ±0)`
-		//  Estimated: `7087`
-		// Minimum execution time: 36_481_000 picoseconds.
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
 		Weight::from_parts(39_243_499, 7087)
-			// Standard Error: 1_791
 			.saturating_add(Weight::from_parts(84_121, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
@@ -707,8 +1329,8 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:0)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::CandidacyBond` (r:1 w:0)
 	/// Proof: `CollatorSelection::CandidacyBond` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:100 w:0)
@@ -719,30 +1341,34 @@ impl WeightInfo for () {
 	/// Proof: `CollatorSelection::DesiredCandidates` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Stake` (r:97 w:0)
 	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::UnstakingRequests` (r:97 w:97)
-	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::PendingExCandidates` (r:0 w:97)
-	/// Proof: `CollatorSelection::PendingExCandidates` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StaleCandidates` (r:197 w:97)
+	/// Proof: `CollatorSelection::StaleCandidates` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CounterForStaleCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::CounterForStaleCandidates` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinSelfStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinSelfStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidacyBondGraceEnd` (r:1 w:0)
+	/// Proof: `CollatorSelection::CandidacyBondGraceEnd` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 100]`.
 	/// The range of component `c` is `[1, 100]`.
 	fn new_session(r: u32, c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `363 + c * (92 ±0)`
-		//  Estimated: `7087 + c * (2507 ±0) + r * (2832 ±0)`
-		// Minimum execution time: 32_040_000 picoseconds.
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
 		Weight::from_parts(32_511_000, 7087)
-			// Standard Error: 309_600
 			.saturating_add(Weight::from_parts(15_326_198, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 2507).saturating_mul(c.into()))
-			.saturating_add(Weight::from_parts(0, 2832).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 2524).saturating_mul(r.into()))
 	}
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:1)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::StakeCount` (r:1 w:1)
-	/// Proof: `CollatorSelection::StakeCount` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
 	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
@@ -751,60 +1377,60 @@ impl WeightInfo for () {
 	/// Proof: `CollatorSelection::CurrentSession` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
-	/// The range of component `c` is `[1, 100]`.
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 99]`.
 	fn stake(c: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `744 + c * (63 ±0)`
-		//  Estimated: `7087`
-		// Minimum execution time: 91_710_000 picoseconds.
-		Weight::from_parts(97_706_167, 7087)
-			// Standard Error: 2_866
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(97_706_167, 3582)
 			.saturating_add(Weight::from_parts(175_221, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:1)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
 	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::UnstakingRequests` (r:1 w:1)
 	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::StakeCount` (r:1 w:1)
-	/// Proof: `CollatorSelection::StakeCount` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
-	/// The range of component `c` is `[1, 100]`.
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinSelfStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinSelfStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 99]`.
 	/// The range of component `u` is `[0, 15]`.
 	fn unstake_from(c: u32, u: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1190 + c * (64 ±0) + u * (20 ±0)`
-		//  Estimated: `7087`
-		// Minimum execution time: 44_140_000 picoseconds.
-		Weight::from_parts(48_537_795, 7087)
-			// Standard Error: 1_519
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(48_537_795, 3582)
 			.saturating_add(Weight::from_parts(177_672, 0).saturating_mul(c.into()))
-			// Standard Error: 9_663
 			.saturating_add(Weight::from_parts(100_544, 0).saturating_mul(u.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:1)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::Stake` (r:17 w:16)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:16 w:16)
 	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::UnstakingRequests` (r:1 w:1)
 	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::StakeCount` (r:1 w:1)
-	/// Proof: `CollatorSelection::StakeCount` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[16, 100]`.
 	/// The range of component `s` is `[1, 16]`.
 	fn unstake_all(c: u32, s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `369 + c * (56 ±0) + s * (99 ±0)`
-		//  Estimated: `7087 + s * (2567 ±0)`
-		// Minimum execution time: 75_790_000 picoseconds.
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
 		Weight::from_parts(77_041_000, 7087)
-			// Standard Error: 9_923
 			.saturating_add(Weight::from_parts(14_772, 0).saturating_mul(c.into()))
-			// Standard Error: 63_513
 			.saturating_add(Weight::from_parts(24_058_170, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
@@ -838,13 +1464,10 @@ impl WeightInfo for () {
 		Weight::from_parts(11_000_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: `CollatorSelection::CollatorRewardPercentage` (r:0 w:1)
-	/// Proof: `CollatorSelection::CollatorRewardPercentage` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
-	fn set_collator_reward_percentage() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 7_530_000 picoseconds.
+	/// Storage: `CollatorSelection::MaxCommission` (r:0 w:1)
+	/// Proof: `CollatorSelection::MaxCommission` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_max_commission() -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
 		Weight::from_parts(7_780_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -897,41 +1520,57 @@ impl WeightInfo for () {
 	}
 	/// Storage: `CollatorSelection::ProducedBlocks` (r:2 w:1)
 	/// Proof: `CollatorSelection::ProducedBlocks` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::CandidateList` (r:1 w:1)
-	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(5602), added: 6097, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::TotalBlocks` (r:1 w:0)
 	/// Proof: `CollatorSelection::TotalBlocks` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Rewards` (r:1 w:0)
 	/// Proof: `CollatorSelection::Rewards` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::CollatorRewardPercentage` (r:1 w:0)
-	/// Proof: `CollatorSelection::CollatorRewardPercentage` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:202 w:202)
+	/// Storage: `CollatorSelection::CommissionChangeRequests` (r:1 w:0)
+	/// Proof: `CollatorSelection::CommissionChangeRequests` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCommission` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCommission` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::Stake` (r:201 w:200)
+	/// Storage: `CollatorSelection::CurrentRewardCursor` (r:0 w:1)
+	/// Proof: `CollatorSelection::CurrentRewardCursor` (`max_values`: Some(1), `max_size`: Some(117), added: 612, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateLockBonus` (r:1 w:0)
+	/// Proof: `CollatorSelection::CandidateLockBonus` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 16]`.
+	fn reward_one_collator(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(72_436_208, 7087)
+			.saturating_add(Weight::from_parts(213_559, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `CollatorSelection::CurrentRewardCursor` (r:1 w:1)
+	/// Proof: `CollatorSelection::CurrentRewardCursor` (`max_values`: Some(1), `max_size`: Some(117), added: 612, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:65 w:65)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:65 w:64)
 	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::AutoCompound` (r:200 w:0)
+	/// Storage: `CollatorSelection::AutoCompound` (r:64 w:0)
 	/// Proof: `CollatorSelection::AutoCompound` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::StakeCount` (r:200 w:0)
-	/// Proof: `CollatorSelection::StakeCount` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:64 w:0)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
 	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Holds` (r:200 w:200)
+	/// Storage: `Balances::Holds` (r:64 w:64)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
-	/// The range of component `c` is `[1, 16]`.
-	/// The range of component `s` is `[0, 200]`.
+	/// Storage: `CollatorSelection::StakeLocks` (r:64 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 64]`.
 	/// The range of component `a` is `[0, 100]`.
-	fn reward_one_collator(_c: u32, s: u32, a: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0 + a * (206 ±0) + c * (123 ±0) + s * (333 ±0)`
-		//  Estimated: `7087 + a * (3144 ±37) + s * (2591 ±19)`
-		// Minimum execution time: 72_350_000 picoseconds.
-		Weight::from_parts(73_011_000, 7087)
-			// Standard Error: 523_043
-			.saturating_add(Weight::from_parts(105_947_006, 0).saturating_mul(s.into()))
-			// Standard Error: 1_045_111
-			.saturating_add(Weight::from_parts(84_752_927, 0).saturating_mul(a.into()))
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(s.into())))
+	fn reward_stakers_page(s: u32, a: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(30_202_000, 7087)
+			.saturating_add(Weight::from_parts(104_318_255, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(84_106_331, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(s.into())))
 			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(a.into())))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(s.into())))
@@ -945,20 +1584,590 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:200 w:200)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::StakeCount` (r:200 w:200)
-	/// Proof: `CollatorSelection::StakeCount` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:200 w:200)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[0, 200]`.
 	fn refund_stakers(s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `183 + s * (290 ±0)`
-		//  Estimated: `3557 + s * (2591 ±0)`
-		// Minimum execution time: 12_580_000 picoseconds.
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
 		Weight::from_parts(12_780_000, 3557)
-			// Standard Error: 26_585
 			.saturating_add(Weight::from_parts(66_185_690, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(s.into())))
 			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(s.into())))
 			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(s.into()))
 	}
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnstakingRequests` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinSelfStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinSelfStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 99]`.
+	/// The range of component `u` is `[0, 15]`.
+	fn unstake_amount(c: u32, u: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(49_312_420, 3582)
+			.saturating_add(Weight::from_parts(178_104, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(101_287, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnstakingRequests` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CurrentSession` (r:1 w:0)
+	/// Proof: `CollatorSelection::CurrentSession` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 99]`.
+	/// The range of component `u` is `[1, 16]`.
+	fn rebond(c: u32, u: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(64_103_871, 3582)
+			.saturating_add(Weight::from_parts(176_930, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(197_415, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `CollatorSelection::MaxCommission` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCommission` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CommissionChangeRequests` (r:0 w:1)
+	/// Proof: `CollatorSelection::CommissionChangeRequests` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 100]`.
+	fn set_commission(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(18_602_115, 7087)
+			.saturating_add(Weight::from_parts(131_476, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CurrentSession` (r:1 w:0)
+	/// Proof: `CollatorSelection::CurrentSession` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnappliedSlashes` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnappliedSlashes` (`max_values`: None, `max_size`: Some(3622), added: 6097, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 100]`.
+	fn slash_candidate(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(20_316_402, 7087)
+			.saturating_add(Weight::from_parts(142_718, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::UnappliedSlashes` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnappliedSlashes` (`max_values`: None, `max_size`: Some(3622), added: 6097, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 100]`.
+	fn cancel_deferred_slash(s: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(14_027_556, 7087)
+			.saturating_add(Weight::from_parts(61_932, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:201 w:201)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:201 w:201)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:201 w:200)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateUnstakers` (r:3201 w:3200)
+	/// Proof: `CollatorSelection::CandidateUnstakers` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateUnstakeRequestCount` (r:1 w:1)
+	/// Proof: `CollatorSelection::CandidateUnstakeRequestCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnstakingRequests` (r:3200 w:3200)
+	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 200]`.
+	/// The range of component `u` is `[0, 3200]`.
+	fn apply_slash(s: u32, u: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(73_048_217, 7087)
+			.saturating_add(Weight::from_parts(104_381_602, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(98_000_000, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(u.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(u.into())))
+			.saturating_add(Weight::from_parts(0, 3581).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(0, 3581).saturating_mul(u.into()))
+	}
+	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CurrentSession` (r:1 w:0)
+	/// Proof: `CollatorSelection::CurrentSession` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::ProducedBlocks` (r:1 w:0)
+	/// Proof: `CollatorSelection::ProducedBlocks` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::LastClaimedSession` (r:1 w:1)
+	/// Proof: `CollatorSelection::LastClaimedSession` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::RewardPerStake` (r:10 w:0)
+	/// Proof: `CollatorSelection::RewardPerStake` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnclaimedRewards` (r:10 w:10)
+	/// Proof: `CollatorSelection::UnclaimedRewards` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::AutoCompound` (r:1 w:0)
+	/// Proof: `CollatorSelection::AutoCompound` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 10]`.
+	fn claim_rewards(s: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(79_854_117, 7087)
+			.saturating_add(Weight::from_parts(6_138_502, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2547).saturating_mul(s.into()))
+	}
+	/// Storage: `CollatorSelection::LastStakeMove` (r:1 w:1)
+	/// Proof: `CollatorSelection::LastStakeMove` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:2 w:2)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:2)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:6)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListHead` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListHead` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CurrentSession` (r:1 w:0)
+	/// Proof: `CollatorSelection::CurrentSession` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinSelfStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinSelfStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 99]`.
+	fn move_stake(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(72_915_304, 3822)
+			.saturating_add(Weight::from_parts(351_842, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::StakeFunders` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakeFunders` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CurrentSession` (r:1 w:0)
+	/// Proof: `CollatorSelection::CurrentSession` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 99]`.
+	fn stake_for(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(135_204_611, 6196)
+			.saturating_add(Weight::from_parts(176_934, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::StakeFunders` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakeFunders` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnstakingRequests` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 99]`.
+	/// The range of component `u` is `[0, 15]`.
+	fn unstake_for(c: u32, u: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(87_266_140, 6196)
+			.saturating_add(Weight::from_parts(178_355, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(102_871, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::RewardDestinations` (r:0 w:1)
+	/// Proof: `CollatorSelection::RewardDestinations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	fn set_reward_destination() -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(11_370_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinSelfStake` (r:0 w:1)
+	/// Proof: `CollatorSelection::MinSelfStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_minimum_self_stake() -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(13_480_000, 1501)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::CandidacyBond` (r:1 w:0)
+	/// Proof: `CollatorSelection::CandidacyBond` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnstakingRequests` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	fn update_candidacy_bond() -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(32_571_000, 3822)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:1)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListHead` (r:1 w:1)
+	/// Proof: `CollatorSelection::CandidateListHead` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:100)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:0)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 100]`.
+	fn set_max_candidate_stake(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(15_092_417, 1517)
+			.saturating_add(Weight::from_parts(10_833_946, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::LockTiers` (r:0 w:1)
+	/// Proof: `CollatorSelection::LockTiers` (`max_values`: Some(1), `max_size`: Some(161), added: 656, mode: `MaxEncodedLen`)
+	/// The range of component `t` is `[0, 8]`.
+	fn set_lock_tiers(t: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(8_342_118, 0)
+			.saturating_add(Weight::from_parts(27_553, 0).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::LockTiers` (r:1 w:0)
+	/// Proof: `CollatorSelection::LockTiers` (`max_values`: Some(1), `max_size`: Some(161), added: 656, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListTail` (r:0 w:1)
+	/// Proof: `CollatorSelection::CandidateListTail` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CurrentSession` (r:1 w:0)
+	/// Proof: `CollatorSelection::CurrentSession` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MaxCandidateStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MaxCandidateStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateLockBonus` (r:1 w:1)
+	/// Proof: `CollatorSelection::CandidateLockBonus` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 99]`.
+	fn stake_with_lock(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(110_918_402, 3597)
+			.saturating_add(Weight::from_parts(178_630, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateLockBonus` (r:1 w:1)
+	/// Proof: `CollatorSelection::CandidateLockBonus` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn unlock_stake() -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(19_870_000, 3597)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CollatorSelection::NextPoolId` (r:1 w:1)
+	/// Proof: `CollatorSelection::NextPoolId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Pools` (r:1 w:1)
+	/// Proof: `CollatorSelection::Pools` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::RewardDestinations` (r:0 w:1)
+	/// Proof: `CollatorSelection::RewardDestinations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:16 w:16)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:16 w:16)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:16 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::PoolMembers` (r:1 w:1)
+	/// Proof: `CollatorSelection::PoolMembers` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 16]`.
+	fn create_pool(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(98_224_511, 3822)
+			.saturating_add(Weight::from_parts(41_316_920, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2607).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::Pools` (r:1 w:1)
+	/// Proof: `CollatorSelection::Pools` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:16 w:16)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:16 w:16)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:16 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::PoolMembers` (r:1 w:1)
+	/// Proof: `CollatorSelection::PoolMembers` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 16]`.
+	fn join_pool(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(93_540_118, 3822)
+			.saturating_add(Weight::from_parts(42_108_377, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2607).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::Pools` (r:1 w:1)
+	/// Proof: `CollatorSelection::Pools` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnstakingRequests` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::RewardDestinations` (r:0 w:1)
+	/// Proof: `CollatorSelection::RewardDestinations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:16 w:16)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:16 w:16)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:16 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::PoolMembers` (r:1 w:1)
+	/// Proof: `CollatorSelection::PoolMembers` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 16]`.
+	fn leave_pool(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(101_871_204, 4571)
+			.saturating_add(Weight::from_parts(52_740_391, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2607).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:100 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:100 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::MinStake` (r:1 w:0)
+	/// Proof: `CollatorSelection::MinStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:1 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 99]`.
+	fn liquid_stake(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(133_811_096, 3822)
+			.saturating_add(Weight::from_parts(180_117, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2592).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:16 w:16)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:16 w:16)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakeLocks` (r:16 w:0)
+	/// Proof: `CollatorSelection::StakeLocks` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnstakingRequests` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 16]`.
+	fn liquid_unstake(c: u32, ) -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(104_519_773, 4571)
+			.saturating_add(Weight::from_parts(52_904_615, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2607).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::StaleCandidates` (r:2 w:1)
+	/// Proof: `CollatorSelection::StaleCandidates` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CounterForStaleCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::CounterForStaleCandidates` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CounterForCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::CounterForCandidates` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateListNodes` (r:3 w:3)
+	/// Proof: `CollatorSelection::CandidateListNodes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Stake` (r:1 w:1)
+	/// Proof: `CollatorSelection::Stake` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::StakedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::StakedCandidates` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::UnstakingRequests` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnstakingRequests` (`max_values`: None, `max_size`: Some(357), added: 2832, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CommissionChangeRequests` (r:0 w:1)
+	/// Proof: `CollatorSelection::CommissionChangeRequests` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::PendingExCandidates` (r:0 w:1)
+	/// Proof: `CollatorSelection::PendingExCandidates` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:1)
+	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn kick_stale_candidate() -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(63_215_000, 8757)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	/// Storage: `CollatorSelection::ExtraReward` (r:1 w:0)
+	/// Proof: `CollatorSelection::ExtraReward` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn provide_rewards_extra_pot() -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(60_000_000, 6172)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn provide_rewards_fixed_mint() -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(31_000_000, 3581)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CollatorSelection::TotalStaked` (r:1 w:0)
+	/// Proof: `CollatorSelection::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn provide_rewards_inflation() -> Weight {
+		// Placeholder: written by hand, not measured. Must be replaced by benchmark results.
+		Weight::from_parts(34_000_000, 3581)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }